@@ -64,12 +64,9 @@ pub struct IntrospectionResultOutput {
 
 impl fmt::Display for IntrospectionResultOutput {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{{\"datamodel\": \"{}\", \"warnings\": {}, \"version\": \"{}\"}}",
-            self.datamodel,
-            serde_json::to_string(&self.warnings).unwrap(),
-            serde_json::to_string(&self.version).unwrap(),
-        )
+        // Serialize the whole struct through serde rather than hand-assembling the JSON: the
+        // datamodel string can contain quotes and backslashes (e.g. from `@map` arguments or
+        // doc comments), and interpolating it directly used to produce invalid JSON.
+        write!(f, "{}", serde_json::to_string(self).unwrap())
     }
 }
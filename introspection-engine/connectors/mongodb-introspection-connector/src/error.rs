@@ -0,0 +1,11 @@
+use introspection_connector::{ConnectorError, ErrorKind};
+
+pub(crate) trait IntoConnectorResult<T> {
+    fn into_connector_result(self) -> Result<T, ConnectorError>;
+}
+
+impl<T> IntoConnectorResult<T> for Result<T, mongodb::error::Error> {
+    fn into_connector_result(self) -> Result<T, ConnectorError> {
+        self.map_err(|err| ConnectorError::from_kind(ErrorKind::QueryError(err.into())))
+    }
+}
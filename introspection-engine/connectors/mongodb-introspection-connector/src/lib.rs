@@ -0,0 +1,109 @@
+//! The MongoDB introspection connector.
+//!
+//! MongoDB has no catalog to describe, so introspection works by sampling documents out of every
+//! collection in the database and inferring a Prisma model from the fields observed. See
+//! [`sampling`] for the inference itself.
+
+mod error;
+mod sampling;
+
+use datamodel::Datamodel;
+use error::IntoConnectorResult;
+use introspection_connector::{
+    ConnectorError, ConnectorResult, DatabaseMetadata, ErrorKind, IntrospectionConnector, IntrospectionResult, Version,
+};
+use mongodb::{bson, options::ClientOptions, Client, Database};
+use url::Url;
+
+/// The top-level MongoDB introspection connector.
+pub struct MongoDbIntrospectionConnector {
+    client: Client,
+    db_name: String,
+}
+
+impl MongoDbIntrospectionConnector {
+    pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        let url = Url::parse(database_str).map_err(ConnectorError::url_parse_error)?;
+        let db_name = url.path().trim_start_matches('/').to_string();
+
+        let client_options = ClientOptions::parse(database_str).await.into_connector_result()?;
+        let client = Client::with_options(client_options).into_connector_result()?;
+
+        Ok(Self { client, db_name })
+    }
+
+    fn database(&self) -> Database {
+        self.client.database(&self.db_name)
+    }
+}
+
+#[async_trait::async_trait]
+impl IntrospectionConnector for MongoDbIntrospectionConnector {
+    async fn list_databases(&self) -> ConnectorResult<Vec<String>> {
+        self.client
+            .list_database_names(None, None)
+            .await
+            .into_connector_result()
+    }
+
+    async fn get_metadata(&self) -> ConnectorResult<DatabaseMetadata> {
+        let db = self.database();
+
+        let table_count = db.list_collection_names(None).await.into_connector_result()?.len();
+
+        let stats = db
+            .run_command(bson::doc! { "dbStats": 1 }, None)
+            .await
+            .into_connector_result()?;
+
+        let size_in_bytes = stats.get_f64("dataSize").unwrap_or(0.0) as usize;
+
+        Ok(DatabaseMetadata {
+            table_count,
+            size_in_bytes,
+        })
+    }
+
+    async fn get_database_description(&self) -> ConnectorResult<String> {
+        // There is no fixed schema to describe on MongoDB: the closest analogue is the list of
+        // collections the introspected model set would be sampled from.
+        let collection_names = self
+            .database()
+            .list_collection_names(None)
+            .await
+            .into_connector_result()?;
+
+        Ok(serde_json::to_string_pretty(&collection_names).unwrap())
+    }
+
+    async fn get_database_version(&self) -> ConnectorResult<String> {
+        let build_info = self
+            .database()
+            .run_command(bson::doc! { "buildInfo": 1 }, None)
+            .await
+            .into_connector_result()?;
+
+        Ok(build_info
+            .get_str("version")
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|_| "unknown".to_owned()))
+    }
+
+    async fn introspect(&self, _existing_data_model: &Datamodel) -> ConnectorResult<IntrospectionResult> {
+        let models = sampling::sample_database(&self.database())
+            .await
+            .map_err(|err| ConnectorError::from_kind(ErrorKind::QueryError(err.into())))?;
+
+        let data_model = Datamodel { models, enums: vec![] };
+
+        Ok(IntrospectionResult {
+            data_model,
+            // Re-introspection (diffing against `_existing_data_model` to preserve user edits, the
+            // way the SQL connector's `re_introspection` module does) isn't implemented, and fields
+            // that sampling could only type as `Json` are not reported either: no warnings are
+            // returned.
+            warnings: vec![],
+            version: Version::NonPrisma,
+        })
+    }
+}
@@ -0,0 +1,211 @@
+//! Sampling-based schema inference for MongoDB.
+//!
+//! Collections in MongoDB have no fixed schema, so unlike the SQL connectors we cannot ask the
+//! database to describe itself. Instead we pull a sample of documents out of each collection with
+//! `$sample` and infer a model from the fields we actually observed - the same approach `prisma db
+//! pull` takes for MongoDB.
+//!
+//! Anything we can't confidently type - embedded documents, mixed-type arrays, BSON types with no
+//! obvious Prisma equivalent (regexes, JavaScript, etc.) - is mapped to `ScalarType::Json` rather
+//! than guessed at. No warning is raised for such fields.
+
+use datamodel::dml::{Field, FieldArity, FieldType, Model, ScalarField, ScalarType};
+use futures::stream::StreamExt;
+use mongodb::{bson, bson::Bson, bson::Document, Database};
+use std::collections::BTreeMap;
+
+/// Number of documents sampled per collection via `$sample`. Large enough to catch fields that
+/// only show up on a minority of documents, small enough to keep introspection fast on large
+/// collections.
+const SAMPLE_SIZE: i64 = 1000;
+
+/// Infer a `Model` per collection in `db` by sampling up to `SAMPLE_SIZE` documents from each and
+/// merging the fields observed across the sample.
+pub(crate) async fn sample_database(db: &Database) -> mongodb::error::Result<Vec<Model>> {
+    let mut collection_names = db.list_collection_names(None).await?;
+    collection_names.sort();
+
+    let mut models = Vec::with_capacity(collection_names.len());
+
+    for collection_name in collection_names {
+        models.push(sample_collection(db, &collection_name).await?);
+    }
+
+    Ok(models)
+}
+
+async fn sample_collection(db: &Database, collection_name: &str) -> mongodb::error::Result<Model> {
+    let collection = db.collection::<Document>(collection_name);
+    let pipeline = vec![bson::doc! { "$sample": { "size": SAMPLE_SIZE } }];
+    let mut cursor = collection.aggregate(pipeline, None).await?;
+
+    let mut fields: BTreeMap<String, FieldObservations> = BTreeMap::new();
+    let mut documents_seen = 0usize;
+
+    while let Some(document) = cursor.next().await {
+        let document = document?;
+        documents_seen += 1;
+
+        for (key, value) in document.iter() {
+            if key == "_id" {
+                continue;
+            }
+
+            fields.entry(key.clone()).or_default().observe(value);
+        }
+    }
+
+    Ok(model_from_observations(collection_name, fields, documents_seen))
+}
+
+/// What we have learned about a single field across the sampled documents.
+#[derive(Default)]
+struct FieldObservations {
+    /// Number of sampled documents that had this key set to a non-null value.
+    present_and_non_null: usize,
+    /// Whether the field was ever `null` in the sample.
+    saw_null: bool,
+    /// Whether the field was ever a BSON array.
+    is_array: bool,
+    /// The scalar type we have settled on so far, if any.
+    scalar_type: Option<ScalarType>,
+    /// Set once two observations disagree on the scalar type, so we fall back to `Json`.
+    saw_mixed_types: bool,
+}
+
+impl FieldObservations {
+    fn observe(&mut self, value: &Bson) {
+        if let Bson::Null = value {
+            self.saw_null = true;
+            return;
+        }
+
+        self.present_and_non_null += 1;
+
+        let observed_type = match value {
+            Bson::Array(items) => {
+                self.is_array = true;
+                let mut item_types = items
+                    .iter()
+                    .filter(|item| !matches!(item, Bson::Null))
+                    .map(scalar_type_of);
+                let first_type = item_types.next();
+
+                if let Some(first_type) = &first_type {
+                    if item_types.any(|t| t != *first_type) {
+                        self.saw_mixed_types = true;
+                    }
+                }
+
+                first_type
+            }
+            other => Some(scalar_type_of(other)),
+        };
+
+        let observed_type = match observed_type {
+            Some(t) => t,
+            // An empty (or all-null) array doesn't tell us anything about the element type.
+            None => return,
+        };
+
+        match &self.scalar_type {
+            Some(existing) if *existing != observed_type => self.saw_mixed_types = true,
+            Some(_) => {}
+            None => self.scalar_type = Some(observed_type),
+        }
+    }
+
+    fn is_required(&self, documents_seen: usize) -> bool {
+        documents_seen > 0 && !self.saw_null && self.present_and_non_null == documents_seen
+    }
+
+    fn scalar_type(&self) -> ScalarType {
+        if self.saw_mixed_types {
+            ScalarType::Json
+        } else {
+            self.scalar_type.unwrap_or(ScalarType::Json)
+        }
+    }
+}
+
+fn scalar_type_of(value: &Bson) -> ScalarType {
+    match value {
+        Bson::Double(_) => ScalarType::Float,
+        Bson::String(_) => ScalarType::String,
+        Bson::Array(_) => ScalarType::Json, // arrays of arrays: not worth chasing further.
+        Bson::Document(_) => ScalarType::Json,
+        Bson::Boolean(_) => ScalarType::Boolean,
+        Bson::Null => ScalarType::Json,
+        Bson::Int32(_) => ScalarType::Int,
+        Bson::Int64(_) => ScalarType::BigInt,
+        Bson::DateTime(_) => ScalarType::DateTime,
+        Bson::Timestamp(_) => ScalarType::DateTime,
+        Bson::Binary(_) => ScalarType::Bytes,
+        Bson::Decimal128(_) => ScalarType::Decimal,
+        Bson::ObjectId(_) => ScalarType::String,
+        // Regexes, JavaScript code, symbols, min/max key, undefined, DB pointers: no sensible
+        // Prisma scalar. Fall back to Json rather than guess.
+        _ => ScalarType::Json,
+    }
+}
+
+fn model_from_observations(
+    collection_name: &str,
+    fields: BTreeMap<String, FieldObservations>,
+    documents_seen: usize,
+) -> Model {
+    let mut model_fields = Vec::with_capacity(fields.len() + 1);
+
+    model_fields.push(id_field());
+
+    for (name, observations) in fields {
+        let arity = if observations.is_array {
+            FieldArity::List
+        } else if observations.is_required(documents_seen) {
+            FieldArity::Required
+        } else {
+            FieldArity::Optional
+        };
+
+        model_fields.push(Field::ScalarField(ScalarField::new(
+            &name,
+            arity,
+            FieldType::Base(observations.scalar_type(), None),
+        )));
+    }
+
+    Model {
+        database_name: None,
+        name: collection_name.to_owned(),
+        documentation: None,
+        is_embedded: false,
+        is_commented_out: false,
+        is_ignored: false,
+        allow_index_hints: false,
+        fields: model_fields,
+        is_generated: false,
+        indices: vec![],
+        id_fields: vec![],
+        primary_key_name: None,
+    }
+}
+
+/// The `_id` field every MongoDB document has. We don't attempt to infer its default value
+/// generator here, since that would require assumptions about how the ObjectId was produced that
+/// we can't verify from a sample of already-existing documents.
+fn id_field() -> Field {
+    Field::ScalarField(ScalarField {
+        name: "id".to_owned(),
+        arity: FieldArity::Required,
+        field_type: FieldType::Base(ScalarType::String, None),
+        database_name: Some("_id".to_owned()),
+        default_value: None,
+        is_unique: false,
+        is_id: true,
+        documentation: None,
+        is_generated: false,
+        is_updated_at: false,
+        is_commented_out: false,
+        is_ignored: false,
+    })
+}
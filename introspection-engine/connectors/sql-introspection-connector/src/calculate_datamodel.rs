@@ -5,6 +5,10 @@ use crate::prisma_1_defaults::*;
 use crate::re_introspection::enrich;
 use crate::sanitize_datamodel_names::{sanitization_leads_to_duplicate_names, sanitize_datamodel_names};
 use crate::version_checker::VersionChecker;
+use crate::warnings::{
+    warning_foreign_keys_referencing_other_schema, warning_memory_optimized_tables, warning_redundant_indexes,
+    warning_relation_tables_with_extra_columns, Model, ModelWithSuggestion,
+};
 use crate::SqlIntrospectionResult;
 use datamodel::Datamodel;
 use introspection_connector::IntrospectionResult;
@@ -13,10 +17,21 @@ use sql_schema_describer::*;
 use tracing::debug;
 
 /// Calculate a data model from a database schema.
+///
+/// Note: `introspect` never turns a `View` into a model - views of any kind are described by
+/// `sql-schema-describer` (including Postgres materialized views, see
+/// `postgres::SqlSchemaDescriber::get_views`) but are not translated into fresh models. Doing
+/// that for real would need a "read-only model" concept in `dml` (an `@@ignore`d-for-writes model
+/// isn't the same thing - it still assumes a writable table) and column introspection for views
+/// (`View` currently only carries a name and its SQL definition, no columns or indexes), neither
+/// of which exists yet. What `enrich` (via `schema.views`) does handle is the re-introspection
+/// side: if a model from the previous schema maps to something that is now a view, it is kept
+/// as-is and flagged `@@ignore`d instead of silently disappearing.
 pub fn calculate_datamodel(
     schema: &SqlSchema,
     family: &SqlFamily,
     previous_data_model: &Datamodel,
+    prisma1_compatibility: bool,
 ) -> SqlIntrospectionResult<IntrospectionResult> {
     debug!("Calculating data model.");
 
@@ -24,7 +39,7 @@ pub fn calculate_datamodel(
     let mut data_model = Datamodel::new();
 
     // 1to1 translation of the sql schema
-    introspect(schema, &mut version_check, &mut data_model, *family)?;
+    let cross_schema_references = introspect(schema, &mut version_check, &mut data_model, *family)?;
 
     if !sanitization_leads_to_duplicate_names(&data_model) {
         // our opinionation about valid names
@@ -35,20 +50,77 @@ pub fn calculate_datamodel(
     deduplicate_relation_field_names(&mut data_model);
 
     let mut warnings = vec![];
+
+    if !cross_schema_references.is_empty() {
+        warnings.push(warning_foreign_keys_referencing_other_schema(&cross_schema_references));
+    }
+
     if !previous_data_model.is_empty() {
-        warnings.append(&mut enrich(previous_data_model, &mut data_model, family));
+        warnings.append(&mut enrich(previous_data_model, &mut data_model, schema, family));
         tracing::debug!("Enriching datamodel is done: {:?}", data_model);
     }
 
     // commenting out models, fields, enums, enum values
     warnings.append(&mut commenting_out_guardrails(&mut data_model, family));
 
+    // flag join tables that carry extra metadata columns and cannot become implicit m-n relations
+    let relation_tables_with_extra_columns: Vec<ModelWithSuggestion> = schema
+        .tables
+        .iter()
+        .filter(|table| is_relation_table_with_extra_columns(table))
+        .map(|table| ModelWithSuggestion::new(&table.name, &suggested_explicit_relation_model(table)))
+        .collect();
+
+    if !relation_tables_with_extra_columns.is_empty() {
+        warnings.push(warning_relation_tables_with_extra_columns(
+            &relation_tables_with_extra_columns,
+        ));
+    }
+
+    // flag indexes whose columns are a prefix of another index's columns on the same model
+    let redundant_indexes: Vec<ModelWithSuggestion> = data_model
+        .models()
+        .flat_map(|model| {
+            redundant_indexes(model).map(move |(index, covering_index)| {
+                ModelWithSuggestion::new(
+                    &model.name,
+                    &format!(
+                        "@@index([{}]) is a prefix of @@index([{}]) and can be removed",
+                        index.fields.join(", "),
+                        covering_index.fields.join(", "),
+                    ),
+                )
+            })
+        })
+        .collect();
+
+    if !redundant_indexes.is_empty() {
+        warnings.push(warning_redundant_indexes(&redundant_indexes));
+    }
+
+    // flag memory-optimized tables, since the datamodel has no way to express that and a
+    // migration from it would create an ordinary (non-memory-optimized) table
+    let memory_optimized_tables: Vec<Model> = schema
+        .tables
+        .iter()
+        .filter(|table| table.is_memory_optimized)
+        .map(|table| Model::new(&table.name))
+        .collect();
+
+    if !memory_optimized_tables.is_empty() {
+        warnings.push(warning_memory_optimized_tables(&memory_optimized_tables));
+    }
+
     // try to identify whether the schema was created by a previous Prisma version
     let version = version_check.version(&warnings, &data_model);
 
     // if based on a previous Prisma version add id default opinionations
     add_prisma_1_id_defaults(family, &version, &mut data_model, schema, &mut warnings);
 
+    if prisma1_compatibility {
+        add_prisma_1_scalar_list_compatibility(family, &version, &mut data_model, schema, &mut warnings);
+    }
+
     // renderer -> parser -> validator, is_commented_out gets lost between renderer and parser
     debug!("Done calculating data model {:?}", data_model);
     Ok(IntrospectionResult {
@@ -79,6 +151,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                allow_index_hints: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "optional",
@@ -108,6 +181,7 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                primary_key_name: None,
             }],
             enums: vec![],
         };
@@ -122,18 +196,21 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        description: None,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                 ],
                 indices: vec![],
@@ -143,6 +220,11 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                storage_options: Default::default(),
+                check_constraints: Default::default(),
+
+                identity_columns: Default::default(),
+                description: None,
             }],
             enums: vec![],
             sequences: vec![],
@@ -150,7 +232,7 @@ mod tests {
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -167,6 +249,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -191,6 +274,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -200,6 +284,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -224,6 +309,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -233,6 +319,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -257,6 +344,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
             ],
             enums: vec![],
@@ -277,6 +365,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        description: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -285,6 +374,11 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -298,6 +392,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        description: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -306,6 +401,11 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -319,16 +419,24 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        description: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
                         columns: vec!["primary".to_string()],
                         sequence: Some(Sequence {
                             name: "sequence".to_string(),
+                            start_value: 1,
+                            increment_by: 1,
                         }),
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
             ],
             enums: vec![],
@@ -337,7 +445,7 @@ mod tests {
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -352,6 +460,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                allow_index_hints: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "non_unique",
@@ -376,6 +485,7 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                primary_key_name: None,
             }],
             enums: vec![],
         };
@@ -391,28 +501,37 @@ mod tests {
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["unique".to_string()],
                     tpe: IndexType::Unique,
+                    nulls_not_distinct: false,
+                    predicate: None,
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                storage_options: Default::default(),
+                check_constraints: Default::default(),
+
+                identity_columns: Default::default(),
+                description: None,
             }],
             enums: vec![],
             sequences: vec![],
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -428,6 +547,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -477,6 +597,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
                 Model {
                     database_name: None,
@@ -485,6 +606,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -564,6 +686,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
             ],
             enums: vec![],
@@ -586,6 +709,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            description: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -597,6 +721,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            description: None,
                         },
                     ],
                     indices: vec![],
@@ -606,6 +731,11 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -620,6 +750,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            description: None,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -631,6 +762,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            description: None,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -642,6 +774,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            description: None,
                         },
                     ],
                     indices: vec![],
@@ -659,6 +792,11 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
             ],
             enums: vec![],
@@ -666,7 +804,7 @@ mod tests {
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, expected_data_model);
     }
@@ -681,6 +819,7 @@ mod tests {
                 is_embedded: false,
                 is_commented_out: false,
                 is_ignored: false,
+                allow_index_hints: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -733,8 +872,12 @@ mod tests {
                     name: Some("name_last_name_unique".to_string()),
                     fields: vec!["name".to_string(), "lastname".to_string()],
                     tpe: datamodel::dml::IndexType::Unique,
+                    db_name: Some("name_last_name_unique".to_string()),
+                    nulls_not_distinct: false,
+                    predicate: None,
                 }],
                 id_fields: vec![],
+                primary_key_name: None,
             }],
             enums: vec![],
         };
@@ -755,6 +898,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        description: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -766,6 +910,7 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -777,12 +922,15 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        description: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "name_last_name_unique".to_string(),
                     columns: vec!["name".to_string(), "lastname".to_string()],
                     tpe: IndexType::Unique,
+                    nulls_not_distinct: false,
+                    predicate: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
@@ -790,13 +938,18 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                storage_options: Default::default(),
+                check_constraints: Default::default(),
+
+                identity_columns: Default::default(),
+                description: None,
             }],
             enums: vec![],
             sequences: vec![],
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -812,6 +965,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -861,6 +1015,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
                 Model {
                     database_name: None,
@@ -869,6 +1024,7 @@ mod tests {
                     is_embedded: false,
                     is_commented_out: false,
                     is_ignored: false,
+                    allow_index_hints: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -918,6 +1074,7 @@ mod tests {
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    primary_key_name: None,
                 },
             ],
             enums: vec![],
@@ -940,6 +1097,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            description: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -951,6 +1109,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            description: None,
                         },
                     ],
                     indices: vec![],
@@ -960,6 +1119,11 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
                 Table {
                     name: "User".to_string(),
@@ -974,6 +1138,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            description: None,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -985,6 +1150,7 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            description: None,
                         },
                     ],
                     indices: vec![],
@@ -1001,6 +1167,11 @@ mod tests {
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    storage_options: Default::default(),
+                    check_constraints: Default::default(),
+
+                    identity_columns: Default::default(),
+                    description: None,
                 },
             ],
             enums: vec![],
@@ -1008,7 +1179,7 @@ mod tests {
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -1052,7 +1223,7 @@ mod tests {
             user_defined_types: vec![],
         };
         let introspection_result =
-            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new()).expect("calculate data model");
+            calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
 
         assert_eq!(introspection_result.data_model, ref_data_model);
     }
@@ -69,15 +69,16 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
     //on postgres this is allowed, on the other dbs, this could be a symptom of missing privileges
     for model in datamodel.models_mut() {
         if model.fields.is_empty() {
-            model.is_commented_out = true;
+            model.is_ignored = true;
             let comment = match family {
                 SqlFamily::Postgres =>
                     "We could not retrieve columns for the underlying table. Either it has none or you are missing rights to see them. Please check your privileges.".to_string(),
                 _ => "We could not retrieve columns for the underlying table. You probably have no rights to see them. Please check your privileges.".to_string(),
 
             };
-            //postgres could be valid, or privileges, commenting out because we cannot handle it.
-            //others, this is invalid, commenting out because we cannot handle it.
+            //postgres could be valid, or privileges. Either way we can't infer fields for the
+            //client to select, so ignore the model instead of commenting it out: the migration
+            //engine still needs to know the table exists.
             model.documentation = Some(comment);
             models_without_columns.push(Model {
                 model: model.name.clone(),
@@ -117,7 +118,6 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
     let mut warnings = vec![];
 
     //extra warning about missing columns
-    //todo instead of commenting out use @@ignore here
     if !models_without_columns.is_empty() {
         warnings.push(warning_models_without_columns(&models_without_columns))
     }
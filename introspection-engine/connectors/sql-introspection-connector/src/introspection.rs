@@ -1,9 +1,10 @@
 use crate::introspection_helpers::{
     calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_new_migration_table, is_old_migration_table, is_prisma_1_point_0_join_table,
-    is_prisma_1_point_1_or_2_join_table, is_relay_table,
+    calculate_scalar_field, calculate_table_documentation, is_new_migration_table, is_old_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
+use crate::warnings::ModelAndCrossSchemaReference;
 use crate::Dedup;
 use crate::SqlError;
 use datamodel::{dml, walkers::find_model_by_db_name, Datamodel, Field, Model, RelationField};
@@ -11,12 +12,22 @@ use quaint::connector::SqlFamily;
 use sql_schema_describer::{SqlSchema, Table};
 use tracing::debug;
 
+/// Runs a 1-to-1 translation of the described `SqlSchema` into a `Datamodel`.
+///
+/// Returns the foreign keys that had to be skipped because they reference a table that isn't
+/// part of `schema` (i.e. a cross-schema foreign key: `sql-schema-describer` only ever describes
+/// one schema at a time, see `SqlSchemaDescriberBackend::describe`). Fully supporting those would
+/// mean describing and diffing multiple schemas together, which is a bigger change than this
+/// function can make locally; skipping them (and reporting it back as a warning) at least avoids
+/// producing a `Datamodel` with a relation pointing at a model that doesn't exist.
 pub fn introspect(
     schema: &SqlSchema,
     version_check: &mut VersionChecker,
     data_model: &mut Datamodel,
     sql_family: SqlFamily,
-) -> Result<(), SqlError> {
+) -> Result<Vec<ModelAndCrossSchemaReference>, SqlError> {
+    let mut cross_schema_references = Vec::new();
+
     for table in schema
         .tables
         .iter()
@@ -28,6 +39,7 @@ pub fn introspect(
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.documentation = calculate_table_documentation(&table);
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
@@ -39,6 +51,15 @@ pub fn introspect(
         foreign_keys_copy.clear_duplicates();
 
         for foreign_key in &foreign_keys_copy {
+            if schema.table(&foreign_key.referenced_table).is_err() {
+                cross_schema_references.push(ModelAndCrossSchemaReference::new(
+                    &table.name,
+                    &foreign_key.columns,
+                    &foreign_key.referenced_table,
+                ));
+                continue;
+            }
+
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
             let relation_field = calculate_relation_field(schema, table, foreign_key)?;
@@ -99,7 +120,7 @@ pub fn introspect(
         data_model.find_model_mut(&model).add_field(Field::RelationField(field));
     }
 
-    Ok(())
+    Ok(cross_schema_references)
 }
 
 fn calculate_fields_for_prisma_join_table(
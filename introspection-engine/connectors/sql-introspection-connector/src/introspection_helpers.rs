@@ -8,7 +8,9 @@ use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::DefaultKind;
-use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SqlSchema, Table};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnTypeFamily, ForeignKey, IdentityGeneration, Index, IndexType, SqlSchema, Table,
+};
 use tracing::debug;
 
 //checks
@@ -95,6 +97,51 @@ fn common_prisma_m_to_n_relation_conditions(table: &Table) -> bool {
         }
 }
 
+/// A table that looks like an m-n join table (two foreign keys to two other
+/// tables) but carries extra columns beyond the join columns themselves,
+/// e.g. a `PostsToUsers` table with an additional `date` column. Prisma
+/// cannot generate an implicit many-to-many relation for these, since the
+/// extra columns would have nowhere to live.
+pub(crate) fn is_relation_table_with_extra_columns(table: &Table) -> bool {
+    table.foreign_keys.len() == 2
+        && table.foreign_keys[0].referenced_table != table.foreign_keys[1].referenced_table
+        && table.columns.len() > table.foreign_keys[0].columns.len() + table.foreign_keys[1].columns.len()
+}
+
+/// Renders a minimal PSL snippet for the explicit relation model a user should
+/// write instead of relying on implicit m-n resolution, for a table flagged by
+/// [`is_relation_table_with_extra_columns`].
+pub(crate) fn suggested_explicit_relation_model(table: &Table) -> String {
+    let mut fields = String::new();
+
+    for column in &table.columns {
+        fields.push_str(&format!("  {} {:?}\n", column.name, column.tpe.family));
+    }
+
+    for fk in &table.foreign_keys {
+        fields.push_str(&format!(
+            "  {} {} @relation(fields: [{}], references: [{}])\n",
+            fk.referenced_table.to_lowercase(),
+            fk.referenced_table,
+            fk.columns.join(", "),
+            fk.referenced_columns.join(", ")
+        ));
+    }
+
+    format!(
+        "model {} {{\n{}\n  @@id([{}])\n}}",
+        table.name,
+        fields,
+        table
+            .foreign_keys
+            .iter()
+            .flat_map(|fk| fk.columns.iter())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
 //calculators
 
 pub fn calculate_many_to_many_field(
@@ -131,6 +178,50 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        db_name: Some(index.name.clone()),
+        nulls_not_distinct: index.nulls_not_distinct,
+        predicate: index.predicate.clone(),
+    }
+}
+
+/// Renders a table's CHECK constraints (see `sql_schema_describer::CheckConstraint`) as model
+/// documentation, since there is no `@@check` attribute in the datamodel yet. Returns `None` if
+/// the table has none, which is always true for connectors that don't describe them (see
+/// `Table::check_constraints`'s doc comment).
+fn calculate_check_constraints_documentation(table: &Table) -> Option<String> {
+    if table.check_constraints.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<String> = table
+        .check_constraints
+        .iter()
+        .map(|check| format!("CHECK constraint `{}`: {}", check.name, check.expression))
+        .collect();
+
+    Some(lines.join("\n"))
+}
+
+/// Renders a model's documentation from everything we know about the underlying table that
+/// doesn't have a first-class datamodel representation: its database comment (see
+/// `Table::description`) and its CHECK constraints. `Table::description` is only ever populated
+/// for Postgres and MySQL today (see its doc comment), so this falls back to the CHECK constraint
+/// rendering alone on other connectors.
+pub(crate) fn calculate_table_documentation(table: &Table) -> Option<String> {
+    join_documentation(&[
+        table.description.clone(),
+        calculate_check_constraints_documentation(table),
+    ])
+}
+
+/// Joins the non-`None` parts with a blank line, in order, or returns `None` if all parts are `None`.
+fn join_documentation(parts: &[Option<String>]) -> Option<String> {
+    let lines: Vec<&str> = parts.iter().filter_map(|part| part.as_deref()).collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n\n"))
     }
 }
 
@@ -159,7 +250,7 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, family: &Sq
         default_value,
         is_unique,
         is_id,
-        documentation: None,
+        documentation: calculate_column_documentation(table, column),
         is_generated: false,
         is_updated_at: false,
         is_commented_out: false,
@@ -167,6 +258,32 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, family: &Sq
     }
 }
 
+/// Notes a Postgres `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY` column as a doc comment,
+/// since the PSL has no attribute or native type argument for the distinction yet - the field
+/// still introspects to the same `@default(autoincrement())` as a plain serial column (see
+/// `calculate_default`), which loses the ALWAYS/BY DEFAULT choice on re-introspection. Adding a
+/// first-class native type argument for this would mean threading it through
+/// `NativeTypeInstance`/the connector's native type parsing, which is out of scope here.
+fn calculate_identity_documentation(table: &Table, column: &Column) -> Option<String> {
+    table
+        .identity_columns
+        .get(&column.name)
+        .map(|generation| match generation {
+            IdentityGeneration::Always => "GENERATED ALWAYS AS IDENTITY".to_owned(),
+            IdentityGeneration::ByDefault => "GENERATED BY DEFAULT AS IDENTITY".to_owned(),
+        })
+}
+
+/// Renders a field's documentation from its database comment (see `Column::description`) and the
+/// identity annotation above. `Column::description` is only ever populated for Postgres and MySQL
+/// today (see its doc comment), so this falls back to the identity rendering alone elsewhere.
+fn calculate_column_documentation(table: &Table, column: &Column) -> Option<String> {
+    join_documentation(&[
+        column.description.clone(),
+        calculate_identity_documentation(table, column),
+    ])
+}
+
 pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
@@ -387,6 +504,33 @@ pub fn columns_match(a_cols: &[String], b_cols: &[String]) -> bool {
     a_cols.len() == b_cols.len() && a_cols.iter().all(|a_col| b_cols.iter().any(|b_col| a_col == b_col))
 }
 
+/// Finds `@@index`es that are made redundant by another index on the same model, so we can warn
+/// about them instead of silently introspecting all of them. An index is redundant if its columns,
+/// in order, are a prefix of another index's columns (including an exact duplicate, a prefix of
+/// itself): the other index already serves any lookup or sort the redundant one would. We don't
+/// flag `@@unique` indexes this way, since a `@@unique` enforces a constraint a plain index
+/// covering the same or more columns does not.
+pub fn redundant_indexes(model: &Model) -> impl Iterator<Item = (&IndexDefinition, &IndexDefinition)> {
+    model
+        .indices
+        .iter()
+        .enumerate()
+        .filter(|(_, index)| !index.is_unique())
+        .filter_map(move |(i, index)| {
+            model
+                .indices
+                .iter()
+                .enumerate()
+                .find(|(j, other)| {
+                    *j != i
+                        && other.fields.len() >= index.fields.len()
+                        && other.fields[..index.fields.len()] == index.fields[..]
+                        && (other.fields.len() > index.fields.len() || *j < i)
+                })
+                .map(|(_, covering_index)| (index, covering_index))
+        })
+}
+
 pub fn replace_field_names(target: &mut Vec<String>, old_name: &str, new_name: &str) {
     target
         .iter_mut()
@@ -10,14 +10,14 @@ mod re_introspection;
 mod sanitize_datamodel_names;
 mod schema_describer_loading;
 mod version_checker;
-mod warnings;
+pub mod warnings; // only exported so RPC-level best-effort introspection can raise its own warning
 
 use datamodel::Datamodel;
 pub use error::*;
 use introspection_connector::{
-    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
+    ConnectorError, ConnectorResult, DatabaseMetadata, ErrorKind, IntrospectionConnector, IntrospectionResult,
 };
-use quaint::{prelude::ConnectionInfo, single::Quaint};
+use quaint::{connector::SqlFamily, prelude::ConnectionInfo, single::Quaint};
 use schema_describer_loading::load_describer;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
 use std::future::Future;
@@ -27,6 +27,7 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 #[derive(Debug)]
 pub struct SqlIntrospectionConnector {
     connection: Quaint,
+    prisma1_compatibility: bool,
 }
 
 impl SqlIntrospectionConnector {
@@ -39,7 +40,17 @@ impl SqlIntrospectionConnector {
 
         tracing::debug!("SqlIntrospectionConnector initialized.");
 
-        Ok(SqlIntrospectionConnector { connection })
+        Ok(SqlIntrospectionConnector {
+            connection,
+            prisma1_compatibility: false,
+        })
+    }
+
+    /// Opt into the Prisma 1 compatibility mode described on `add_prisma_1_scalar_list_compatibility`.
+    /// Off by default, since it only makes sense for databases that used to be served by Prisma 1.
+    pub fn with_prisma1_compatibility(mut self, enabled: bool) -> Self {
+        self.prisma1_compatibility = enabled;
+        self
     }
 
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
@@ -88,6 +99,26 @@ impl SqlIntrospectionConnector {
     }
 }
 
+/// Introspect a schema snapshot previously produced by `get_database_description`, instead of a
+/// live database connection. This lets CI check that introspecting a known snapshot still yields
+/// the expected datamodel after engine upgrades, without needing a database available to connect
+/// to.
+///
+/// Errors are always reported as `ErrorKind::Generic`: `SqlError::into_connector_error` produces
+/// its more specific `ErrorKind`s (bad credentials, unreachable host, etc.) from a live
+/// connection's `ConnectionInfo`, which doesn't exist on this path.
+pub fn introspect_from_database_description(
+    description: &str,
+    family: SqlFamily,
+    existing_data_model: &Datamodel,
+) -> ConnectorResult<IntrospectionResult> {
+    let sql_schema: SqlSchema =
+        serde_json::from_str(description).map_err(|err| ConnectorError::from_kind(ErrorKind::Generic(err.into())))?;
+
+    calculate_datamodel::calculate_datamodel(&sql_schema, &family, existing_data_model, false)
+        .map_err(|err| ConnectorError::from_kind(ErrorKind::Generic(err.into())))
+}
+
 #[async_trait::async_trait]
 impl IntrospectionConnector for SqlIntrospectionConnector {
     async fn list_databases(&self) -> ConnectorResult<Vec<String>> {
@@ -116,10 +147,15 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
         let family = self.connection.connection_info().sql_family();
 
-        let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family, &previous_data_model)
-            .map_err(|sql_introspection_error| {
-                sql_introspection_error.into_connector_error(&self.connection.connection_info())
-            })?;
+        let introspection_result = calculate_datamodel::calculate_datamodel(
+            &sql_schema,
+            &family,
+            &previous_data_model,
+            self.prisma1_compatibility,
+        )
+        .map_err(|sql_introspection_error| {
+            sql_introspection_error.into_connector_error(&self.connection.connection_info())
+        })?;
 
         tracing::debug!("Calculating datamodel is done: {:?}", introspection_result.data_model);
 
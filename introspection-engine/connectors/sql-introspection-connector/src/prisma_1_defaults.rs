@@ -1,4 +1,8 @@
-use crate::warnings::{warning_default_cuid_warning, warning_default_uuid_warning, ModelAndField};
+use crate::introspection_helpers::{calculate_scalar_field_type_with_native_types, is_prisma_1_or_11_list_table};
+use crate::warnings::{
+    warning_default_cuid_warning, warning_default_uuid_warning, warning_prisma_1_scalar_list_table_converted,
+    warning_prisma_1_scalar_list_table_unsupported, ModelAndField, ModelWithSuggestion,
+};
 use datamodel::{dml, Datamodel, ValueGenerator};
 use introspection_connector::{Version, Warning};
 use native_types::{MySqlType, PostgresType};
@@ -69,3 +73,105 @@ pub fn add_prisma_1_id_defaults(
         warnings.push(warning_default_uuid_warning(&inferred_uuids))
     }
 }
+
+/// Prisma 1 emulated list fields with a `<Model>_<field>` join table holding one row per list
+/// item (`nodeId`, `position`, `value`), since the SQL databases it supported didn't all have
+/// native array types. When Prisma 1 compatibility mode is on, these tables - which would
+/// otherwise introspect as regular junk models - are recognized and either turned into a native
+/// list field on the owning model (currently only possible on Postgres, since that's the only
+/// connector here with a native array type) or reported as needing a manual migration.
+///
+/// Either way the join table's own model is commented out: the table is left untouched in the
+/// database (dropping it is part of the suggested migration SQL, not something introspection
+/// does on its own), but it no longer needs to show up as a model in the generated schema.
+pub fn add_prisma_1_scalar_list_compatibility(
+    family: &SqlFamily,
+    version: &Version,
+    data_model: &mut Datamodel,
+    schema: &SqlSchema,
+    warnings: &mut Vec<Warning>,
+) {
+    if !matches!(version, Version::Prisma1 | Version::Prisma11) {
+        return;
+    }
+
+    let mut converted = vec![];
+    let mut unsupported = vec![];
+
+    for table in schema.tables.iter().filter(|table| is_prisma_1_or_11_list_table(table)) {
+        let (model_name, field_name) = match table.name.split_once('_') {
+            Some(parts) => parts,
+            None => continue,
+        };
+
+        let model = match data_model
+            .find_model(model_name)
+            .or_else(|| data_model.find_model_db_name(model_name))
+        {
+            Some(model) => model.name.clone(),
+            None => continue,
+        };
+
+        let join_table_model_name = data_model
+            .find_model(&table.name)
+            .or_else(|| data_model.find_model_db_name(&table.name))
+            .map(|m| m.name.clone());
+
+        let value_column = match table.column("value") {
+            Some(column) => column,
+            None => continue,
+        };
+
+        let model = data_model.find_model_mut(&model).unwrap();
+
+        if model.find_field(field_name).is_some() {
+            continue;
+        }
+
+        if *family == SqlFamily::Postgres {
+            let field_type = calculate_scalar_field_type_with_native_types(value_column, family);
+
+            model.add_field(dml::Field::ScalarField(dml::ScalarField {
+                name: field_name.to_owned(),
+                arity: dml::FieldArity::List,
+                field_type,
+                database_name: None,
+                default_value: None,
+                is_unique: false,
+                is_id: false,
+                documentation: None,
+                is_generated: false,
+                is_updated_at: false,
+                is_commented_out: false,
+                is_ignored: false,
+            }));
+
+            let migration = format!(
+                "ALTER TABLE \"{model_table}\" ADD COLUMN \"{field}\" {tpe}[];\nUPDATE \"{model_table}\" AS m SET \"{field}\" = t.values FROM (SELECT \"nodeId\", array_agg(\"value\" ORDER BY \"position\") AS values FROM \"{join_table}\" GROUP BY \"nodeId\") AS t WHERE t.\"nodeId\" = m.id;\nDROP TABLE \"{join_table}\";",
+                model_table = model.database_name.clone().unwrap_or_else(|| model.name.clone()),
+                field = field_name,
+                tpe = value_column.tpe.full_data_type,
+                join_table = table.name,
+            );
+
+            converted.push(ModelWithSuggestion::new(&table.name, &migration));
+        } else {
+            unsupported.push(ModelWithSuggestion::new(
+                &table.name,
+                "This table could not be converted to a native list field automatically, since that is currently only supported on Postgres. The table was left in the database and commented out of the datamodel; the data can still be migrated by hand.",
+            ));
+        }
+
+        if let Some(join_table_model_name) = join_table_model_name {
+            data_model.find_model_mut(&join_table_model_name).is_commented_out = true;
+        }
+    }
+
+    if !converted.is_empty() {
+        warnings.push(warning_prisma_1_scalar_list_table_converted(&converted));
+    }
+
+    if !unsupported.is_empty() {
+        warnings.push(warning_prisma_1_scalar_list_table_unsupported(&unsupported));
+    }
+}
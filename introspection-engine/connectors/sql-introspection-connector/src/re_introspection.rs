@@ -1,15 +1,42 @@
 use crate::introspection_helpers::replace_field_names;
 use crate::warnings::*;
-use datamodel::{Datamodel, DefaultValue, FieldType, Ignorable, ValueGenerator};
+use datamodel::{Datamodel, DefaultValue, FieldType, Ignorable};
 use introspection_connector::Warning;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
+use sql_schema_describer::SqlSchema;
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Equal, Greater, Less};
 
-pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family: &SqlFamily) -> Vec<Warning> {
+pub fn enrich(
+    old_data_model: &Datamodel,
+    new_data_model: &mut Datamodel,
+    schema: &SqlSchema,
+    family: &SqlFamily,
+) -> Vec<Warning> {
     let mut warnings = vec![];
 
+    // Models that used to map to a table but now map to a view are otherwise silently dropped,
+    // since `introspect` never turns a `View` into a model (see the note on
+    // `calculate_datamodel::calculate_datamodel`). Keep them verbatim from the previous schema so
+    // a manual mapping to a view survives re-introspection, and mark them `@@ignore`d since there
+    // is no dedicated "this model is backed by a view" marker in the dml yet.
+    let mut preserved_view_models = vec![];
+    {
+        for old_model in old_data_model.models() {
+            let db_name = old_model.database_name.as_ref().unwrap_or(&old_model.name);
+            let maps_to_view = schema.views.iter().any(|view| &view.name == db_name);
+
+            if maps_to_view && new_data_model.find_model(&old_model.name).is_none() {
+                let mut preserved = old_model.clone();
+                preserved.is_ignored = true;
+
+                preserved_view_models.push(Model::new(&preserved.name));
+                new_data_model.add_model(preserved);
+            }
+        }
+    }
+
     //@@map on models
     let mut changed_model_names = vec![];
     {
@@ -294,9 +321,11 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
     }
 
     // Prisma Level Only concepts
-    // @default(cuid) / @default(uuid) / @updatedAt
-    let mut re_introspected_prisma_level_cuids = vec![];
-    let mut re_introspected_prisma_level_uuids = vec![];
+    // @default(cuid) / @default(cuid2) / @default(nanoid) / @default(uuid) / @updatedAt
+    //
+    // The old generator is cloned wholesale, rather than being rebuilt from scratch, so that
+    // arguments like `uuid(7)`'s version or `nanoid(10)`'s length survive re-introspection too.
+    let mut re_introspected_prisma_level_generators = vec![];
     let mut re_introspected_updated_at = vec![];
     {
         for model in new_data_model.models() {
@@ -304,12 +333,11 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
                 if let Some(old_model) = old_data_model.find_model(&model.name) {
                     if let Some(old_field) = old_model.find_scalar_field(&field.name) {
                         if field.default_value.is_none() && field.field_type.is_string() {
-                            if old_field.default_value == Some(DefaultValue::Expression(ValueGenerator::new_cuid())) {
-                                re_introspected_prisma_level_cuids.push(ModelAndField::new(&model.name, &field.name));
-                            }
-
-                            if old_field.default_value == Some(DefaultValue::Expression(ValueGenerator::new_uuid())) {
-                                re_introspected_prisma_level_uuids.push(ModelAndField::new(&model.name, &field.name));
+                            if let Some(DefaultValue::Expression(generator)) = &old_field.default_value {
+                                if matches!(generator.name.as_str(), "cuid" | "cuid2" | "nanoid" | "uuid") {
+                                    re_introspected_prisma_level_generators
+                                        .push((ModelAndField::new(&model.name, &field.name), generator.clone()));
+                                }
                             }
                         }
 
@@ -321,16 +349,10 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
             }
         }
 
-        for cuid in &re_introspected_prisma_level_cuids {
+        for (model_and_field, generator) in &re_introspected_prisma_level_generators {
             new_data_model
-                .find_scalar_field_mut(&cuid.model, &cuid.field)
-                .default_value = Some(DefaultValue::Expression(ValueGenerator::new_cuid()));
-        }
-
-        for uuid in &re_introspected_prisma_level_uuids {
-            new_data_model
-                .find_scalar_field_mut(&uuid.model, &uuid.field)
-                .default_value = Some(DefaultValue::Expression(ValueGenerator::new_uuid()));
+                .find_scalar_field_mut(&model_and_field.model, &model_and_field.field)
+                .default_value = Some(DefaultValue::Expression(generator.clone()));
         }
 
         for updated_at in &re_introspected_updated_at {
@@ -503,6 +525,63 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         warnings.push(warning_enriched_fields_with_ignore(&re_introspected_field_ignores));
     }
 
+    if !preserved_view_models.is_empty() {
+        warnings.push(warning_models_preserved_from_view(&preserved_view_models));
+    }
+
+    warnings
+}
+
+/// Re-introspection normally does a two-way merge between the previous
+/// schema and what was just introspected. When a common ancestor schema is
+/// also available (e.g. the last schema that was actually applied to the
+/// database), we can detect the case where the user's edits and the schema
+/// drift disagree about a model's documentation, and leave a conflict marker
+/// instead of silently picking one side.
+///
+/// Only the model-level `documentation` (the `///` comment) is merged this way,
+/// since it is the one piece of the datamodel that both re-introspection and
+/// users routinely rewrite independently.
+pub fn three_way_merge_documentation(
+    ancestor_data_model: &Datamodel,
+    previous_data_model: &Datamodel,
+    new_data_model: &mut Datamodel,
+) -> Vec<Warning> {
+    let mut warnings = vec![];
+    let mut conflicts = vec![];
+
+    for model in new_data_model.models() {
+        let ancestor_doc = ancestor_data_model
+            .find_model(&model.name)
+            .and_then(|m| m.documentation.clone());
+        let previous_doc = previous_data_model
+            .find_model(&model.name)
+            .and_then(|m| m.documentation.clone());
+        let new_doc = model.documentation.clone();
+
+        if previous_doc != ancestor_doc && new_doc != ancestor_doc && previous_doc != new_doc {
+            conflicts.push((
+                Model::new(&model.name),
+                previous_doc.unwrap_or_default(),
+                new_doc.unwrap_or_default(),
+            ));
+        }
+    }
+
+    for (model, local, introspected) in &conflicts {
+        let merged = format!(
+            "<<<<<<< local\n{}\n=======\n{}\n>>>>>>> introspected",
+            local, introspected
+        );
+
+        new_data_model.find_model_mut(&model.model).documentation = Some(merged);
+    }
+
+    if !conflicts.is_empty() {
+        let affected: Vec<Model> = conflicts.into_iter().map(|(model, _, _)| model).collect();
+        warnings.push(warning_documentation_merge_conflict(&affected));
+    }
+
     warnings
 }
 
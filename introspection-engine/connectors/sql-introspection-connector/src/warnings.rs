@@ -1,6 +1,39 @@
 use introspection_connector::Warning;
 use serde::Serialize;
 
+/// Stable numeric codes identifying each kind of introspection warning.
+///
+/// These are part of the public introspection API: the Prisma CLI and editors match on them to
+/// render fix-its for the corresponding `affected` payload, so a code must keep the same meaning
+/// forever once shipped, and must never be reused for a different warning.
+pub mod codes {
+    pub const MODELS_WITHOUT_IDENTIFIER: i8 = 1;
+    pub const FIELDS_WITH_EMPTY_NAMES: i8 = 2;
+    pub const UNSUPPORTED_TYPES: i8 = 3;
+    pub const ENUM_VALUES_WITH_EMPTY_NAMES: i8 = 4;
+    pub const DEFAULT_CUID_WARNING: i8 = 5;
+    pub const DEFAULT_UUID_WARNING: i8 = 6;
+    pub const ENRICHED_WITH_MAP_ON_MODEL: i8 = 7;
+    pub const ENRICHED_WITH_MAP_ON_FIELD: i8 = 8;
+    pub const ENRICHED_WITH_MAP_ON_ENUM: i8 = 9;
+    pub const ENRICHED_WITH_MAP_ON_ENUM_VALUE: i8 = 10;
+    pub const ENRICHED_WITH_CUID: i8 = 11;
+    pub const ENRICHED_WITH_UUID: i8 = 12;
+    pub const ENRICHED_WITH_UPDATED_AT: i8 = 13;
+    pub const MODELS_WITHOUT_COLUMNS: i8 = 14;
+    pub const ENRICHED_MODELS_WITH_IGNORE: i8 = 15;
+    pub const ENRICHED_FIELDS_WITH_IGNORE: i8 = 16;
+    pub const RELATION_TABLES_WITH_EXTRA_COLUMNS: i8 = 17;
+    pub const DOCUMENTATION_MERGE_CONFLICT: i8 = 18;
+    pub const REDUNDANT_INDEXES: i8 = 19;
+    pub const MODELS_PRESERVED_FROM_VIEW: i8 = 20;
+    pub const FOREIGN_KEYS_REFERENCING_OTHER_SCHEMA: i8 = 21;
+    pub const INTROSPECTION_FAILED: i8 = 22;
+    pub const PRISMA_1_SCALAR_LIST_TABLE_CONVERTED: i8 = 23;
+    pub const PRISMA_1_SCALAR_LIST_TABLE_UNSUPPORTED: i8 = 24;
+    pub const MEMORY_OPTIMIZED_TABLES: i8 = 25;
+}
+
 #[derive(Serialize, Debug, Clone)]
 pub struct Model {
     pub(crate) model: String,
@@ -64,7 +97,7 @@ impl EnumAndValue {
 
 pub fn warning_models_without_identifier(affected: &[Model]) -> Warning {
     Warning {
-        code: 1,
+        code: codes::MODELS_WITHOUT_IDENTIFIER,
         message: "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -72,7 +105,7 @@ pub fn warning_models_without_identifier(affected: &[Model]) -> Warning {
 
 pub fn warning_fields_with_empty_names(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 2,
+        code: codes::FIELDS_WITH_EMPTY_NAMES,
         message: "These fields were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` attribute."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -81,7 +114,7 @@ pub fn warning_fields_with_empty_names(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_unsupported_types(affected: &[ModelAndFieldAndType]) -> Warning {
     Warning {
-        code: 3,
+        code: codes::UNSUPPORTED_TYPES,
         message: "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -89,7 +122,7 @@ pub fn warning_unsupported_types(affected: &[ModelAndFieldAndType]) -> Warning {
 
 pub fn warning_enum_values_with_empty_names(affected: &[EnumAndValue]) -> Warning {
     Warning {
-        code: 4,
+        code: codes::ENUM_VALUES_WITH_EMPTY_NAMES,
         message: "These enum values were commented out because their names are currently not supported by Prisma. Please provide valid ones that match [a-zA-Z][a-zA-Z0-9_]* using the `@map` attribute."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -98,7 +131,7 @@ pub fn warning_enum_values_with_empty_names(affected: &[EnumAndValue]) -> Warnin
 
 pub fn warning_default_cuid_warning(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 5,
+        code: codes::DEFAULT_CUID_WARNING,
         message:
             "These id fields had a `@default(cuid())` added because we believe the schema was created by Prisma 1."
                 .into(),
@@ -108,7 +141,7 @@ pub fn warning_default_cuid_warning(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_default_uuid_warning(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 6,
+        code: codes::DEFAULT_UUID_WARNING,
         message:
             "These id fields had a `@default(uuid())` added because we believe the schema was created by Prisma 1."
                 .into(),
@@ -118,7 +151,7 @@ pub fn warning_default_uuid_warning(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_map_on_model(affected: &[Model]) -> Warning {
     Warning {
-        code: 7,
+        code: codes::ENRICHED_WITH_MAP_ON_MODEL,
         message: "These models were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -126,7 +159,7 @@ pub fn warning_enriched_with_map_on_model(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_with_map_on_field(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 8,
+        code: codes::ENRICHED_WITH_MAP_ON_FIELD,
         message: "These fields were enriched with `@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -134,7 +167,7 @@ pub fn warning_enriched_with_map_on_field(affected: &[ModelAndField]) -> Warning
 
 pub fn warning_enriched_with_map_on_enum(affected: &[Enum]) -> Warning {
     Warning {
-        code: 9,
+        code: codes::ENRICHED_WITH_MAP_ON_ENUM,
         message: "These enums were enriched with `@@map` information taken from the previous Prisma schema.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -142,7 +175,7 @@ pub fn warning_enriched_with_map_on_enum(affected: &[Enum]) -> Warning {
 
 pub fn warning_enriched_with_map_on_enum_value(affected: &[EnumAndValue]) -> Warning {
     Warning {
-        code: 10,
+        code: codes::ENRICHED_WITH_MAP_ON_ENUM_VALUE,
         message: "These enum values were enriched with `@map` information taken from the previous Prisma schema."
             .into(),
         affected: serde_json::to_value(&affected).unwrap(),
@@ -151,7 +184,7 @@ pub fn warning_enriched_with_map_on_enum_value(affected: &[EnumAndValue]) -> War
 
 pub fn warning_enriched_with_cuid(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 11,
+        code: codes::ENRICHED_WITH_CUID,
         message:
             "These id fields were enriched with `@default(cuid())` information taken from the previous Prisma schema."
                 .into(),
@@ -161,7 +194,7 @@ pub fn warning_enriched_with_cuid(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_uuid(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 12,
+        code: codes::ENRICHED_WITH_UUID,
         message:
             "These id fields were enriched with `@default(uuid())` information taken from the previous Prisma schema."
                 .into(),
@@ -171,7 +204,7 @@ pub fn warning_enriched_with_uuid(affected: &[ModelAndField]) -> Warning {
 
 pub fn warning_enriched_with_updated_at(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 13,
+        code: codes::ENRICHED_WITH_UPDATED_AT,
         message:
             "These DateTime fields were enriched with `@updatedAt` information taken from the previous Prisma schema."
                 .into(),
@@ -183,7 +216,7 @@ pub fn warning_enriched_with_updated_at(affected: &[ModelAndField]) -> Warning {
 //but maybe we should have warnings for ignored fields and models
 pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
     Warning {
-        code: 14,
+        code: codes::MODELS_WITHOUT_COLUMNS,
         message: "The following models were commented out as we could not retrieve columns for them. Please check your privileges.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -191,7 +224,7 @@ pub fn warning_models_without_columns(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_models_with_ignore(affected: &[Model]) -> Warning {
     Warning {
-        code: 15,
+        code: codes::ENRICHED_MODELS_WITH_IGNORE,
         message: "The following models were enriched with an @@ignore taken from your previous datamodel".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
@@ -199,8 +232,128 @@ pub fn warning_enriched_models_with_ignore(affected: &[Model]) -> Warning {
 
 pub fn warning_enriched_fields_with_ignore(affected: &[ModelAndField]) -> Warning {
     Warning {
-        code: 16,
+        code: codes::ENRICHED_FIELDS_WITH_IGNORE,
         message: "The following fields were enriched with an @ignore taken from your previous datamodel".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelWithSuggestion {
+    pub(crate) model: String,
+    pub(crate) suggestion: String,
+}
+
+impl ModelWithSuggestion {
+    pub fn new(model: &str, suggestion: &str) -> Self {
+        ModelWithSuggestion {
+            model: model.to_owned(),
+            suggestion: suggestion.to_owned(),
+        }
+    }
+}
+
+pub fn warning_relation_tables_with_extra_columns(affected: &[ModelWithSuggestion]) -> Warning {
+    Warning {
+        code: codes::RELATION_TABLES_WITH_EXTRA_COLUMNS,
+        message: "These tables look like join tables for many-to-many relations, but have extra columns so they were kept as regular models. Consider using the suggested explicit relation model instead.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_documentation_merge_conflict(affected: &[Model]) -> Warning {
+    Warning {
+        code: codes::DOCUMENTATION_MERGE_CONFLICT,
+        message: "These models had documentation comments that were changed both in your previous Prisma schema and by re-introspection. The two versions were merged with conflict markers, please resolve them manually.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_redundant_indexes(affected: &[ModelWithSuggestion]) -> Warning {
+    Warning {
+        code: codes::REDUNDANT_INDEXES,
+        message: "These indexes are redundant: their columns are a prefix of another index's columns on the same model, which already serves any query the redundant index would. Consider removing them.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_models_preserved_from_view(affected: &[Model]) -> Warning {
+    Warning {
+        code: codes::MODELS_PRESERVED_FROM_VIEW,
+        message: "These models were kept from your previous Prisma schema because they map to a database view. Views are not yet introspected, so their fields could not be re-checked against the database.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_memory_optimized_tables(affected: &[Model]) -> Warning {
+    Warning {
+        code: codes::MEMORY_OPTIMIZED_TABLES,
+        message: "These tables are memory-optimized in the database, which Prisma's datamodel cannot represent. If you migrate this schema, the resulting tables will not be memory-optimized.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModelAndCrossSchemaReference {
+    pub(crate) model: String,
+    pub(crate) fields: Vec<String>,
+    pub(crate) referenced_table: String,
+}
+
+impl ModelAndCrossSchemaReference {
+    pub fn new(model: &str, fields: &[String], referenced_table: &str) -> Self {
+        ModelAndCrossSchemaReference {
+            model: model.to_owned(),
+            fields: fields.to_owned(),
+            referenced_table: referenced_table.to_owned(),
+        }
+    }
+}
+
+pub fn warning_foreign_keys_referencing_other_schema(affected: &[ModelAndCrossSchemaReference]) -> Warning {
+    Warning {
+        code: codes::FOREIGN_KEYS_REFERENCING_OTHER_SCHEMA,
+        message: "These foreign keys reference a table that lives in a different schema than the one being introspected, so the relation could not be added. Prisma currently only introspects a single schema at a time.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct IntrospectionFailure {
+    pub(crate) reason: String,
+}
+
+impl IntrospectionFailure {
+    pub fn new(reason: impl Into<String>) -> Self {
+        IntrospectionFailure { reason: reason.into() }
+    }
+}
+
+/// Used by best-effort introspection when the connector could not describe the database at all,
+/// so that the caller gets back an (empty) result with a structured explanation instead of a
+/// hard error. Note that this can't point at individual offending tables: the SQL describers
+/// fetch a whole schema's columns, indexes and foreign keys in bulk rather than table by table,
+/// so a describe failure is necessarily a failure of the entire schema, not one table in it.
+pub fn warning_introspection_failed(affected: &[IntrospectionFailure]) -> Warning {
+    Warning {
+        code: codes::INTROSPECTION_FAILED,
+        message: "Introspection failed and best-effort mode returned this result instead of failing outright. The datamodel below is likely incomplete.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_prisma_1_scalar_list_table_converted(affected: &[ModelWithSuggestion]) -> Warning {
+    Warning {
+        code: codes::PRISMA_1_SCALAR_LIST_TABLE_CONVERTED,
+        message: "These Prisma 1 scalar list tables were replaced with a native list field on their model and commented out. The table still holds the existing data - run the generated SQL for each one to copy it into the new column, then drop the table.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
+
+pub fn warning_prisma_1_scalar_list_table_unsupported(affected: &[ModelWithSuggestion]) -> Warning {
+    Warning {
+        code: codes::PRISMA_1_SCALAR_LIST_TABLE_UNSUPPORTED,
+        message: "These Prisma 1 scalar list tables could not be converted to a native list field, since that is currently only supported on Postgres. They were left in the database and commented out of the datamodel - see the suggestion for each one.".into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
@@ -1,10 +1,20 @@
 use crate::error::Error;
 use datamodel::{Configuration, Datamodel};
-use introspection_connector::{ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput};
+use introspection_connector::{
+    ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput, Version,
+};
 use jsonrpc_core::BoxFuture;
 use jsonrpc_derive::rpc;
 use serde_derive::*;
-use sql_introspection_connector::SqlIntrospectionConnector;
+use sql_introspection_connector::{
+    warnings::{warning_introspection_failed, IntrospectionFailure},
+    SqlIntrospectionConnector,
+};
+
+#[cfg(feature = "mongodb")]
+use datamodel::common::provider_names::MONGODB_SOURCE_NAME;
+#[cfg(feature = "mongodb")]
+use mongodb_introspection_connector::MongoDbIntrospectionConnector;
 
 type RpcError = jsonrpc_core::Error;
 type RpcResult<T> = Result<T, RpcError>;
@@ -13,7 +23,7 @@ type RpcFutureResult<T> = BoxFuture<RpcResult<T>>;
 #[rpc]
 pub trait Rpc {
     #[rpc(name = "listDatabases")]
-    fn list_databases(&self, input: IntrospectionInput) -> RpcFutureResult<Vec<String>>;
+    fn list_databases(&self, input: ListDatabasesInput) -> RpcFutureResult<Vec<String>>;
 
     #[rpc(name = "getDatabaseMetadata")]
     fn get_database_metadata(&self, input: IntrospectionInput) -> RpcFutureResult<DatabaseMetadata>;
@@ -34,8 +44,8 @@ pub trait Rpc {
 pub struct RpcImpl;
 
 impl Rpc for RpcImpl {
-    fn list_databases(&self, input: IntrospectionInput) -> RpcFutureResult<Vec<String>> {
-        Box::pin(Self::list_databases_internal(input.schema))
+    fn list_databases(&self, input: ListDatabasesInput) -> RpcFutureResult<Vec<String>> {
+        Box::pin(Self::list_databases_internal(input))
     }
 
     fn get_database_metadata(&self, input: IntrospectionInput) -> RpcFutureResult<DatabaseMetadata> {
@@ -51,7 +61,12 @@ impl Rpc for RpcImpl {
     }
 
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput> {
-        Box::pin(Self::introspect_internal(input.schema, input.force))
+        Box::pin(Self::introspect_internal(
+            input.schema,
+            input.force,
+            input.best_effort,
+            input.prisma1_compatibility,
+        ))
     }
 
     fn debug_panic(&self) -> RpcFutureResult<()> {
@@ -60,23 +75,34 @@ impl Rpc for RpcImpl {
 }
 
 impl RpcImpl {
-    async fn load_connector(schema: &str) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
+    async fn load_connector(
+        schema: &str,
+        prisma1_compatibility: bool,
+    ) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)
             .map_err(|diagnostics| Error::DatamodelError(diagnostics.to_pretty_string("schema.prisma", schema)))?;
 
-        let url = config
+        let source = config
             .subject
             .datasources
             .first()
-            .ok_or_else(|| Error::Generic("There is no datasource in the schema.".into()))?
+            .ok_or_else(|| Error::Generic("There is no datasource in the schema.".into()))?;
+
+        let url = source
             .load_url(|key| std::env::var(key).ok())
             .map_err(|diagnostics| Error::DatamodelError(diagnostics.to_pretty_string("schema.prisma", schema)))?;
 
-        Ok((
-            config.subject,
-            url.clone(),
-            Box::new(SqlIntrospectionConnector::new(&url).await?),
-        ))
+        let connector: Box<dyn IntrospectionConnector> = match source.active_provider.as_str() {
+            #[cfg(feature = "mongodb")]
+            MONGODB_SOURCE_NAME => Box::new(MongoDbIntrospectionConnector::new(&url).await?),
+            _ => Box::new(
+                SqlIntrospectionConnector::new(&url)
+                    .await?
+                    .with_prisma1_compatibility(prisma1_compatibility),
+            ),
+        };
+
+        Ok((config.subject, url, connector))
     }
 
     pub async fn catch<O>(fut: impl std::future::Future<Output = ConnectorResult<O>>) -> RpcResult<O> {
@@ -86,8 +112,13 @@ impl RpcImpl {
         }
     }
 
-    pub async fn introspect_internal(schema: String, force: bool) -> RpcResult<IntrospectionResultOutput> {
-        let (config, url, connector) = RpcImpl::load_connector(&schema).await?;
+    pub async fn introspect_internal(
+        schema: String,
+        force: bool,
+        best_effort: bool,
+        prisma1_compatibility: bool,
+    ) -> RpcResult<IntrospectionResultOutput> {
+        let (config, url, connector) = RpcImpl::load_connector(&schema, prisma1_compatibility).await?;
 
         let input_data_model = if !force {
             Self::parse_datamodel(&schema)?
@@ -110,6 +141,21 @@ impl RpcImpl {
                     })
                 }
             }
+            // In best-effort mode, a hard introspection failure (e.g. an unusually
+            // heterogeneous legacy database the connector can't fully describe) is downgraded
+            // to a structured warning on an empty result instead of failing the command
+            // outright. We can't isolate and skip just the offending table here: the SQL
+            // describers fetch a whole schema's columns, indexes and foreign keys with bulk
+            // queries rather than one query per table, so a describe failure is necessarily a
+            // failure of the whole schema. This still gives callers something to work with
+            // instead of nothing.
+            Err(e) if best_effort => Ok(IntrospectionResultOutput {
+                datamodel: datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config),
+                warnings: vec![warning_introspection_failed(&[IntrospectionFailure::new(
+                    e.to_string(),
+                )])],
+                version: Version::NonPrisma,
+            }),
             Err(e) => Err(Error::from(e)),
         };
 
@@ -125,23 +171,33 @@ impl RpcImpl {
         Ok(final_dm)
     }
 
-    pub async fn list_databases_internal(schema: String) -> RpcResult<Vec<String>> {
-        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
-        RpcImpl::catch(connector.list_databases()).await
+    pub async fn list_databases_internal(input: ListDatabasesInput) -> RpcResult<Vec<String>> {
+        let (_, _, connector) = RpcImpl::load_connector(&input.schema, false).await?;
+        let mut databases = RpcImpl::catch(connector.list_databases()).await?;
+
+        if let Some(skip) = input.skip {
+            databases = databases.into_iter().skip(skip).collect();
+        }
+
+        if let Some(take) = input.take {
+            databases.truncate(take);
+        }
+
+        Ok(databases)
     }
 
     pub async fn get_database_description_internal(schema: String) -> RpcResult<String> {
-        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
+        let (_, _, connector) = RpcImpl::load_connector(&schema, false).await?;
         RpcImpl::catch(connector.get_database_description()).await
     }
 
     pub async fn get_database_version_internal(schema: String) -> RpcResult<String> {
-        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
+        let (_, _, connector) = RpcImpl::load_connector(&schema, false).await?;
         RpcImpl::catch(connector.get_database_version()).await
     }
 
     pub async fn get_database_metadata_internal(schema: String) -> RpcResult<DatabaseMetadata> {
-        let (_, _, connector) = RpcImpl::load_connector(&schema).await?;
+        let (_, _, connector) = RpcImpl::load_connector(&schema, false).await?;
         RpcImpl::catch(connector.get_metadata()).await
     }
 
@@ -155,8 +211,32 @@ pub struct IntrospectionInput {
     pub(crate) schema: String,
     #[serde(default = "default_false")]
     pub(crate) force: bool,
+    /// If introspection fails, return an empty datamodel with a warning explaining why instead
+    /// of failing the whole command. Intended for very heterogeneous databases where a single
+    /// unsupported construct would otherwise block introspection entirely.
+    #[serde(default = "default_false")]
+    pub(crate) best_effort: bool,
+    /// Opt-in Prisma 1 compatibility mode: on top of the usual id-default detection, also detects
+    /// Prisma 1 scalar list tables (the `nodeId`/`position`/`value` join tables Prisma 1 used to
+    /// emulate list fields) and either turns them into native list fields or reports why it
+    /// couldn't, together with the SQL needed to migrate the data by hand. Off by default since it
+    /// only makes sense when introspecting a database that used to be served by Prisma 1.
+    #[serde(default = "default_false")]
+    pub(crate) prisma1_compatibility: bool,
 }
 
 fn default_false() -> bool {
     false
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDatabasesInput {
+    pub(crate) schema: String,
+    /// Number of database names to skip from the start of the list, for paging through servers
+    /// with a large number of databases/schemas instead of always returning the full list.
+    #[serde(default)]
+    pub(crate) skip: Option<usize>,
+    /// Maximum number of database names to return after skipping.
+    #[serde(default)]
+    pub(crate) take: Option<usize>,
+}
@@ -81,6 +81,22 @@ impl TestApi {
         ))
     }
 
+    /// Introspects a previously captured `get_database_description` snapshot instead of the live
+    /// database, so a test can pin a schema shape from an older engine version and assert that
+    /// introspecting it still produces the same datamodel.
+    pub fn introspect_from_database_description(&self, description: &str) -> Result<String> {
+        let introspection_result = sql_introspection_connector::introspect_from_database_description(
+            description,
+            self.sql_family(),
+            &Datamodel::new(),
+        )?;
+
+        Ok(datamodel::render_datamodel_and_config_to_string(
+            &introspection_result.data_model,
+            &self.configuration(),
+        ))
+    }
+
     pub fn is_cockroach(&self) -> bool {
         self.tags().contains(Tags::Cockroach)
     }
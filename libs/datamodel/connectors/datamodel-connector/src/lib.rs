@@ -188,6 +188,14 @@ pub enum ConnectorCapability {
     JsonFilteringJsonPath,
     JsonFilteringArrayPath,
     CompoundIds,
+    MongoDbRawQueries,
+    TextSearch,
+
+    /// The connector can render `QueryArguments.index_hint` into the SQL it generates (e.g.
+    /// MySQL's `USE INDEX`, MSSQL's `WITH (INDEX(...))`). No connector implements this yet, so
+    /// `@@allowIndexHints` is validated at the datamodel level but the `indexHint` argument stays
+    /// hidden from the GraphQL schema until one does.
+    IndexHints,
 }
 
 /// Contains all capabilities that the connector is able to serve.
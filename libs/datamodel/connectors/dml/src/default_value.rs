@@ -46,11 +46,26 @@ impl DefaultValue {
         matches!(self, DefaultValue::Expression(generator) if generator.name == "cuid")
     }
 
+    /// Does this match @default(cuid2(_))?
+    pub fn is_cuid2(&self) -> bool {
+        matches!(self, DefaultValue::Expression(generator) if generator.name == "cuid2")
+    }
+
+    /// Does this match @default(nanoid(_))?
+    pub fn is_nanoid(&self) -> bool {
+        matches!(self, DefaultValue::Expression(generator) if generator.name == "nanoid")
+    }
+
     /// Does this match @default(dbgenerated(_))?
     pub fn is_dbgenerated(&self) -> bool {
         matches!(self, DefaultValue::Expression(generator) if generator.name == "dbgenerated")
     }
 
+    /// Does this match @default(env(_))?
+    pub fn is_env(&self) -> bool {
+        matches!(self, DefaultValue::Expression(generator) if generator.name == "env")
+    }
+
     /// Does this match @default(now())?
     pub fn is_now(&self) -> bool {
         matches!(self, DefaultValue::Expression(generator) if generator.name == "now")
@@ -87,6 +102,7 @@ pub struct ValueGenerator {
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> Result<Self, String> {
         let generator = ValueGeneratorFn::new(name.as_ref())?;
+        generator.check_args(&args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -99,6 +115,10 @@ impl ValueGenerator {
         ValueGenerator::new("dbgenerated".to_owned(), vec![PrismaValue::String(description)]).unwrap()
     }
 
+    pub fn new_env(var_name: String) -> Self {
+        ValueGenerator::new("env".to_owned(), vec![PrismaValue::String(var_name)]).unwrap()
+    }
+
     pub fn new_now() -> Self {
         ValueGenerator::new("now".to_owned(), vec![]).unwrap()
     }
@@ -111,6 +131,19 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_uuid_v7() -> Self {
+        ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap()
+    }
+
+    pub fn new_cuid2() -> Self {
+        ValueGenerator::new("cuid2".to_owned(), vec![]).unwrap()
+    }
+
+    pub fn new_nanoid(length: Option<i64>) -> Self {
+        let args = length.into_iter().map(PrismaValue::Int).collect();
+        ValueGenerator::new("nanoid".to_owned(), args).unwrap()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -129,7 +162,7 @@ impl ValueGenerator {
 
     #[cfg(feature = "default_generators")]
     pub fn generate(&self) -> Option<PrismaValue> {
-        self.generator.invoke()
+        self.generator.invoke(&self.args)
     }
 
     pub fn check_compatibility_with_scalar_type(&self, scalar_type: ScalarType) -> Result<(), String> {
@@ -157,31 +190,60 @@ impl ValueGenerator {
 pub enum ValueGeneratorFn {
     Uuid,
     Cuid,
+    Cuid2,
+    Nanoid,
     Now,
     Autoincrement,
     DbGenerated,
+    Env,
 }
 
 impl ValueGeneratorFn {
     fn new(name: &str) -> std::result::Result<Self, String> {
         match name {
             "cuid" => Ok(Self::Cuid),
+            "cuid2" => Ok(Self::Cuid2),
+            "nanoid" => Ok(Self::Nanoid),
             "uuid" => Ok(Self::Uuid),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
+            "env" => Ok(Self::Env),
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
 
+    /// Validates the arguments accepted by generators that take an optional numeric argument.
+    /// The other generators keep their pre-existing, unvalidated argument handling.
+    fn check_args(&self, args: &[PrismaValue]) -> Result<(), String> {
+        match self {
+            Self::Uuid => match args {
+                [] | [PrismaValue::Int(4)] | [PrismaValue::Int(7)] => Ok(()),
+                _ => Err("uuid() takes either no argument, or a single argument that is either `4` or `7`.".to_owned()),
+            },
+            Self::Nanoid => match args {
+                [] => Ok(()),
+                [PrismaValue::Int(length)] if *length > 0 => Ok(()),
+                _ => Err(
+                    "nanoid() takes either no argument, or a single positive integer argument for the length."
+                        .to_owned(),
+                ),
+            },
+            _ => Ok(()),
+        }
+    }
+
     #[cfg(feature = "default_generators")]
-    fn invoke(&self) -> Option<PrismaValue> {
+    fn invoke(&self, args: &[PrismaValue]) -> Option<PrismaValue> {
         match self {
-            Self::Uuid => Some(Self::generate_uuid()),
+            Self::Uuid => Some(Self::generate_uuid(args)),
             Self::Cuid => Some(Self::generate_cuid()),
+            Self::Cuid2 => Some(Self::generate_cuid2()),
+            Self::Nanoid => Some(Self::generate_nanoid(args)),
             Self::Now => Some(Self::generate_now()),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Env => Self::generate_env(args),
         }
     }
 
@@ -190,10 +252,15 @@ impl ValueGeneratorFn {
         match (self, scalar_type) {
             (Self::Uuid, ScalarType::String) => true,
             (Self::Cuid, ScalarType::String) => true,
+            (Self::Cuid2, ScalarType::String) => true,
+            (Self::Nanoid, ScalarType::String) => true,
             (Self::Now, ScalarType::DateTime) => true,
             (Self::Autoincrement, ScalarType::Int) => true,
             (Self::Autoincrement, ScalarType::BigInt) => true,
             (Self::DbGenerated, _) => true,
+            // The environment variable always holds a string; resolving it into another scalar
+            // type would need a generic string -> PrismaValue conversion we don't have yet.
+            (Self::Env, ScalarType::String) => true,
             _ => false,
         }
     }
@@ -204,14 +271,91 @@ impl ValueGeneratorFn {
     }
 
     #[cfg(feature = "default_generators")]
-    fn generate_uuid() -> PrismaValue {
-        PrismaValue::Uuid(uuid::Uuid::new_v4())
+    fn generate_uuid(args: &[PrismaValue]) -> PrismaValue {
+        match args.first() {
+            Some(PrismaValue::Int(7)) => PrismaValue::Uuid(Self::generate_uuid_v7()),
+            _ => PrismaValue::Uuid(uuid::Uuid::new_v4()),
+        }
+    }
+
+    /// Builds a UUID v7 (time-ordered) by hand: the pinned `uuid` crate predates native v7
+    /// support, so this lays out the 16 bytes directly - a 48-bit big-endian Unix millisecond
+    /// timestamp, followed by random bytes with the version and variant nibbles patched in.
+    #[cfg(feature = "default_generators")]
+    fn generate_uuid_v7() -> uuid::Uuid {
+        use rand::Rng;
+
+        let millis = chrono::Utc::now().timestamp_millis() as u64;
+        let mut bytes = [0u8; 16];
+        bytes[0..6].copy_from_slice(&millis.to_be_bytes()[2..8]);
+
+        rand::thread_rng().fill(&mut bytes[6..]);
+
+        bytes[6] = (bytes[6] & 0x0f) | 0x70; // version 7
+        bytes[8] = (bytes[8] & 0x3f) | 0x80; // variant 10
+
+        uuid::Uuid::from_bytes(bytes)
+    }
+
+    /// A simplified stand-in for the upstream cuid2 algorithm (which mixes a session
+    /// fingerprint, a monotonic counter and a hash over entropy) until a dedicated
+    /// implementation is vendored. It produces cuid2-shaped ids - a lowercase letter followed by
+    /// 23 base36 characters - but doesn't reproduce the collision-resistance properties of the
+    /// real algorithm.
+    #[cfg(feature = "default_generators")]
+    fn generate_cuid2() -> PrismaValue {
+        use rand::Rng;
+
+        const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        let mut rng = rand::thread_rng();
+
+        let first = (b'a' + rng.gen_range(0, 26)) as char;
+        let rest: String = (0..23)
+            .map(|_| ALPHABET[rng.gen_range(0, ALPHABET.len())] as char)
+            .collect();
+
+        PrismaValue::String(format!("{}{}", first, rest))
+    }
+
+    #[cfg(feature = "default_generators")]
+    fn generate_nanoid(args: &[PrismaValue]) -> PrismaValue {
+        use rand::Rng;
+
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-";
+
+        let length = match args.first() {
+            Some(PrismaValue::Int(length)) => *length as usize,
+            _ => 21,
+        };
+
+        let mut rng = rand::thread_rng();
+        let id: String = (0..length)
+            .map(|_| ALPHABET[rng.gen_range(0, ALPHABET.len())] as char)
+            .collect();
+
+        PrismaValue::String(id)
     }
 
     #[cfg(feature = "default_generators")]
     fn generate_now() -> PrismaValue {
         PrismaValue::DateTime(chrono::Utc::now().into())
     }
+
+    /// Resolves `@default(env("VAR"))` by reading the named environment variable. This happens
+    /// once per default value evaluation (i.e. once per query needing it), same as `now()`, `uuid()`
+    /// and `cuid()`, rather than being baked into the datamodel at load time: the field keeps the
+    /// same schema across environments, and the value can change with the process environment
+    /// without needing a schema change or a migration. It is never pushed to the database as a
+    /// column default (see `sql_schema_calculator`'s handling of `DefaultValue::Expression`), so
+    /// changing the environment variable does not require a migration either. Returns `None`,
+    /// same as `dbgenerated()`/`autoincrement()`, if the variable is unset - in which case the
+    /// field must be provided explicitly, exactly as it would for a missing required argument.
+    #[cfg(feature = "default_generators")]
+    fn generate_env(args: &[PrismaValue]) -> Option<PrismaValue> {
+        let var_name = args.first()?.as_string()?;
+
+        std::env::var(var_name).ok().map(PrismaValue::String)
+    }
 }
 
 impl PartialEq for ValueGenerator {
@@ -264,6 +408,30 @@ mod tests {
         assert!(!cuid_default.is_now());
     }
 
+    #[test]
+    fn default_value_is_uuid_v7() {
+        let uuid_default = DefaultValue::Expression(ValueGenerator::new_uuid_v7());
+
+        assert!(uuid_default.is_uuid());
+        assert!(!uuid_default.is_autoincrement());
+    }
+
+    #[test]
+    fn default_value_is_cuid2() {
+        let cuid2_default = DefaultValue::Expression(ValueGenerator::new_cuid2());
+
+        assert!(cuid2_default.is_cuid2());
+        assert!(!cuid2_default.is_cuid());
+    }
+
+    #[test]
+    fn default_value_is_nanoid() {
+        let nanoid_default = DefaultValue::Expression(ValueGenerator::new_nanoid(Some(10)));
+
+        assert!(nanoid_default.is_nanoid());
+        assert!(!nanoid_default.is_cuid2());
+    }
+
     #[test]
     fn default_value_is_dbgenerated() {
         let db_generated_default = DefaultValue::Expression(ValueGenerator::new_dbgenerated("test".to_string()));
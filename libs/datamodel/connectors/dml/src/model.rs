@@ -18,12 +18,18 @@ pub struct Model {
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The database constraint name of the primary key, set via `@@id(..., map: ...)`.
+    pub primary_key_name: Option<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
     /// Indicates if this model has to be ignored by the Client.
     pub is_ignored: bool,
+    /// Set via `@@allowIndexHints`. Opts the model into accepting a per-query `indexHint`
+    /// argument naming one of its indexes, for pathological queries where the planner needs a
+    /// nudge towards a specific index.
+    pub allow_index_hints: bool,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
@@ -32,6 +38,15 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// The database constraint name, set via `@@unique(..., map: ...)` or `@@index(..., map: ...)`.
+    pub db_name: Option<String>,
+    /// Whether multiple `NULL`s are considered distinct from one another for
+    /// the purposes of the unique constraint, set via `@@unique(..., nullsNotDistinct: true)`.
+    /// Only meaningful for unique indexes, currently only supported on Postgres 15+.
+    pub nulls_not_distinct: bool,
+    /// The `WHERE` clause of a partial index, set via `@@index([...], where: "...")`.
+    /// Only supported on Postgres.
+    pub predicate: Option<String>,
 }
 
 impl IndexDefinition {
@@ -66,12 +81,14 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            primary_key_name: None,
             documentation: None,
             database_name,
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
             is_ignored: false,
+            allow_index_hints: false,
         }
     }
 
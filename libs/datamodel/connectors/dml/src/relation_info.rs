@@ -39,6 +39,15 @@ impl RelationInfo {
 }
 
 /// Describes what happens when related nodes are deleted.
+///
+/// This only ever controls the `ON DELETE` clause of a real foreign key
+/// constraint generated by the migration engine (see
+/// `sql_schema_calculator::calculate_on_delete_action`, which currently
+/// derives `CASCADE`/`SET NULL` from field arity rather than reading this
+/// value). There is no `relationMode` / referential-action-emulation
+/// concept in this codebase: the query engine's write graph doesn't know
+/// about foreign key actions at all, so there's nowhere to add `SetDefault`
+/// or `SetNull` emulation without first building that layer from scratch.
 #[derive(Debug, Copy, PartialEq, Clone)]
 pub enum OnDeleteStrategy {
     Cascade,
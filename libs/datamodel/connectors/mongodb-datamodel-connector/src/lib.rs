@@ -31,6 +31,8 @@ impl MongoDbDatamodelConnector {
             ConnectorCapability::CreateSkipDuplicates,
             ConnectorCapability::ScalarLists,
             ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::MongoDbRawQueries,
+            ConnectorCapability::TextSearch,
         ];
 
         let native_types = mongodb_types::available_types();
@@ -57,6 +57,7 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrement,
             ConnectorCapability::CompoundIds,
+            ConnectorCapability::InsensitiveFilters,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![
@@ -0,0 +1,76 @@
+use super::*;
+use crate::diagnostics::{DatamodelError, Diagnostics};
+use std::collections::{HashMap, HashSet};
+
+const MIXIN_ATTRIBUTE_NAME: &str = "mixin";
+
+/// Expands `mixin` declarations referenced via `@@mixin("Name")` into the fields of the
+/// models that reference them, then drops the mixin declarations from the AST.
+///
+/// Mixins only exist to let a set of fields (id, createdAt, updatedAt, tenantId, ...) be
+/// written once and reused across models. By the time validation, DMMF generation or
+/// migrations run, they are gone: every model that included one looks exactly as if its
+/// fields had been written out by hand.
+pub(crate) fn expand_mixins(mut schema: SchemaAst, diagnostics: &mut Diagnostics) -> SchemaAst {
+    let mixins: HashMap<String, Model> = schema
+        .tops
+        .iter()
+        .filter_map(|top| match top {
+            Top::Model(model) if model.is_mixin => Some((model.name.name.clone(), model.clone())),
+            _ => None,
+        })
+        .collect();
+
+    for top in schema.tops.iter_mut() {
+        let model = match top {
+            Top::Model(model) if !model.is_mixin => model,
+            _ => continue,
+        };
+
+        let mut used_mixin_attributes = HashSet::new();
+
+        for (idx, attribute) in model.attributes.iter().enumerate() {
+            if attribute.name.name != MIXIN_ATTRIBUTE_NAME {
+                continue;
+            }
+
+            used_mixin_attributes.insert(idx);
+
+            let mixin_name = match attribute.arguments.first().and_then(|arg| arg.value.as_string_value()) {
+                Some((name, _)) => name,
+                None => {
+                    diagnostics.push_error(DatamodelError::new_validation_error(
+                        "`@@mixin` requires the name of the mixin to include as a string argument.",
+                        attribute.span,
+                    ));
+                    continue;
+                }
+            };
+
+            match mixins.get(mixin_name) {
+                Some(mixin) => {
+                    let mut fields = mixin.fields.clone();
+                    fields.append(&mut model.fields);
+                    model.fields = fields;
+                }
+                None => diagnostics.push_error(DatamodelError::new_validation_error(
+                    &format!("Mixin `{}` is used but not defined.", mixin_name),
+                    attribute.span,
+                )),
+            }
+        }
+
+        let mut idx = 0;
+        model.attributes.retain(|_| {
+            let keep = !used_mixin_attributes.contains(&idx);
+            idx += 1;
+            keep
+        });
+    }
+
+    schema
+        .tops
+        .retain(|top| !matches!(top, Top::Model(model) if model.is_mixin));
+
+    schema
+}
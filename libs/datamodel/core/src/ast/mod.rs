@@ -12,6 +12,7 @@ mod field;
 mod generator_config;
 mod helper;
 mod identifier;
+mod mixins;
 mod model;
 mod parser;
 mod renderer;
@@ -35,6 +36,7 @@ pub use span::Span;
 pub use top::Top;
 pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdentifier, WithName, WithSpan};
 
+pub(crate) use mixins::expand_mixins;
 pub(crate) use model::{FieldId, Model};
 pub(crate) use parser::parse_schema;
 pub(crate) use renderer::Renderer;
@@ -18,6 +18,10 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Declared with the `mixin` keyword. Mixins are expanded into the models
+    /// that reference them via `@@mixin(...)` before validation and are not
+    /// themselves part of the resulting datamodel.
+    pub is_mixin: bool,
 }
 
 impl Model {
@@ -14,6 +14,7 @@ pub fn parse_model(token: &Token<'_>) -> Result<Model, Diagnostics> {
     let mut attributes: Vec<Attribute> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
+    let mut is_mixin = false;
 
     for current in token.relevant_children() {
         match current.as_rule() {
@@ -21,6 +22,7 @@ pub fn parse_model(token: &Token<'_>) -> Result<Model, Diagnostics> {
                 "Model declarations have to be indicated with the `model` keyword.",
                 Span::from_pest(current.as_span()),
             )),
+            Rule::MIXIN_KEYWORD => is_mixin = true,
             Rule::non_empty_identifier => name = Some(current.to_id()),
             Rule::block_level_attribute => attributes.push(parse_attribute(&current)),
             Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current) {
@@ -46,6 +48,7 @@ pub fn parse_model(token: &Token<'_>) -> Result<Model, Diagnostics> {
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_mixin,
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
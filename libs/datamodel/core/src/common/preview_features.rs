@@ -53,6 +53,13 @@ features!(
     OrderByAggregateGroup,
     FilterJson,
     PlanetScaleMode,
+    FullTextSearch,
+    // Reserved ahead of an actual Oracle connector existing. There is no `DatasourceProvider`
+    // for the `oracle` provider string yet - that needs a quaint driver plus schema-describer,
+    // migration-flavour and introspection support, none of which live in this crate - so this
+    // flag currently has no effect beyond being parsed. It's here so schemas experimenting with
+    // Oracle can already opt in without a later flag rename.
+    OracleDatabase,
 );
 
 // Mapping of which active, deprecated and hidden
@@ -69,8 +76,9 @@ pub static GENERATOR: Lazy<FeatureMap> = Lazy::new(|| {
             OrderByAggregateGroup,
             FilterJson,
             PlanetScaleMode,
+            FullTextSearch,
         ])
-        .with_hidden(vec![MongoDb])
+        .with_hidden(vec![MongoDb, OracleDatabase])
         .with_deprecated(vec![
             AtomicNumberOperations,
             AggregateApi,
@@ -4,3 +4,6 @@ pub const POSTGRES_SOURCE_NAME_HEROKU: &str = "postgres";
 pub const MYSQL_SOURCE_NAME: &str = "mysql";
 pub const MSSQL_SOURCE_NAME: &str = "sqlserver";
 pub const MONGODB_SOURCE_NAME: &str = "mongodb";
+// Not yet wired up to a `DatasourceProvider`/`Connector` - see the `OracleDatabase` preview
+// feature doc comment for why the provider string is reserved ahead of the connector existing.
+pub const ORACLE_SOURCE_NAME: &str = "oracle";
@@ -22,6 +22,64 @@ pub struct Datasource {
     pub(crate) shadow_database_url: Option<(StringFromEnvVar, Span)>,
     /// Whether planetScaleMode = true was provided
     pub planet_scale_mode: bool,
+    /// TLS options for connecting to the database, if configured.
+    pub tls: Option<TlsOptions>,
+    /// Connection pool configuration, if configured.
+    pub pool_options: Option<PoolOptions>,
+    /// Per-connection session settings, if configured.
+    pub session_options: Option<SessionOptions>,
+}
+
+/// Structured TLS configuration for a datasource. Parsed and validated here; not yet wired into
+/// any engine's connection setup (see `get_tls_options_arg`'s scope note), so setting these
+/// currently has no effect on the connection actually opened.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsOptions {
+    /// Path to a PEM-encoded root certificate, or an inline PEM string.
+    pub root_cert: Option<String>,
+    /// Path to a PEM-encoded client certificate, for mutual TLS.
+    pub client_cert_path: Option<String>,
+    /// Path to the PEM-encoded private key matching `client_cert_path`.
+    pub client_key_path: Option<String>,
+    /// Whether to verify the server's certificate. Defaults to `true`;
+    /// only meant to be disabled for local development.
+    pub verify: bool,
+}
+
+/// Structured connection pool configuration for a datasource. These mirror the query string
+/// parameters the underlying database drivers already understand (`connection_limit`,
+/// `pool_timeout`, `socket_timeout`, `statement_cache_size`, `pgbouncer`), validated here instead
+/// of at the URL level so that mistakes (a non-numeric `connectionLimit`, for instance) are caught
+/// while parsing the schema rather than when a connection is first opened.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoolOptions {
+    /// The maximum number of connections the pool is allowed to open.
+    pub connection_limit: Option<u32>,
+    /// The number of seconds to wait for a connection from the pool before timing out.
+    pub pool_timeout: Option<u32>,
+    /// The number of seconds to wait for a query on the socket before timing out.
+    pub socket_timeout: Option<u32>,
+    /// The number of prepared statements the driver caches per connection. `0` disables the
+    /// cache. Postgres and MySQL only.
+    pub statement_cache_size: Option<u32>,
+    /// Whether the datasource is behind PgBouncer in transaction mode. Postgres only.
+    pub pgbouncer: Option<bool>,
+}
+
+/// Structured per-connection session settings for a datasource, meant to be applied once when a
+/// new pooled connection is opened, before it is handed out to the query engine. Each field is
+/// specific to one provider: `statement_timeout` and `search_path` are Postgres-only, `sql_mode`
+/// is MySQL-only. They're validated as non-empty strings here rather than left as opaque
+/// connection string fragments, but which provider a field applies to is not cross-checked
+/// against `Datasource::active_provider` yet (`pool_options.pgbouncer` has the same gap).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionOptions {
+    /// Postgres only: the value to `SET statement_timeout` to for the session, e.g. `"5s"`.
+    pub statement_timeout: Option<String>,
+    /// Postgres only: the schema search path to set for the session.
+    pub search_path: Option<String>,
+    /// MySQL only: the `sql_mode` to set for the session.
+    pub sql_mode: Option<String>,
 }
 
 impl std::fmt::Debug for Datasource {
@@ -34,6 +92,9 @@ impl std::fmt::Debug for Datasource {
             .field("documentation", &self.documentation)
             .field("active_connector", &&"...")
             .field("shadow_database_url", &self.shadow_database_url)
+            .field("tls", &self.tls)
+            .field("pool_options", &self.pool_options)
+            .field("session_options", &self.session_options)
             .finish()
     }
 }
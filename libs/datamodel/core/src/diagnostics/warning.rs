@@ -12,6 +12,8 @@ use thiserror::Error;
 pub enum DatamodelWarning {
   #[error("Preview feature \"{}\" is deprecated. The functionality can be used without specifying it as a preview feature.", preview_feature)]
   DeprecatedPreviewFeature { preview_feature: String, span: Span },
+  #[error("The `shadowDatabaseUrl` datasource property has no effect on SQLite: shadow database operations always run against a private in-memory database, so no configured URL is ever needed.")]
+  ShadowDatabaseUrlIgnoredOnSqlite { span: Span },
 }
 
 #[rustfmt::skip]
@@ -23,9 +25,14 @@ impl DatamodelWarning {
     }
   }
 
+  pub fn new_shadow_database_url_ignored_on_sqlite_warning(span: Span) -> DatamodelWarning {
+    DatamodelWarning::ShadowDatabaseUrlIgnoredOnSqlite { span }
+  }
+
   pub fn span(&self) -> Span {
     match self {
      DatamodelWarning::DeprecatedPreviewFeature { span, .. } => *span,
+     DatamodelWarning::ShadowDatabaseUrlIgnoredOnSqlite { span } => *span,
     }
   }
 
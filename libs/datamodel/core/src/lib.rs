@@ -138,6 +138,9 @@ fn parse_datamodel_internal(
 ) -> Result<Validated<(Configuration, Datamodel)>, diagnostics::Diagnostics> {
     let mut diagnostics = diagnostics::Diagnostics::new();
     let ast = ast::parse_schema(datamodel_string)?;
+    let ast = ast::expand_mixins(ast, &mut diagnostics);
+
+    diagnostics.to_result()?;
 
     let generators = GeneratorLoader::load_generators_from_ast(&ast, &mut diagnostics);
     let preview_features = preview_features(&generators);
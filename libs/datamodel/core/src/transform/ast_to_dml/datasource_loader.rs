@@ -8,18 +8,34 @@ use super::{
 };
 use crate::{
     ast::SourceConfig,
-    diagnostics::{DatamodelError, Diagnostics},
+    diagnostics::{DatamodelError, DatamodelWarning, Diagnostics},
+};
+use crate::{
+    ast::Span,
+    common::{preview_features::PreviewFeature, provider_names::SQLITE_SOURCE_NAME},
+    configuration::StringFromEnvVar,
 };
-use crate::{ast::Span, common::preview_features::PreviewFeature, configuration::StringFromEnvVar};
 use crate::{
     ast::{self},
-    Datasource,
+    Datasource, PoolOptions, SessionOptions, TlsOptions,
 };
 use std::collections::{HashMap, HashSet};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
 const SHADOW_DATABASE_URL_KEY: &str = "shadowDatabaseUrl";
 const URL_KEY: &str = "url";
+const TLS_CA_CERT_KEY: &str = "tlsCaCert";
+const TLS_CLIENT_CERT_KEY: &str = "tlsClientCertPath";
+const TLS_CLIENT_KEY_KEY: &str = "tlsClientKeyPath";
+const TLS_VERIFY_KEY: &str = "tlsVerify";
+const CONNECTION_LIMIT_KEY: &str = "connectionLimit";
+const POOL_TIMEOUT_KEY: &str = "poolTimeout";
+const SOCKET_TIMEOUT_KEY: &str = "socketTimeout";
+const STATEMENT_CACHE_SIZE_KEY: &str = "statementCacheSize";
+const PGBOUNCER_KEY: &str = "pgbouncer";
+const STATEMENT_TIMEOUT_KEY: &str = "statementTimeout";
+const SEARCH_PATH_KEY: &str = "searchPath";
+const SQL_MODE_KEY: &str = "sqlMode";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -158,6 +174,10 @@ impl DatasourceLoader {
                 None
             };
 
+        let tls = get_tls_options_arg(&args, source_name, diagnostics);
+        let pool_options = get_pool_options_arg(&args, source_name, diagnostics);
+        let session_options = get_session_options_arg(&args, source_name, diagnostics);
+
         preview_features_guardrail(&args, diagnostics);
 
         let documentation = ast_source.documentation.as_ref().map(|comment| comment.text.clone());
@@ -173,6 +193,12 @@ impl DatasourceLoader {
             }
         };
 
+        if let (Some((_, span)), SQLITE_SOURCE_NAME) = (&shadow_database_url, datasource_provider.canonical_name()) {
+            diagnostics.push_warning(DatamodelWarning::new_shadow_database_url_ignored_on_sqlite_warning(
+                *span,
+            ));
+        }
+
         Some(Datasource {
             name: source_name.to_string(),
             provider: provider.to_owned(),
@@ -183,6 +209,9 @@ impl DatasourceLoader {
             active_connector: datasource_provider.connector(),
             shadow_database_url,
             planet_scale_mode: get_planet_scale_mode_arg(&args, preview_features, ast_source, diagnostics),
+            tls,
+            pool_options,
+            session_options,
         })
     }
 
@@ -248,6 +277,181 @@ fn get_planet_scale_mode_arg(
     }
 }
 
+/// Parses the structured TLS options (`tlsCaCert`, `tlsClientCertPath`,
+/// `tlsClientKeyPath`, `tlsVerify`), if any are present on the datasource.
+///
+/// Scope note: this only gets the settings as far as the parsed datamodel, the same as
+/// `pool_options` and `session_options`. Actually having a connection use these instead of
+/// nothing at all would mean wiring `TlsOptions` through each engine's `FromSource::from_source`,
+/// mapping onto whatever TLS query parameters quaint accepts for that driver (they're not the
+/// same shape per driver - e.g. Postgres/MySQL take a combined `sslidentity` file rather than
+/// separate cert/key paths) - and quaint itself is an external git dependency that isn't vendored
+/// in this repo, so that part is out of reach here. Until that wiring exists, setting any of these
+/// has no effect on the connection actually opened.
+fn get_tls_options_arg(
+    args: &HashMap<&str, ValueValidator>,
+    source_name: &str,
+    diagnostics: &mut Diagnostics,
+) -> Option<TlsOptions> {
+    let as_optional_string = |key: &str, diagnostics: &mut Diagnostics| -> Option<String> {
+        let value = args.get(key)?;
+
+        match value.as_string_literal() {
+            Some((s, _)) => Some(s.to_owned()),
+            None => {
+                diagnostics.push_error(DatamodelError::new_source_validation_error(
+                    &format!("`{}` must be a string literal", key),
+                    source_name,
+                    value.span(),
+                ));
+                None
+            }
+        }
+    };
+
+    let root_cert = as_optional_string(TLS_CA_CERT_KEY, diagnostics);
+    let client_cert_path = as_optional_string(TLS_CLIENT_CERT_KEY, diagnostics);
+    let client_key_path = as_optional_string(TLS_CLIENT_KEY_KEY, diagnostics);
+
+    let verify = match args.get(TLS_VERIFY_KEY) {
+        Some(value) => match value.as_bool() {
+            Ok(verify) => verify,
+            Err(err) => {
+                diagnostics.push_error(err);
+                true
+            }
+        },
+        None => true,
+    };
+
+    if root_cert.is_none()
+        && client_cert_path.is_none()
+        && client_key_path.is_none()
+        && args.get(TLS_VERIFY_KEY).is_none()
+    {
+        return None;
+    }
+
+    Some(TlsOptions {
+        root_cert,
+        client_cert_path,
+        client_key_path,
+        verify,
+    })
+}
+
+/// Parses the structured connection pool options (`connectionLimit`, `poolTimeout`,
+/// `socketTimeout`, `statementCacheSize`, `pgbouncer`), if any are present on the datasource.
+/// These are the same knobs that can already be passed as query string parameters on the URL;
+/// declaring them here instead lets datamodel core catch a malformed value (e.g. a non-numeric
+/// `connectionLimit`) up front.
+fn get_pool_options_arg(
+    args: &HashMap<&str, ValueValidator>,
+    source_name: &str,
+    diagnostics: &mut Diagnostics,
+) -> Option<PoolOptions> {
+    let as_optional_u32 = |key: &str, diagnostics: &mut Diagnostics| -> Option<u32> {
+        let value = args.get(key)?;
+
+        match value.as_int() {
+            Ok(int) if int >= 0 && int <= i64::from(u32::MAX) => Some(int as u32),
+            Ok(_) => {
+                diagnostics.push_error(DatamodelError::new_source_validation_error(
+                    &format!("`{}` must be a non-negative number", key),
+                    source_name,
+                    value.span(),
+                ));
+                None
+            }
+            Err(err) => {
+                diagnostics.push_error(err);
+                None
+            }
+        }
+    };
+
+    let connection_limit = as_optional_u32(CONNECTION_LIMIT_KEY, diagnostics);
+    let pool_timeout = as_optional_u32(POOL_TIMEOUT_KEY, diagnostics);
+    let socket_timeout = as_optional_u32(SOCKET_TIMEOUT_KEY, diagnostics);
+    let statement_cache_size = as_optional_u32(STATEMENT_CACHE_SIZE_KEY, diagnostics);
+
+    let pgbouncer = match args.get(PGBOUNCER_KEY) {
+        Some(value) => match value.as_bool() {
+            Ok(pgbouncer) => Some(pgbouncer),
+            Err(err) => {
+                diagnostics.push_error(err);
+                None
+            }
+        },
+        None => None,
+    };
+
+    if connection_limit.is_none()
+        && pool_timeout.is_none()
+        && socket_timeout.is_none()
+        && statement_cache_size.is_none()
+        && pgbouncer.is_none()
+    {
+        return None;
+    }
+
+    Some(PoolOptions {
+        connection_limit,
+        pool_timeout,
+        socket_timeout,
+        statement_cache_size,
+        pgbouncer,
+    })
+}
+
+/// Parses the structured per-connection session options (`statementTimeout`, `searchPath`,
+/// `sqlMode`), if any are present on the datasource. Unlike `tls`/`pool_options`, none of these
+/// are already understood as URL query parameters by the underlying drivers - they're PSL-level
+/// only for now. See `SessionOptions` for which provider each field applies to.
+fn get_session_options_arg(
+    args: &HashMap<&str, ValueValidator>,
+    source_name: &str,
+    diagnostics: &mut Diagnostics,
+) -> Option<SessionOptions> {
+    let as_optional_string = |key: &str, diagnostics: &mut Diagnostics| -> Option<String> {
+        let value = args.get(key)?;
+
+        match value.as_string_literal() {
+            Some((s, _)) if s.is_empty() => {
+                diagnostics.push_error(DatamodelError::new_source_validation_error(
+                    &format!("`{}` must not be empty", key),
+                    source_name,
+                    value.span(),
+                ));
+                None
+            }
+            Some((s, _)) => Some(s.to_owned()),
+            None => {
+                diagnostics.push_error(DatamodelError::new_source_validation_error(
+                    &format!("`{}` must be a string literal", key),
+                    source_name,
+                    value.span(),
+                ));
+                None
+            }
+        }
+    };
+
+    let statement_timeout = as_optional_string(STATEMENT_TIMEOUT_KEY, diagnostics);
+    let search_path = as_optional_string(SEARCH_PATH_KEY, diagnostics);
+    let sql_mode = as_optional_string(SQL_MODE_KEY, diagnostics);
+
+    if statement_timeout.is_none() && search_path.is_none() && sql_mode.is_none() {
+        return None;
+    }
+
+    Some(SessionOptions {
+        statement_timeout,
+        search_path,
+        sql_mode,
+    })
+}
+
 fn preview_features_guardrail(args: &HashMap<&str, ValueValidator>, diagnostics: &mut Diagnostics) {
     let arg = args.get(PREVIEW_FEATURES_KEY);
 
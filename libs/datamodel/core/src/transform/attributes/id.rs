@@ -54,6 +54,22 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
 
         obj.id_fields = fields;
 
+        match args
+            .optional_arg("map")
+            .as_ref()
+            .and_then(ValueValidator::as_string_literal)
+        {
+            Some(("", span)) => {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The `map` argument cannot be an empty string.",
+                    self.attribute_name(),
+                    span,
+                ))
+            }
+            Some((name, _)) => obj.primary_key_name = Some(name.to_owned()),
+            None => (),
+        };
+
         let undefined_fields: Vec<String> = obj
             .id_fields
             .iter()
@@ -122,7 +138,7 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
 
     fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
         if !model.id_fields.is_empty() {
-            let args = vec![ast::Argument::new_array(
+            let mut args = vec![ast::Argument::new_array(
                 "",
                 model
                     .id_fields
@@ -131,6 +147,10 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
                     .collect(),
             )];
 
+            if let Some(db_name) = &model.primary_key_name {
+                args.push(ast::Argument::new_string("map", &db_name));
+            }
+
             return vec![ast::Attribute::new(self.attribute_name(), args)];
         }
 
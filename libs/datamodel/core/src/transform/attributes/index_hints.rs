@@ -0,0 +1,26 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Opts a model into accepting a per-query `indexHint` argument that names one of its indexes.
+pub struct AllowIndexHintsAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "allowIndexHints";
+
+impl AttributeValidator<dml::Model> for AllowIndexHintsAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments<'_>, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.allow_index_hints = true;
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        match obj.allow_index_hints {
+            true => vec![ast::Attribute::new(ATTRIBUTE_NAME, vec![])],
+            false => vec![],
+        }
+    }
+}
@@ -3,6 +3,7 @@ mod attribute_validator;
 mod default;
 mod id;
 mod ignore;
+mod index_hints;
 mod map;
 mod relation;
 mod unique_and_index;
@@ -55,6 +56,7 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
     validator.add(Box::new(ignore::IgnoreAttributeValidator {}));
+    validator.add(Box::new(index_hints::AllowIndexHintsAttributeValidator {}));
 
     validator
 }
@@ -124,6 +124,9 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            db_name: None,
+            nulls_not_distinct: false,
+            predicate: None,
         };
 
         match args
@@ -142,13 +145,82 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             None => (),
         };
 
+        match args
+            .optional_arg("map")
+            .as_ref()
+            .and_then(ValueValidator::as_string_literal)
+        {
+            Some(("", span)) => {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The `map` argument cannot be an empty string.",
+                    self.attribute_name(),
+                    span,
+                ))
+            }
+            Some((name, _)) => index_def.db_name = Some(name.to_owned()),
+            None => (),
+        };
+
+        match args.optional_arg("nullsNotDistinct").as_ref() {
+            Some(value) => {
+                if index_type != IndexType::Unique {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        "The `nullsNotDistinct` argument is only allowed on unique indexes.",
+                        self.attribute_name(),
+                        value.span(),
+                    ));
+                }
+
+                index_def.nulls_not_distinct = value.as_bool()?;
+            }
+            None => (),
+        };
+
+        match args.optional_arg("where").as_ref() {
+            Some(value) => {
+                if index_type != IndexType::Normal {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        "The `where` argument is only allowed on `@@index`.",
+                        self.attribute_name(),
+                        value.span(),
+                    ));
+                }
+
+                index_def.predicate = Some(value.as_string_literal().map(|(s, _)| s.to_owned()).ok_or_else(|| {
+                    DatamodelError::new_attribute_validation_error(
+                        "The `where` argument must be a string.",
+                        self.attribute_name(),
+                        value.span(),
+                    )
+                })?);
+            }
+            None => (),
+        };
+
         let fields = args
             .default_arg("fields")?
             .as_array()
             .iter()
             .map(|f| f.as_constant_literal())
             .collect::<Result<Vec<_>, _>>()?;
-        index_def.fields = fields;
+
+        // `@@index` may reference a relation field directly; resolve it to the underlying
+        // scalar fields it is backed by (in declaration order) instead of requiring users to
+        // spell out the FK columns themselves. `@@unique` keeps rejecting relation fields below,
+        // since a unique constraint on the relation's underlying columns is not always what the
+        // author of `@@unique([relationField])` meant (e.g. for a composite FK where uniqueness
+        // should apply per column, not just to the whole tuple).
+        if index_type == IndexType::Normal {
+            index_def.fields = fields
+                .into_iter()
+                .flat_map(|field| match obj.find_relation_field(&field) {
+                    Some(rf) => rf.relation_info.fields.clone(),
+                    None => vec![field],
+                })
+                .collect();
+        } else {
+            index_def.fields = fields;
+        }
 
         let duplicated_fields = find_duplicates(&index_def.fields);
         if !duplicated_fields.is_empty() {
@@ -255,6 +327,21 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
                     args.push(ast::Argument::new_string("name", &name));
                 }
 
+                if let Some(db_name) = &index_def.db_name {
+                    args.push(ast::Argument::new_string("map", &db_name));
+                }
+
+                if index_def.nulls_not_distinct {
+                    args.push(ast::Argument::new(
+                        "nullsNotDistinct",
+                        ast::Expression::BooleanValue("true".to_owned(), ast::Span::empty()),
+                    ));
+                }
+
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", predicate));
+                }
+
                 ast::Attribute::new(self.attribute_name(), args)
             })
             .collect();
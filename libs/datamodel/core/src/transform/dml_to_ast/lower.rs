@@ -48,6 +48,7 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_mixin: false,
         }
     }
 
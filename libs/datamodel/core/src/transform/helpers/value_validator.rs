@@ -190,6 +190,10 @@ impl ValueValidator {
                         let x = ValueValidator::new(args.first().unwrap()).as_type(ScalarType::String)?;
                         vec![x]
                     }
+                    [Expression::NumericValue(_, _)] if matches!(name.as_str(), "uuid" | "nanoid") => {
+                        let x = ValueValidator::new(args.first().unwrap()).as_type(ScalarType::Int)?;
+                        vec![x]
+                    }
                     [] => vec![],
                     _ => return Err(DatamodelError::new_validation_error(&format!("DefaultValue function parsing failed. The function arg should only be empty or a single String. Got: `{:?}`. You can read about the available functions here: https://pris.ly/d/attribute-functions", args), self.span())),
                 };
@@ -221,6 +225,10 @@ impl ValueValidator {
                         let x = ValueValidator::new(args.first().unwrap()).as_type(ScalarType::String)?;
                         vec![x]
                     }
+                    [Expression::NumericValue(_, _)] if matches!(name.as_str(), "uuid" | "nanoid") => {
+                        let x = ValueValidator::new(args.first().unwrap()).as_type(ScalarType::Int)?;
+                        vec![x]
+                    }
                     [] => vec![],
                     _ => panic!("Should only be empty or single String."),
                 };
@@ -113,6 +113,24 @@ fn must_error_if_now_function_is_used_for_fields_that_are_not_datetime() {
     ));
 }
 
+#[test]
+fn must_error_if_env_function_is_used_for_fields_that_are_not_string() {
+    let dml = r#"
+    model Model {
+        id  Int    @id
+        foo Int @default(env("BAR"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `env()` can not be used on fields of type `Int`.",
+        "default",
+        Span::new(67, 77),
+    ));
+}
+
 #[test]
 fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
     let dml = r#"
@@ -20,9 +20,76 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
+#[test]
+fn nulls_not_distinct_is_rejected_on_at_at_index() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String?
+
+        @@index([firstName,lastName], nullsNotDistinct: true)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `nullsNotDistinct` argument is only allowed on unique indexes.",
+        "index",
+        Span::new(155, 159),
+    ));
+}
+
+#[test]
+fn partial_index_must_work() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String?
+
+        @@index([firstName,lastName], where: "\"lastName\" IS NOT NULL")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: Some("\"lastName\" IS NOT NULL".to_string()),
+    });
+}
+
+#[test]
+fn where_is_rejected_on_at_at_unique() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String?
+
+        @@unique([firstName,lastName], where: "\"lastName\" IS NOT NULL")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `where` argument is only allowed on `@@index`.",
+        "unique",
+        Span::new(145, 171),
+    ));
+}
+
 #[test]
 fn indexes_on_enum_fields_must_work() {
     let dml = r#"
@@ -45,11 +112,14 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
 #[test]
-fn indexes_on_relation_fields_must_error() {
+fn indexes_on_relation_fields_resolve_to_the_underlying_fk_columns() {
     let dml = r#"
     model User {
         id               Int @id
@@ -65,12 +135,49 @@ fn indexes_on_relation_fields_must_error() {
     }
     "#;
 
-    let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_model_validation_error(
-        "The index definition refers to the relation fields identification. Index definitions must reference only scalar fields. Did you mean `@@index([identificationId])`?",
-        "User",
-        Span::new(187,210),
-    ));
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["identificationId".to_string()],
+        tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
+    });
+}
+
+#[test]
+fn indexes_on_relation_fields_with_composite_fks_resolve_in_declaration_order() {
+    let dml = r#"
+    model User {
+        identificationId1 Int
+        identificationId2 Int
+
+        identification    Identification @relation(fields: [identificationId1, identificationId2], references: [id1, id2])
+
+        @@id([identificationId1, identificationId2])
+        @@index([identification])
+    }
+
+    model Identification {
+        id1 Int
+        id2 Int
+
+        @@id([id1, id2])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["identificationId1".to_string(), "identificationId2".to_string()],
+        tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
+    });
 }
 
 #[test]
@@ -91,6 +198,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -168,12 +278,18 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -299,12 +415,18 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
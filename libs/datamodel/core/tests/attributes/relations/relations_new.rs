@@ -330,6 +330,38 @@ fn relation_must_error_when_referenced_fields_are_multiple_uniques() {
     errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: id, firstName", Span::new(290, 367)));
 }
 
+#[test]
+fn relation_must_succeed_when_referencing_a_compound_unique_criteria() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+        posts     Post[]
+
+        @@unique([firstName, lastName])
+    }
+
+    model Post {
+        id            Int    @id
+        text          String
+        userFirstName String
+        userLastName  String
+
+        user User @relation(fields: [userFirstName, userLastName], references: [firstName, lastName])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_relation_field("user")
+        .assert_arity(&dml::FieldArity::Required)
+        .assert_relation_to("User")
+        .assert_relation_base_fields(&["userFirstName", "userLastName"])
+        .assert_relation_referenced_fields(&["firstName", "lastName"]);
+}
+
 #[test]
 fn relation_must_error_when_types_of_base_field_and_referenced_field_do_not_match() {
     let dml = r#"
@@ -19,6 +19,9 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -160,6 +163,9 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -183,12 +189,42 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
+    });
+}
+
+#[test]
+fn the_nulls_not_distinct_argument_must_work() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String?
+
+        @@unique([firstName,lastName], nullsNotDistinct: true)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: true,
+        predicate: None,
     });
 }
 
@@ -214,6 +250,9 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        nulls_not_distinct: false,
+        predicate: None,
     });
 }
 
@@ -19,3 +19,21 @@ fn nice_warning_for_deprecated_generator_preview_feature() {
             Span::new(88, 103),
         ));
 }
+
+#[test]
+fn nice_warning_for_shadow_database_url_on_sqlite() {
+    let schema = r#"
+        datasource myds {
+            provider = "sqlite"
+            url = "file:dev.db"
+            shadowDatabaseUrl = "file:shadow.db"
+        }
+    "#;
+
+    let res = datamodel::parse_configuration(schema).unwrap();
+
+    res.warnings
+        .assert_is(DatamodelWarning::new_shadow_database_url_ignored_on_sqlite_warning(
+            Span::new(123, 139),
+        ));
+}
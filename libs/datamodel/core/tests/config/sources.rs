@@ -497,6 +497,67 @@ fn planet_scale_mode_with_preview_feature_works() {
     assert!(config.planet_scale_mode());
 }
 
+#[test]
+fn pool_options_are_parsed() {
+    let schema = r#"
+    datasource db {
+        provider        = "postgresql"
+        url             = "postgresql://localhost"
+        connectionLimit   = 10
+        poolTimeout       = 20
+        socketTimeout     = 30
+        statementCacheSize = 40
+        pgbouncer         = true
+    }
+    "#;
+
+    let config = parse_configuration(schema);
+    let pool_options = config.datasources[0].pool_options.as_ref().unwrap();
+
+    assert_eq!(pool_options.connection_limit, Some(10));
+    assert_eq!(pool_options.pool_timeout, Some(20));
+    assert_eq!(pool_options.socket_timeout, Some(30));
+    assert_eq!(pool_options.statement_cache_size, Some(40));
+    assert_eq!(pool_options.pgbouncer, Some(true));
+}
+
+#[test]
+fn pool_options_default_to_none() {
+    let schema = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost"
+    }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert!(config.datasources[0].pool_options.is_none());
+}
+
+#[test]
+fn a_negative_connection_limit_errors() {
+    let schema = r#"
+    datasource db {
+        provider        = "postgresql"
+        url             = "postgresql://localhost"
+        connectionLimit = -1
+    }
+    "#;
+
+    let err = parse_error(schema);
+
+    assert!(
+        err.errors
+            .first()
+            .unwrap()
+            .to_string()
+            .contains("`connectionLimit` must be a non-negative number"),
+        "{}",
+        err.errors.first().unwrap()
+    );
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
@@ -60,3 +60,82 @@ fn correctly_handle_server_side_uuid_function() {
         .assert_base_type(&ScalarType::String)
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid()));
 }
+
+#[test]
+fn correctly_handle_server_side_uuid_v7_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(uuid(7))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_is_embedded(false);
+    user_model
+        .assert_has_scalar_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_uuid_v7()));
+}
+
+#[test]
+fn correctly_handle_server_side_cuid2_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(cuid2())
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_is_embedded(false);
+    user_model
+        .assert_has_scalar_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_cuid2()));
+}
+
+#[test]
+fn correctly_handle_server_side_nanoid_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(nanoid())
+        shortId String @default(nanoid(10))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_is_embedded(false);
+    user_model
+        .assert_has_scalar_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(None)));
+    user_model
+        .assert_has_scalar_field("shortId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid(Some(10))));
+}
+
+#[test]
+fn correctly_handle_server_side_env_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        region String @default(env("DEFAULT_REGION"))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_is_embedded(false);
+    user_model
+        .assert_has_scalar_field("region")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_env(
+            "DEFAULT_REGION".to_string(),
+        )));
+}
@@ -148,6 +148,7 @@ impl<'a> DatamodelConverter<'a> {
             .filter(|i| i.fields.len() > 1 && model.is_compound_index_supported(i)) // @@unique for 1 field are transformed to is_unique instead
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
+                db_name: i.db_name.clone(),
                 fields: i.fields.clone(),
                 typ: match i.tpe {
                     dml::IndexType::Unique => IndexType::Unique,
@@ -4,6 +4,7 @@ use std::sync::Arc;
 #[derive(Debug)]
 pub struct IndexTemplate {
     pub name: Option<String>,
+    pub db_name: Option<String>,
     pub fields: Vec<String>,
     pub typ: IndexType,
 }
@@ -17,6 +18,7 @@ impl IndexTemplate {
 
         Index {
             name: self.name,
+            db_name: self.db_name,
             typ: self.typ,
             fields,
         }
@@ -40,6 +42,9 @@ impl IndexTemplate {
 #[derive(Debug)]
 pub struct Index {
     pub name: Option<String>,
+    /// The database constraint name, set via `map:`. This is what a database-level index hint
+    /// (e.g. MySQL's `USE INDEX`) needs to reference, as opposed to the client-facing `name`.
+    pub db_name: Option<String>,
     pub fields: Vec<ScalarFieldWeak>,
     pub typ: IndexType,
 }
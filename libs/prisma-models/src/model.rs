@@ -166,4 +166,10 @@ impl Model {
             .into_iter()
             .find_map(|field| if field.db_name() == name { Some(field) } else { None })
     }
+
+    /// Set via `@@allowIndexHints`. Whether this model accepts a per-query `indexHint` argument
+    /// naming one of its indexes.
+    pub fn allow_index_hints(&self) -> bool {
+        self.dml_model.allow_index_hints
+    }
 }
@@ -52,6 +52,16 @@ pub fn stringify_date(date: &DateTime<FixedOffset>) -> String {
     date.to_rfc3339_opts(SecondsFormat::Millis, true)
 }
 
+// Note: `PrismaValue::Bytes` is always held as a fully materialized `Vec<u8>` and base64-encoded
+// or decoded in one shot here. A chunked/streaming path for large blobs (so an engine boundary
+// like the HTTP or napi interface never has to hold a whole multi-megabyte value at once) isn't
+// something we can bolt onto these two functions: every value on a `Record`, all the way up
+// through `response_ir` and the `GraphQlBody`/`tide::Body::from_json` response the HTTP server
+// sends, is a single in-memory tree that gets serialized as a whole (see
+// `query-engine/query-engine/src/server/mod.rs`). Streaming would mean threading a
+// `Read`/`AsyncRead` value through `PrismaValue`, `Record` and the response IR instead of a
+// `Vec<u8>`, which is a data-model change across the query engine, not something scoped to the
+// codec functions here.
 pub fn encode_bytes(bytes: &[u8]) -> String {
     base64::encode(bytes)
 }
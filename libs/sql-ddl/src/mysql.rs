@@ -205,6 +205,8 @@ pub struct CreateTable<'a> {
     pub primary_key: Vec<Cow<'a, str>>,
     pub default_character_set: Option<Cow<'a, str>>,
     pub collate: Option<Cow<'a, str>>,
+    /// Table-level `key = value` options, e.g. `ENGINE = InnoDB` or `ROW_FORMAT = DYNAMIC`.
+    pub table_options: Vec<(Cow<'a, str>, Cow<'a, str>)>,
 }
 
 impl Display for CreateTable<'_> {
@@ -244,6 +246,10 @@ impl Display for CreateTable<'_> {
             f.write_str(collate.as_ref())?;
         }
 
+        for (option, value) in &self.table_options {
+            write!(f, " {} = {}", option.to_uppercase(), value)?;
+        }
+
         Ok(())
     }
 }
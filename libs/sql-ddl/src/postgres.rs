@@ -323,6 +323,8 @@ pub struct CreateIndex<'a> {
     pub is_unique: bool,
     pub table_reference: PostgresIdentifier<'a>,
     pub columns: Vec<Cow<'a, str>>,
+    pub nulls_not_distinct: bool,
+    pub predicate: Option<Cow<'a, str>>,
 }
 
 impl<'a> Display for CreateIndex<'a> {
@@ -337,7 +339,17 @@ impl<'a> Display for CreateIndex<'a> {
 
         self.columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
-        f.write_str(")")
+        f.write_str(")")?;
+
+        if self.nulls_not_distinct {
+            f.write_str(" NULLS NOT DISTINCT")?;
+        }
+
+        if let Some(predicate) = &self.predicate {
+            write!(f, " WHERE {}", predicate)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -378,6 +390,8 @@ mod tests {
             index_name: "meow_idx".into(),
             table_reference: "Cat".into(),
             columns,
+            nulls_not_distinct: false,
+            predicate: None,
         };
 
         assert_eq!(
@@ -386,6 +400,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_unique_index_with_nulls_not_distinct() {
+        let columns = vec!["name".into(), "age".into()];
+
+        let create_index = CreateIndex {
+            is_unique: true,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            columns,
+            nulls_not_distinct: true,
+            predicate: None,
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE UNIQUE INDEX \"meow_idx\" ON \"Cat\"(\"name\", \"age\") NULLS NOT DISTINCT"
+        )
+    }
+
+    #[test]
+    fn create_partial_index() {
+        let columns = vec!["name".into()];
+
+        let create_index = CreateIndex {
+            is_unique: false,
+            index_name: "meow_idx".into(),
+            table_reference: "Cat".into(),
+            columns,
+            nulls_not_distinct: false,
+            predicate: Some("(status = 'active'::text)".into()),
+        };
+
+        assert_eq!(
+            create_index.to_string(),
+            "CREATE INDEX \"meow_idx\" ON \"Cat\"(\"name\") WHERE (status = 'active'::text)"
+        )
+    }
+
     #[test]
     fn full_alter_table_add_foreign_key() {
         let alter_table = AlterTable {
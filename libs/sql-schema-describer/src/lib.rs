@@ -8,6 +8,7 @@ use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use walkers::{EnumWalker, TableWalker, UserDefinedTypeWalker, ViewWalker};
 
@@ -154,6 +155,30 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// Vendor-specific storage options for the table (e.g. Postgres
+    /// `fillfactor`, MySQL `ENGINE`/`ROW_FORMAT`), keyed by option name in
+    /// lowercase. Empty for connectors that don't have a notion of table
+    /// storage options.
+    pub storage_options: BTreeMap<String, String>,
+    /// The table's CHECK constraints. Only populated on Postgres for now -
+    /// see `CheckConstraint`.
+    pub check_constraints: Vec<CheckConstraint>,
+    /// Columns that are Postgres `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY` columns,
+    /// keyed by column name. A serial/sequence-default column that isn't declared with
+    /// `GENERATED ... AS IDENTITY` won't appear here even though it also reports
+    /// `Column::auto_increment == true` - see `IdentityGeneration`. Only populated on
+    /// Postgres for now.
+    pub identity_columns: BTreeMap<String, IdentityGeneration>,
+    /// The table's comment (Postgres `COMMENT ON TABLE`, MySQL's `COMMENT` table option). Only
+    /// populated on Postgres and MySQL for now.
+    pub description: Option<String>,
+    /// Whether this is a `WITH (SYSTEM_VERSIONING = ON)` temporal table, i.e. `sys.tables.temporal_type`
+    /// is `SYSTEM_VERSIONED_TEMPORAL_TABLE` rather than `NON_TEMPORAL_TABLE` or `HISTORY_TABLE`. Only
+    /// populated on MSSQL for now - see `mssql::is_system_versioned_temporal_table`.
+    pub is_system_versioned: bool,
+    /// Whether this is a memory-optimized (In-Memory OLTP) table, i.e. `sys.tables.is_memory_optimized`.
+    /// Only populated on MSSQL for now.
+    pub is_memory_optimized: bool,
 }
 
 impl Table {
@@ -210,6 +235,37 @@ impl Table {
     }
 }
 
+/// A CHECK constraint on a table.
+///
+/// Only described for Postgres right now, via `pg_catalog.pg_constraint`. MySQL only started
+/// enforcing (as opposed to merely parsing) CHECK constraints in 8.0.16, which makes a single
+/// query correct across "the MySQL connector" doubtful; MSSQL and SQLite are not implemented
+/// here yet either. Tables on those connectors always report an empty `check_constraints`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CheckConstraint {
+    /// The constraint's name.
+    pub name: String,
+    /// The constraint's check expression, as returned by the database (e.g. via
+    /// `pg_get_constraintdef` on Postgres). Kept as-is rather than parsed, the same way
+    /// `default_value` on `Column` is a raw string first and interpreted separately.
+    pub expression: String,
+}
+
+/// Whether a Postgres 10+ identity column always generates its value or only when the insert
+/// doesn't provide one, i.e. the two forms of `GENERATED ... AS IDENTITY`. Distinct from a
+/// plain serial column, whose default is a `DefaultKind::Sequence` `nextval(...)` expression
+/// rather than an identity clause - identity columns typically have no `column_default` at
+/// all, so this can't be folded into `Column::default`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum IdentityGeneration {
+    /// `GENERATED ALWAYS AS IDENTITY`: an explicit value in an `INSERT` is rejected unless
+    /// `OVERRIDING SYSTEM VALUE` is used.
+    Always,
+    /// `GENERATED BY DEFAULT AS IDENTITY`: behaves like a serial column, an explicit value
+    /// in an `INSERT` overrides the generated one.
+    ByDefault,
+}
+
 /// The type of an index.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum IndexType {
@@ -234,6 +290,15 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Whether multiple `NULL`s are treated as equal (not distinct) for
+    /// the purposes of a unique index, i.e. `NULLS NOT DISTINCT` on Postgres
+    /// 15+. Always `false` for connectors without a notion of this.
+    pub nulls_not_distinct: bool,
+    /// The `WHERE` clause of a Postgres partial index, as rendered by
+    /// `pg_get_expr`, without the `WHERE` keyword itself - e.g. `(status = 'active'::text)`.
+    /// `None` for a regular, non-partial index, and always `None` on connectors other than
+    /// Postgres.
+    pub predicate: Option<String>,
 }
 
 impl Index {
@@ -288,6 +353,9 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The column's comment (Postgres `COMMENT ON COLUMN`, MySQL's `COMMENT` column option).
+    /// Only populated on Postgres and MySQL for now.
+    pub description: Option<String>,
 }
 
 impl Column {
@@ -473,6 +541,8 @@ impl PartialEq for ForeignKey {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
             && self.referenced_columns == other.referenced_columns
+            && self.on_delete_action == other.on_delete_action
+            && self.on_update_action == other.on_update_action
     }
 }
 
@@ -490,6 +560,11 @@ pub struct Enum {
 pub struct Sequence {
     /// Sequence name.
     pub name: String,
+    /// The value the sequence starts counting from. Fixed at creation time, so unlike the
+    /// sequence's current value it doesn't drift as rows get inserted, and is safe to diff.
+    pub start_value: i64,
+    /// The step the sequence advances by on each call to `nextval`. Also fixed at creation time.
+    pub increment_by: i64,
 }
 
 /// An SQL view.
@@ -499,6 +574,9 @@ pub struct View {
     pub name: String,
     /// The SQL definition of the view.
     pub definition: Option<String>,
+    /// Whether this is a materialized (as opposed to a plain/virtual) view. Currently only ever
+    /// `true` on Postgres, the only connector we describe materialized views on.
+    pub is_materialized: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -92,12 +92,19 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         let mut columns = self.get_all_columns(schema).await?;
         let mut indexes = self.get_all_indices(schema).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
+        let mut table_properties = self.get_table_properties(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(
+                &table_name,
+                &mut columns,
+                &mut indexes,
+                &mut foreign_keys,
+                &mut table_properties,
+            );
             tables.push(table);
         }
 
@@ -186,6 +193,37 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(names)
     }
 
+    /// Fetches the `sys.tables` flags that don't fit into a regular column/index/foreign key
+    /// query: whether a table is `WITH (SYSTEM_VERSIONING = ON)` (`temporal_type = 2`,
+    /// `SYSTEM_VERSIONED_TEMPORAL_TABLE`) and whether it's memory-optimized (In-Memory OLTP).
+    #[tracing::instrument]
+    async fn get_table_properties(&self, schema: &str) -> DescriberResult<HashMap<String, TableProperties>> {
+        let sql = r#"
+            SELECT t.name AS table_name, t.temporal_type AS temporal_type, t.is_memory_optimized AS is_memory_optimized
+            FROM sys.tables t
+            WHERE SCHEMA_NAME(t.schema_id) = @P1
+            AND t.is_ms_shipped = 0
+            AND t.type = 'U';
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        let properties = rows
+            .into_iter()
+            .map(|row| {
+                let table_name = row.get_expect_string("table_name");
+                let properties = TableProperties {
+                    is_system_versioned: row.get_expect_i64("temporal_type") == 2,
+                    is_memory_optimized: row.get_expect_bool("is_memory_optimized"),
+                };
+
+                (table_name, properties)
+            })
+            .collect();
+
+        Ok(properties)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = indoc! {r#"
@@ -223,11 +261,13 @@ impl<'a> SqlSchemaDescriber<'a> {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        table_properties: &mut HashMap<String, TableProperties>,
     ) -> Table {
         let columns = columns.remove(name).unwrap_or_default();
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let table_properties = table_properties.remove(name).unwrap_or_default();
 
         Table {
             name: name.to_string(),
@@ -235,6 +275,19 @@ impl<'a> SqlSchemaDescriber<'a> {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            storage_options: Default::default(),
+            // Not implemented for MSSQL yet - see `CheckConstraint`.
+            check_constraints: Vec::new(),
+            // Not implemented for MSSQL yet - see `IdentityGeneration`. MSSQL's `IDENTITY`
+            // columns don't have an ALWAYS/BY DEFAULT distinction to model in the first place.
+            identity_columns: Default::default(),
+            // Not implemented for MSSQL yet: table/column comments live in
+            // sys.extended_properties (`MS_Description`), a generic key/value property store
+            // that isn't scoped to comments the way Postgres/MySQL's dedicated comment features
+            // are, and querying it needs a join shape this describer doesn't have yet.
+            description: None,
+            is_system_versioned: table_properties.is_system_versioned,
+            is_memory_optimized: table_properties.is_memory_optimized,
         }
     }
 
@@ -363,6 +416,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                description: None,
             });
         }
 
@@ -462,6 +516,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                                     true => IndexType::Unique,
                                     false => IndexType::Normal,
                                 },
+                                nulls_not_distinct: false,
+                                predicate: None,
                             },
                         );
                     }
@@ -499,6 +555,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
@@ -780,3 +837,27 @@ fn parse_type_parameter(character_maximum_length: Option<i64>) -> Option<MsSqlTy
         None => None,
     }
 }
+
+/// The `sys.tables` flags fetched by `get_table_properties`, for the properties of a table that
+/// don't fit into the regular column/index/foreign key queries.
+#[derive(Debug, Default, Clone, Copy)]
+struct TableProperties {
+    /// `temporal_type = 2` (`SYSTEM_VERSIONED_TEMPORAL_TABLE`) in `sys.tables`.
+    is_system_versioned: bool,
+    /// `is_memory_optimized` in `sys.tables` - an In-Memory OLTP (Hekaton) table.
+    is_memory_optimized: bool,
+}
+
+/// Whether `table` is a `WITH (SYSTEM_VERSIONING = ON)` temporal table.
+///
+/// The differ uses this to refuse dropping or redefining such tables, since SQL Server manages
+/// their paired history table and schema changes need to go through
+/// `ALTER TABLE ... SET (SYSTEM_VERSIONING = OFF)` first.
+///
+/// This used to be a heuristic that matched the period columns' names against the conventional
+/// `SysStartTime`/`SysEndTime`, but `PERIOD FOR SYSTEM_TIME` lets those columns be named anything,
+/// so a renamed period column would silently defeat the check. `Table::is_system_versioned` is
+/// populated straight from `sys.tables.temporal_type`, so it can't miss a renamed table.
+pub fn is_system_versioned_temporal_table(table: &Table) -> bool {
+    table.is_system_versioned
+}
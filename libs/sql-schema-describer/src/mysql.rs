@@ -64,10 +64,19 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'_> {
         let mut columns = Self::get_all_columns(self.conn, schema, &flavour).await?;
         let mut indexes = Self::get_all_indexes(self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(self.conn, schema).await?;
+        let mut storage_options = Self::get_table_storage_options(self.conn, schema).await?;
+        let mut table_descriptions = Self::get_table_descriptions(self.conn, schema).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(
+                table_name,
+                &mut columns,
+                &mut indexes,
+                &mut fks,
+                &mut storage_options,
+                &mut table_descriptions,
+            );
             tables.push(table);
             enums.extend(enms.into_iter());
         }
@@ -130,6 +139,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
@@ -201,18 +211,22 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(size as usize)
     }
 
-    #[tracing::instrument(skip(self, columns, indexes, foreign_keys))]
+    #[tracing::instrument(skip(self, columns, indexes, foreign_keys, storage_options, table_descriptions))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        storage_options: &mut HashMap<String, BTreeMap<String, String>>,
+        table_descriptions: &mut HashMap<String, String>,
     ) -> (Table, Vec<Enum>) {
         let (columns, enums) = columns.remove(name).unwrap_or((vec![], vec![]));
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let storage_options = storage_options.remove(name).unwrap_or_default();
+        let description = table_descriptions.remove(name);
 
         (
             Table {
@@ -221,16 +235,97 @@ impl<'a> SqlSchemaDescriber<'a> {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                storage_options,
+                // Not implemented for MySQL yet: CHECK constraints are only enforced from
+                // MySQL 8.0.16 (MariaDB has supported them since 10.2), so a single query
+                // couldn't describe them consistently across what this connector calls
+                // "MySQL". See `CheckConstraint`.
+                check_constraints: Vec::new(),
+                // Not implemented for MySQL: identity columns are a Postgres 10+ concept, MySQL
+                // has no equivalent to distinguish from a plain `AUTO_INCREMENT` column. See
+                // `IdentityGeneration`.
+                identity_columns: Default::default(),
+                description,
+                // MySQL has no notion of system-versioned temporal tables.
+                is_system_versioned: false,
+                // MySQL has no notion of memory-optimized tables.
+                is_memory_optimized: false,
             },
             enums,
         )
     }
 
+    /// Returns a map from table name to the table's `COMMENT`, for tables that have one.
+    #[tracing::instrument(skip(conn))]
+    async fn get_table_descriptions(
+        conn: &dyn Queryable,
+        schema_name: &str,
+    ) -> DescriberResult<HashMap<String, String>> {
+        let sql = "
+            SELECT table_name as table_name, table_comment as table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_type = 'BASE TABLE'
+        ";
+
+        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+        let mut map = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+
+            if let Some(comment) = row.get_string("table_comment").filter(|c| !c.is_empty()) {
+                map.insert(table_name, comment);
+            }
+        }
+
+        Ok(map)
+    }
+
+    #[tracing::instrument(skip(conn))]
+    async fn get_table_storage_options(
+        conn: &dyn Queryable,
+        schema_name: &str,
+    ) -> DescriberResult<HashMap<String, BTreeMap<String, String>>> {
+        let sql = "
+            SELECT table_name as table_name, engine as engine, row_format as row_format
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_type = 'BASE TABLE'
+        ";
+
+        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+        let mut map = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let mut options = BTreeMap::new();
+
+            if let Some(engine) = row.get_string("engine") {
+                options.insert("engine".to_string(), engine);
+            }
+
+            if let Some(row_format) = row.get_string("row_format") {
+                options.insert("row_format".to_string(), row_format);
+            }
+
+            map.insert(table_name, options);
+        }
+
+        Ok(map)
+    }
+
     async fn get_all_columns(
         conn: &dyn Queryable,
         schema_name: &str,
         flavour: &Flavour,
     ) -> DescriberResult<HashMap<String, (Vec<Column>, Vec<Enum>)>> {
+        // MariaDB doesn't have a native JSON type: `JSON` is just an alias for `LONGTEXT` with an
+        // automatically added `CHECK (JSON_VALID(...))` constraint on the column. Detect that
+        // pattern so introspection can report the column as `Json` like it would for MySQL/Postgres,
+        // instead of losing that information and reporting a plain `LONGTEXT` string column.
+        let mariadb_json_columns = match flavour {
+            Flavour::MariaDb => Self::get_mariadb_json_columns(conn, schema_name).await?,
+            Flavour::Mysql => HashSet::new(),
+        };
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
@@ -246,7 +341,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 column_default column_default,
                 is_nullable is_nullable,
                 extra extra,
-                table_name table_name
+                table_name table_name,
+                column_comment column_comment
             FROM information_schema.columns
             WHERE table_schema = ?
             ORDER BY ordinal_position
@@ -289,6 +385,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             };
 
             let default_value = col.get("column_default");
+            let is_mariadb_json = mariadb_json_columns.contains(&(table_name.clone(), name.clone()));
 
             let (tpe, enum_option) = Self::get_column_type_and_enum(
                 &table_name,
@@ -298,6 +395,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 precision,
                 arity,
                 default_value,
+                is_mariadb_json,
             );
             let extra = col.get_expect_string("extra").to_lowercase();
             let auto_increment = matches!(extra.as_str(), "auto_increment");
@@ -384,6 +482,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                description: col.get_string("column_comment").filter(|c| !c.is_empty()),
             };
 
             entry.0.push(col);
@@ -392,6 +491,40 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(map)
     }
 
+    /// Finds the `(table_name, column_name)` pairs backed by a `CHECK (JSON_VALID(...))`
+    /// constraint, MariaDB's way of emulating the `JSON` type on top of `LONGTEXT`.
+    async fn get_mariadb_json_columns(
+        conn: &dyn Queryable,
+        schema_name: &str,
+    ) -> DescriberResult<HashSet<(String, String)>> {
+        static JSON_VALID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)json_valid\(`?(\w+)`?\)").unwrap());
+
+        let sql = "
+            SELECT table_name table_name, check_clause check_clause
+            FROM information_schema.check_constraints
+            WHERE constraint_schema = ?
+        ";
+
+        let rows = match conn.query_raw(sql, &[schema_name.into()]).await {
+            Ok(rows) => rows,
+            // Older MariaDB/MySQL versions don't have `information_schema.check_constraints`.
+            Err(_) => return Ok(HashSet::new()),
+        };
+
+        let mut columns = HashSet::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let check_clause = row.get_expect_string("check_clause");
+
+            if let Some(captures) = JSON_VALID_RE.captures(&check_clause) {
+                columns.insert((table_name, captures[1].to_owned()));
+            }
+        }
+
+        Ok(columns)
+    }
+
     fn db_generated(default_string: &str, default_generated: bool) -> DefaultValue {
         if default_generated {
             Self::dbgenerated_expression(default_string)
@@ -494,6 +627,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                                     true => IndexType::Unique,
                                     false => IndexType::Normal,
                                 },
+                                nulls_not_distinct: false,
+                                predicate: None,
                             },
                         );
                     }
@@ -633,6 +768,7 @@ impl<'a> SqlSchemaDescriber<'a> {
         precision: Precision,
         arity: ColumnArity,
         default: Option<&Value>,
+        is_mariadb_json: bool,
     ) -> (ColumnType, Option<Enum>) {
         static UNSIGNEDNESS_RE: Lazy<Regex> = Lazy::new(|| Regex::new("(?i)unsigned$").unwrap());
         // println!("Name: {}", column_name);
@@ -694,6 +830,10 @@ impl<'a> SqlSchemaDescriber<'a> {
             "text" => (ColumnTypeFamily::String, Some(MySqlType::Text)),
             "tinytext" => (ColumnTypeFamily::String, Some(MySqlType::TinyText)),
             "mediumtext" => (ColumnTypeFamily::String, Some(MySqlType::MediumText)),
+            // MariaDB stores JSON as LONGTEXT plus a `CHECK (JSON_VALID(...))` constraint. The
+            // native type stays LongText, reflecting how the column is actually stored, but we
+            // report the family as Json so introspection picks it up as a `Json` field.
+            "longtext" if is_mariadb_json => (ColumnTypeFamily::Json, Some(MySqlType::LongText)),
             "longtext" => (ColumnTypeFamily::String, Some(MySqlType::LongText)),
             "enum" => (ColumnTypeFamily::Enum(format!("{}_{}", table, column_name)), None),
             "json" => (ColumnTypeFamily::Json, Some(MySqlType::Json)),
@@ -8,7 +8,12 @@ use native_types::{NativeType, PostgresType};
 use quaint::{connector::ResultRow, prelude::Queryable};
 use regex::Regex;
 use serde_json::from_str;
-use std::{any::type_name, borrow::Cow, collections::HashMap, convert::TryInto};
+use std::{
+    any::type_name,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+};
 use tracing::trace;
 
 #[enumflags2::bitflags]
@@ -53,12 +58,25 @@ impl<'a> super::SqlSchemaDescriberBackend for SqlSchemaDescriber<'a> {
         let mut columns = self.get_columns(schema, &enums, &sequences).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
         let mut indexes = self.get_indices(schema, &sequences).await?;
+        let mut storage_options = self.get_table_storage_options(schema).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
+        let mut identity_columns = self.get_identity_columns(schema).await?;
+        let mut table_descriptions = self.get_table_descriptions(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut storage_options,
+                &mut check_constraints,
+                &mut identity_columns,
+                &mut table_descriptions,
+            ));
         }
 
         let views = self.get_views(schema).await?;
@@ -184,26 +202,146 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(size.try_into().expect("size is not a valid usize"))
     }
 
-    #[tracing::instrument(skip(columns, foreign_keys, indices))]
+    #[tracing::instrument(skip(
+        columns,
+        foreign_keys,
+        indices,
+        storage_options,
+        check_constraints,
+        identity_columns,
+        table_descriptions
+    ))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        storage_options: &mut HashMap<String, BTreeMap<String, String>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
+        identity_columns: &mut HashMap<String, BTreeMap<String, IdentityGeneration>>,
+        table_descriptions: &mut HashMap<String, String>,
     ) -> Table {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).unwrap_or_default();
+        let storage_options = storage_options.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
+        let identity_columns = identity_columns.remove(name).unwrap_or_default();
+        let description = table_descriptions.remove(name);
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            storage_options,
+            check_constraints,
+            identity_columns,
+            description,
+            // Postgres has no notion of system-versioned temporal tables.
+            is_system_versioned: false,
+            // Postgres has no notion of memory-optimized tables.
+            is_memory_optimized: false,
         }
     }
 
+    #[tracing::instrument]
+    async fn get_table_storage_options(
+        &self,
+        schema: &str,
+    ) -> DescriberResult<HashMap<String, BTreeMap<String, String>>> {
+        if self.circumstances.contains(Circumstances::Cockroach) {
+            return Ok(HashMap::new());
+        }
+
+        let sql = r#"
+            SELECT pg_class.relname AS table_name, pg_class.reloptions AS reloptions
+            FROM pg_class
+            INNER JOIN pg_namespace ON pg_class.relnamespace = pg_namespace.oid
+            WHERE pg_namespace.nspname = $1
+                AND pg_class.relkind = 'r'
+                AND pg_class.reloptions IS NOT NULL
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut map = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let reloptions = row
+                .get_string("reloptions")
+                .map(|raw| parse_reloptions(&raw))
+                .unwrap_or_default();
+
+            map.insert(table_name, reloptions);
+        }
+
+        Ok(map)
+    }
+
+    /// Returns a map from table name to the table's CHECK constraints, using
+    /// `pg_get_constraintdef` to render each constraint's expression the same way `psql` would.
+    /// `NOT VALID` constraints are included as-is; whether a constraint is currently enforced
+    /// isn't tracked separately here.
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+            INNER JOIN pg_class cl ON cl.oid = con.conrelid
+            INNER JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+                AND con.contype = 'c'
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let constraint = CheckConstraint {
+                name: row.get_expect_string("constraint_name"),
+                expression: row.get_expect_string("definition"),
+            };
+
+            map.entry(table_name).or_default().push(constraint);
+        }
+
+        Ok(map)
+    }
+
+    /// Returns a map from table name to the table's comment (`COMMENT ON TABLE`), for tables that
+    /// have one.
+    #[tracing::instrument]
+    async fn get_table_descriptions(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                pg_catalog.obj_description(cl.oid, 'pg_class') AS description
+            FROM pg_catalog.pg_class cl
+            INNER JOIN pg_catalog.pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+                AND cl.relkind = 'r'
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut map = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+
+            if let Some(description) = row.get_string("description") {
+                map.insert(table_name, description);
+            }
+        }
+
+        Ok(map)
+    }
+
     #[tracing::instrument]
     async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
         let sql = indoc! {r#"
@@ -219,6 +357,24 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
+            })
+        }
+
+        let matviews_sql = indoc! {r#"
+            SELECT matviewname AS view_name, definition AS view_sql
+            FROM pg_catalog.pg_matviews
+            WHERE schemaname = $1
+        "#};
+
+        let matviews_result_set = self.conn.query_raw(matviews_sql, &[schema.into()]).await?;
+        views.reserve(matviews_result_set.len());
+
+        for row in matviews_result_set.into_iter() {
+            views.push(View {
+                name: row.get_expect_string("view_name"),
+                definition: row.get_string("view_sql"),
+                is_materialized: true,
             })
         }
 
@@ -248,7 +404,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 info.is_nullable,
                 info.is_identity,
                 info.data_type,
-                info.character_maximum_length
+                info.character_maximum_length,
+                pg_catalog.col_description(att.attrelid, att.attnum) as column_description
             FROM information_schema.columns info
             JOIN pg_attribute  att on att.attname = info.column_name
             And att.attrelid = (
@@ -293,6 +450,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                 tpe,
                 default,
                 auto_increment,
+                description: col.get_string("column_description"),
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -303,6 +461,50 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(columns)
     }
 
+    /// Returns a map from table name to a map of identity column name to `IdentityGeneration`,
+    /// for Postgres 10+ `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY` columns. Queries
+    /// `pg_attribute.attidentity` directly rather than folding this into `get_columns`'s existing
+    /// `information_schema.columns` query, since Cockroach's `information_schema.columns` doesn't
+    /// expose an `identity_generation` column and would break column enumeration entirely for it.
+    #[tracing::instrument]
+    async fn get_identity_columns(
+        &self,
+        schema: &str,
+    ) -> DescriberResult<HashMap<String, BTreeMap<String, IdentityGeneration>>> {
+        if self.circumstances.contains(Circumstances::Cockroach) {
+            return Ok(HashMap::new());
+        }
+
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                att.attname AS column_name,
+                att.attidentity AS attidentity
+            FROM pg_attribute att
+            INNER JOIN pg_class cl ON cl.oid = att.attrelid
+            INNER JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1
+                AND att.attidentity IN ('a', 'd')
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut map: HashMap<String, BTreeMap<String, IdentityGeneration>> = HashMap::new();
+
+        for row in rows {
+            let table_name = row.get_expect_string("table_name");
+            let column_name = row.get_expect_string("column_name");
+            let generation = match row.get_string("attidentity").as_deref() {
+                Some("a") => IdentityGeneration::Always,
+                Some("d") => IdentityGeneration::ByDefault,
+                other => panic!("unrecognized attidentity variant '{:?}'", other),
+            };
+
+            map.entry(table_name).or_default().insert(column_name, generation);
+        }
+
+        Ok(map)
+    }
+
     fn get_precision(col: &ResultRow) -> Precision {
         let (character_maximum_length, numeric_precision, numeric_scale, time_precision) =
             if matches!(col.get_expect_string("data_type").as_str(), "ARRAY") {
@@ -490,6 +692,24 @@ impl<'a> SqlSchemaDescriber<'a> {
         Ok(fks)
     }
 
+    /// True if the server understands `pg_index.indnullsnotdistinct`, i.e. `NULLS NOT
+    /// DISTINCT` indexes (Postgres 15+). Cockroach doesn't have the column either, so
+    /// this doubles as a Cockroach check, but we still special-case Cockroach explicitly
+    /// below to avoid a needless round trip.
+    async fn supports_nulls_not_distinct(&self) -> DescriberResult<bool> {
+        let sql = "SELECT EXISTS (
+            SELECT 1 FROM pg_attribute WHERE attrelid = 'pg_index'::regclass AND attname = 'indnullsnotdistinct'
+        ) AS supported";
+
+        let rows = self.conn.query_raw(sql, &[]).await?;
+
+        Ok(rows
+            .into_iter()
+            .next()
+            .map(|row| row.get_expect_bool("supported"))
+            .unwrap_or(false))
+    }
+
     /// Returns a map from table name to indexes and (optional) primary key.
     async fn get_indices(
         &self,
@@ -498,7 +718,15 @@ impl<'a> SqlSchemaDescriber<'a> {
     ) -> DescriberResult<HashMap<String, (Vec<Index>, Option<PrimaryKey>)>> {
         let mut indexes_map = HashMap::new();
 
-        let sql = r#"
+        let nulls_not_distinct_column =
+            if !self.circumstances.contains(Circumstances::Cockroach) && self.supports_nulls_not_distinct().await? {
+                "rawIndex.indnullsnotdistinct"
+            } else {
+                "false"
+            };
+
+        let sql = format!(
+            r#"
         SELECT
             indexInfos.relname as name,
             columnInfos.attname AS column_name,
@@ -506,6 +734,8 @@ impl<'a> SqlSchemaDescriber<'a> {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            rawIndex.indnullsnotdistinct,
+            rawIndex.indpredicate,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -519,11 +749,12 @@ impl<'a> SqlSchemaDescriber<'a> {
                     indisunique,
                     indisprimary,
                     pg_index.indkey AS indkey,
-                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx
+                    generate_subscripts(pg_index.indkey, 1) AS indkeyidx,
+                    {nulls_not_distinct_column} AS indnullsnotdistinct,
+                    -- NULL for a regular index, the index's WHERE clause for a partial one.
+                    pg_get_expr(pg_index.indpred, pg_index.indrelid) AS indpredicate
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey, indnullsnotdistinct, indpredicate
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
@@ -543,9 +774,11 @@ impl<'a> SqlSchemaDescriber<'a> {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx, rawIndex.indnullsnotdistinct, rawIndex.indpredicate
         ORDER BY rawIndex.indkeyidx
-        "#;
+        "#,
+            nulls_not_distinct_column = nulls_not_distinct_column
+        );
 
         let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
 
@@ -555,6 +788,8 @@ impl<'a> SqlSchemaDescriber<'a> {
             let column_name = row.get_expect_string("column_name");
             let is_unique = row.get_expect_bool("is_unique");
             let is_primary_key = row.get_expect_bool("is_primary_key");
+            let is_nulls_not_distinct = row.get_expect_bool("indnullsnotdistinct");
+            let predicate = row.get_string("indpredicate");
             let table_name = row.get_expect_string("table_name");
             let sequence_name = row.get_string("sequence_name");
 
@@ -596,6 +831,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        nulls_not_distinct: is_nulls_not_distinct,
+                        predicate,
                     })
                 }
             }
@@ -606,7 +843,10 @@ impl<'a> SqlSchemaDescriber<'a> {
 
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
-        let sql = "SELECT sequence_name
+        // `start_value` and `increment` are fixed at creation time, unlike the sequence's current
+        // value (not queried here), which advances on every `nextval` call and would otherwise
+        // show up as noise on every diff.
+        let sql = "SELECT sequence_name, start_value, increment
                   FROM information_schema.sequences
                   WHERE sequence_schema = $1";
         let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
@@ -616,6 +856,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                 trace!("Got sequence: {:?}", seq);
                 Sequence {
                     name: seq.get_expect_string("sequence_name"),
+                    start_value: seq.get_expect_i64("start_value"),
+                    increment_by: seq.get_expect_i64("increment"),
                 }
             })
             .collect();
@@ -873,6 +1115,27 @@ fn fetch_dbgenerated(value: &str) -> Option<String> {
     }
 }
 
+/// Parses the textual representation of a Postgres `text[]` column holding
+/// `pg_class.reloptions`, e.g. `{fillfactor=70,autovacuum_enabled=false}`,
+/// into a name -> value map.
+fn parse_reloptions(raw: &str) -> BTreeMap<String, String> {
+    raw.trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .filter_map(|option| {
+            let mut parts = option.splitn(2, '=');
+            let key = parts.next()?.trim();
+            let value = parts.next()?.trim();
+
+            if key.is_empty() {
+                None
+            } else {
+                Some((key.to_string(), value.to_string()))
+            }
+        })
+        .collect()
+}
+
 fn unsuffix_default_literal<'a, T: AsRef<str>>(literal: &'a str, expected_suffixes: &[T]) -> Option<Cow<'a, str>> {
     // Tries to match expressions of the form <expr> or <expr>::<type> or <expr>:::<type>.
     static POSTGRES_DATA_TYPE_SUFFIX_RE: Lazy<Regex> =
@@ -933,18 +1196,28 @@ mod tests {
         let sequences = vec![
             Sequence {
                 name: "first_sequence".to_string(),
+                start_value: 1,
+                increment_by: 1,
             },
             Sequence {
                 name: "second_sequence".to_string(),
+                start_value: 1,
+                increment_by: 1,
             },
             Sequence {
                 name: "third_Sequence".to_string(),
+                start_value: 1,
+                increment_by: 1,
             },
             Sequence {
                 name: "fourth_Sequence".to_string(),
+                start_value: 1,
+                increment_by: 1,
             },
             Sequence {
                 name: "fifth_sequence".to_string(),
+                start_value: 1,
+                increment_by: 1,
             },
         ];
 
@@ -153,6 +153,20 @@ impl<'a> SqlSchemaDescriber<'a> {
             indices,
             primary_key,
             foreign_keys,
+            storage_options: Default::default(),
+            // Not implemented for SQLite yet - CHECK constraints aren't exposed by `PRAGMA
+            // table_info`/`PRAGMA foreign_key_list` and would need parsing the `CREATE TABLE`
+            // statement out of `sqlite_master.sql` instead. See `CheckConstraint`.
+            check_constraints: Vec::new(),
+            // Not implemented for SQLite: identity columns are a Postgres 10+ concept. See
+            // `IdentityGeneration`.
+            identity_columns: Default::default(),
+            // SQLite has no notion of table or column comments.
+            description: None,
+            // SQLite has no notion of system-versioned temporal tables.
+            is_system_versioned: false,
+            // SQLite has no notion of memory-optimized tables.
+            is_memory_optimized: false,
         })
     }
 
@@ -166,6 +180,7 @@ impl<'a> SqlSchemaDescriber<'a> {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_string("view_sql"),
+                is_materialized: false,
             })
         }
 
@@ -250,6 +265,7 @@ impl<'a> SqlSchemaDescriber<'a> {
                     tpe,
                     default,
                     auto_increment: false,
+                    description: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -448,6 +464,8 @@ impl<'a> SqlSchemaDescriber<'a> {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                nulls_not_distinct: false,
+                predicate: None,
             };
 
             let sql = format!(r#"PRAGMA index_info("{}");"#, name);
@@ -7,6 +7,7 @@ use crate::{
     IndexType, PrimaryKey, SqlSchema, Table, UserDefinedType, View,
 };
 use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Traverse all the columns in the schema.
@@ -182,6 +183,11 @@ impl<'a> ViewWalker<'a> {
         self.view().definition.as_deref()
     }
 
+    /// Whether this is a materialized view.
+    pub fn is_materialized(&self) -> bool {
+        self.view().is_materialized
+    }
+
     /// The index of the view in the schema.
     pub fn view_index(&self) -> usize {
         self.view_index
@@ -363,6 +369,12 @@ impl<'a> TableWalker<'a> {
         &self.schema.tables[self.table_index]
     }
 
+    /// The table's vendor-specific storage options, e.g. Postgres
+    /// `fillfactor` or MySQL `ENGINE`.
+    pub fn storage_options(&self) -> &'a BTreeMap<String, String> {
+        &self.table().storage_options
+    }
+
     /// The index of the table in the schema.
     pub fn table_index(&self) -> usize {
         self.table_index
@@ -531,6 +543,17 @@ impl<'a> IndexWalker<'a> {
         &self.get().name
     }
 
+    /// Whether multiple `NULL`s are treated as equal (not distinct) for
+    /// the purposes of a unique index (`NULLS NOT DISTINCT` on Postgres 15+).
+    pub fn nulls_not_distinct(&self) -> bool {
+        self.get().nulls_not_distinct
+    }
+
+    /// The `WHERE` clause of a Postgres partial index, if any.
+    pub fn predicate(&self) -> Option<&'a str> {
+        self.get().predicate.as_deref()
+    }
+
     /// Traverse to the table of the index.
     pub fn table(&self) -> TableWalker<'a> {
         TableWalker {
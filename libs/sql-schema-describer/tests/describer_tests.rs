@@ -219,6 +219,7 @@ fn composite_primary_keys_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "name".to_string(),
@@ -230,6 +231,7 @@ fn composite_primary_keys_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -251,6 +253,13 @@ fn composite_primary_keys_must_work(api: TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -287,6 +296,7 @@ fn indices_must_work(api: TestApi) {
 
             default,
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "count".to_string(),
@@ -302,12 +312,15 @@ fn indices_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
         SqlFamily::Postgres if api.is_cockroach() => None,
         SqlFamily::Postgres => Some(Sequence {
             name: "User_id_seq".to_string(),
+            start_value: 1,
+            increment_by: 1,
         }),
         _ => None,
     };
@@ -320,6 +333,8 @@ fn indices_must_work(api: TestApi) {
             name: "count".to_string(),
             columns: vec!["count".to_string()],
             tpe: IndexType::Normal,
+            nulls_not_distinct: false,
+            predicate: None,
         }],
         user_table.indices
     );
@@ -159,6 +159,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -171,6 +172,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -183,6 +185,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -194,6 +197,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -206,6 +210,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -218,6 +223,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -230,6 +236,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -242,6 +249,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -254,6 +262,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -266,6 +275,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -278,6 +288,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -290,6 +301,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -301,6 +313,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -313,6 +326,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -325,6 +339,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -337,6 +352,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -349,6 +365,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -361,6 +378,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -373,6 +391,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -385,6 +404,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -397,6 +417,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -409,6 +430,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -421,6 +443,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -433,6 +456,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -445,6 +469,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -457,6 +482,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -469,6 +495,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -481,6 +508,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -493,6 +521,7 @@ fn all_mssql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -585,6 +614,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
 
                     default: None,
                     auto_increment: true,
+                    description: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -596,6 +626,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -607,6 +638,7 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
             ],
             indices: vec![],
@@ -633,6 +665,13 @@ fn mssql_foreign_key_on_delete_must_be_handled(api: TestApi) {
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -657,7 +696,9 @@ fn mssql_multi_field_indexes_must_be_inferred(api: TestApi) {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            nulls_not_distinct: false,
+            predicate: None,
         }]
     );
 }
@@ -694,6 +735,8 @@ fn mssql_join_table_unique_indexes_must_be_inferred(api: TestApi) {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            nulls_not_distinct: false,
+            predicate: None,
         }]
     );
 }
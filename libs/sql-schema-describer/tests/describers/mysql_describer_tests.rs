@@ -114,6 +114,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -126,6 +127,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -138,6 +140,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -149,6 +152,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -161,6 +165,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -173,6 +178,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -185,6 +191,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -197,6 +204,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -209,6 +217,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -221,6 +230,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -233,6 +243,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -245,6 +256,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -257,6 +269,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -269,6 +282,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -281,6 +295,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -293,6 +308,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -305,6 +321,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -317,6 +334,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -329,6 +347,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -341,6 +360,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -353,6 +373,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -365,6 +386,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -377,6 +399,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -389,6 +412,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -401,6 +425,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -413,6 +438,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -425,6 +451,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -437,6 +464,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -449,6 +477,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -461,6 +490,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -473,6 +503,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -484,6 +515,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -495,6 +527,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -506,6 +539,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -517,6 +551,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -528,6 +563,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -539,6 +575,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -550,20 +587,20 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "json_col".to_string(),
             tpe: ColumnType {
+                // On MariaDB, `JSON` is an alias for `LONGTEXT` with an automatically added
+                // `CHECK (JSON_VALID(...))` constraint, which the describer picks up to report
+                // the column as `Json` while keeping the native type reflecting real storage.
                 full_data_type: if api.is_mariadb() {
                     "longtext".into()
                 } else {
                     "json".to_string()
                 },
-                family: if api.is_mariadb() {
-                    ColumnTypeFamily::String
-                } else {
-                    ColumnTypeFamily::Json
-                },
+                family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
                 native_type: if api.is_mariadb() {
                     Some(MySqlType::LongText.to_json())
@@ -573,6 +610,7 @@ fn all_mysql_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -589,6 +627,13 @@ fn all_mysql_column_types_must_work(api: TestApi) {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -656,6 +701,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -668,6 +714,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -680,6 +727,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -691,6 +739,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -703,6 +752,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -715,6 +765,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -727,6 +778,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -739,6 +791,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -751,6 +804,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -763,6 +817,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -775,6 +830,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -787,6 +843,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -799,6 +856,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -811,6 +869,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -823,6 +882,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -835,6 +895,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -847,6 +908,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -859,6 +921,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -871,6 +934,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -883,6 +947,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -895,6 +960,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -907,6 +973,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -919,6 +986,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -931,6 +999,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -943,6 +1012,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -955,6 +1025,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -967,6 +1038,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -979,6 +1051,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -991,6 +1064,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -1003,6 +1077,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -1015,6 +1090,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
 
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -1026,6 +1102,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -1037,6 +1114,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -1048,6 +1126,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -1059,6 +1138,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -1070,6 +1150,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -1081,6 +1162,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -1092,6 +1174,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -1103,6 +1186,7 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -1119,6 +1203,13 @@ fn all_mysql_8_column_types_must_work(api: TestApi) {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -1190,6 +1281,8 @@ fn mysql_multi_field_indexes_must_be_inferred(api: TestApi) {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            nulls_not_distinct: false,
+            predicate: None,
         }]
     );
 }
@@ -91,6 +91,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -102,6 +103,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -113,6 +115,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -124,6 +127,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -135,6 +139,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -146,6 +151,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -157,6 +163,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -168,6 +175,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "binary_col".into(),
@@ -179,6 +187,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -190,6 +199,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -201,6 +211,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "double_col".into(),
@@ -212,6 +223,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "float_col".into(),
@@ -223,6 +235,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "int_col".into(),
@@ -234,6 +247,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "primary_col".into(),
@@ -245,6 +259,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "string1_col".into(),
@@ -256,6 +271,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "string2_col".into(),
@@ -267,6 +283,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -278,6 +295,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -289,6 +307,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "bit_col".into(),
@@ -300,6 +319,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -311,6 +331,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "box_col".into(),
@@ -322,6 +343,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "char_col".into(),
@@ -333,6 +355,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "circle_col".into(),
@@ -344,6 +367,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "line_col".into(),
@@ -355,6 +379,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "time_col".into(),
@@ -366,6 +391,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -377,6 +403,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -388,6 +415,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -399,6 +427,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -410,6 +439,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -421,6 +451,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "path_col".into(),
@@ -432,6 +463,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -443,6 +475,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -454,6 +487,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -465,6 +499,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -476,6 +511,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "serial_col".into(),
@@ -487,6 +523,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -498,6 +535,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -509,6 +547,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "txid_col".into(),
@@ -520,6 +559,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "json_col".into(),
@@ -531,6 +571,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -542,6 +583,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -553,6 +595,7 @@ fn all_postgres_column_types_must_work(api: TestApi) {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -566,15 +609,26 @@ fn all_postgres_column_types_must_work(api: TestApi) {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                nulls_not_distinct: false,
+                predicate: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
                 sequence: Some(Sequence {
                     name: "User_primary_col_seq".into(),
+                    start_value: 1,
+                    increment_by: 1,
                 },),
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -682,7 +736,14 @@ fn postgres_sequences_must_work(api: TestApi) {
     let schema = api.describe();
     let got_seq = schema.get_sequence("test").expect("get sequence");
 
-    assert_eq!(got_seq, &Sequence { name: "test".into() },);
+    assert_eq!(
+        got_seq,
+        &Sequence {
+            name: "test".into(),
+            start_value: 1,
+            increment_by: 1,
+        },
+    );
 }
 
 #[test_connector(tags(Postgres))]
@@ -56,6 +56,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -67,6 +68,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -78,6 +80,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -89,6 +92,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -100,6 +104,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            description: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -111,6 +116,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            description: None,
         },
     ];
 
@@ -126,6 +132,13 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
@@ -161,6 +174,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    description: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -172,6 +186,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -183,6 +198,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -194,6 +210,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -205,6 +222,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -216,6 +234,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
             ],
             indices: vec![],
@@ -266,6 +285,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
+            is_system_versioned: false,
+            is_memory_optimized: false,
         }
     );
 }
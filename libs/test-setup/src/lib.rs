@@ -2,6 +2,16 @@
 
 //! This crate contains constants and utilities that are useful for writing tests across the
 //! engines.
+//!
+//! This is a workspace-internal crate, not published to crates.io, and there's no stable API
+//! promised across versions. The path dependencies below and in the sibling
+//! `connector-test-kit-rs` crates (`query-tests-setup`, `migration-engine-tests`,
+//! `introspection-engine-tests`) reach directly into `query-core`, `migration-core`,
+//! `datamodel` and friends, none of which have a public, semver'd API of their own - so there's
+//! nothing stable to build a published conformance-suite crate on top of yet. Getting a
+//! third-party-consumable harness out of this would mean picking (and maintaining) a real public
+//! API on those engine crates first; until that exists, publishing just this harness would freeze
+//! an API surface we can't actually keep stable.
 
 /// Tokio test runtime utils.
 pub mod runtime;
@@ -255,6 +255,16 @@ pub struct ProviderSwitchedError {
 )]
 pub struct AzureMssqlShadowDb;
 
+#[derive(Debug, Serialize, UserFacingError)]
+#[user_facing(
+    code = "P3021",
+    message = "The migration `{migration_name}` references the table `{table_name}`, which is not created by any earlier migration in the history. This can happen when the wrong migration script was copied into the directory. Remove `--strict` if this table is created outside of the migration history (for example by a previous `db push`)."
+)]
+pub struct MigrationReferencesUnknownTable {
+    pub migration_name: String,
+    pub table_name: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +142,12 @@ pub struct QueryValidationFailed {
 
     /// Location of the incorrect parsing, validation in a query. Represented by tuple or object with (line, character)
     pub query_position: String,
+
+    /// A JSON pointer (RFC 6901) into the request body pointing at the offending value, e.g. `/data/where/id`.
+    pub query_pointer: String,
+
+    /// The type the query schema expected at `query_pointer`, when the failure is a type mismatch.
+    pub expected_type: Option<String>,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -161,6 +167,9 @@ pub struct NullConstraintViolation {
 #[user_facing(code = "P2012", message = "Missing a required value at `{path}`")]
 pub struct MissingRequiredValue {
     pub path: String,
+
+    /// A JSON pointer (RFC 6901) into the request body pointing at the missing value, e.g. `/data/where/id`.
+    pub path_pointer: String,
 }
 
 #[derive(Debug, UserFacingError, Serialize)]
@@ -285,3 +294,12 @@ pub struct UnsupportedFeature {
 pub struct MultiError {
     pub errors: String, // Might want to change it to collection of user facing errors.
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2028",
+    message = "The query `{query}` is a write operation, which is not allowed because the engine is running in read-only mode."
+)]
+pub struct WriteOperationsNotAllowed {
+    pub query: String,
+}
@@ -20,15 +20,28 @@ pub(crate) struct Cli {
     datasource: String,
     #[structopt(long, short = "f", parse(try_from_str = parse_setup_flags))]
     qe_test_setup_flags: Option<BitFlags<QueryEngineFlags>>,
+    /// Print the outcome as a single line of JSON on stdout instead of a human-readable message,
+    /// so callers can parse it instead of scraping stderr logs. Errors are always printed as JSON
+    /// on stdout regardless of this flag - this only changes how success is reported.
+    #[structopt(long)]
+    json: bool,
     #[structopt(subcommand)]
     command: CliCommand,
 }
 
 impl Cli {
     pub(crate) async fn run(self) -> ! {
+        let json = self.json;
+
         match std::panic::AssertUnwindSafe(self.run_inner()).catch_unwind().await {
             Ok(Ok(msg)) => {
-                tracing::info!("{}", msg);
+                if json {
+                    serde_json::to_writer(std::io::stdout(), &error::CliSuccess { message: msg })
+                        .expect("failed to write to stdout");
+                    println!();
+                } else {
+                    tracing::info!("{}", msg);
+                }
                 std::process::exit(0);
             }
             Ok(Err(error)) => {
@@ -61,6 +74,15 @@ impl Cli {
                 .await?;
                 Ok(String::new())
             }
+            CliCommand::Diff(input) => {
+                diff(
+                    &self.datasource,
+                    &input.to_schema_datamodel,
+                    input.from_empty,
+                    input.idempotent,
+                )
+                .await
+            }
         }
     }
 }
@@ -75,6 +97,26 @@ enum CliCommand {
     DropDatabase,
     /// Set up the database for connector-test-kit.
     QeSetup,
+    /// Print the SQL migration needed to go from the current state of the database to the schema
+    /// in a given Prisma schema file.
+    Diff(DiffInput),
+}
+
+#[derive(Debug, StructOpt)]
+pub(crate) struct DiffInput {
+    /// Path to the Prisma schema to diff the database against.
+    #[structopt(long)]
+    to_schema_datamodel: String,
+    /// Diff against an empty schema instead of the current state of `--datasource`, i.e. render
+    /// the full script that creates `--to-schema-datamodel` from scratch. Doesn't touch the
+    /// database at all.
+    #[structopt(long)]
+    from_empty: bool,
+    /// Render idempotent guards around the generated statements (currently: around `DROP
+    /// TABLE`), so the script can be run more than once against the same database, for
+    /// external orchestration tools that may re-run it.
+    #[structopt(long)]
+    idempotent: bool,
 }
 
 fn parse_base64_string(s: &str) -> Result<String, CliError> {
@@ -132,6 +174,42 @@ async fn qe_setup(prisma_schema: &str, flags: BitFlags<QueryEngineFlags>) -> Res
     Ok(())
 }
 
+/// Print the SQL migration needed to go from `--from-empty` (or, by default, the current state of
+/// `database_str`) to the schema in the file at `to_schema_datamodel_path`. The "to" side is
+/// always a schema file and the "from" side is either the live database behind `--datasource` or
+/// nothing at all - there's no `GenericApi` method yet that diffs two arbitrary sources (say, a
+/// migrations directory against an unrelated target connection URL), only ones that anchor one
+/// side on the connector's own connection or on an empty schema, so that's what this wraps.
+async fn diff(
+    database_str: &str,
+    to_schema_datamodel_path: &str,
+    from_empty: bool,
+    idempotent: bool,
+) -> Result<String, CliError> {
+    let datamodel = datasource_from_database_str(database_str)?;
+    let api = migration_core::migration_api(&datamodel).await?;
+
+    let to_schema_datamodel = std::fs::read_to_string(to_schema_datamodel_path).map_err(|err| {
+        CliError::invalid_parameters(format!("Error reading '{}': {}", to_schema_datamodel_path, err))
+    })?;
+
+    let from = if from_empty {
+        migration_core::commands::PreviewMigrationScriptFrom::Empty
+    } else {
+        migration_core::commands::PreviewMigrationScriptFrom::Database
+    };
+
+    let output = api
+        .preview_migration_script(&migration_core::commands::PreviewMigrationScriptInput {
+            prisma_schema: to_schema_datamodel,
+            from,
+            idempotent,
+        })
+        .await?;
+
+    Ok(output.migration_script)
+}
+
 fn datasource_from_database_str(database_str: &str) -> Result<String, CliError> {
     let provider = match database_str.split(':').next() {
         Some("postgres") => "postgresql",
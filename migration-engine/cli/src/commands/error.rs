@@ -1,4 +1,5 @@
 use migration_connector::ConnectorError;
+use serde::Serialize;
 use std::fmt::Display;
 use tracing_error::SpanTrace;
 use user_facing_errors::{
@@ -7,6 +8,14 @@ use user_facing_errors::{
     common::TlsConnectionError, UserFacingError,
 };
 
+/// The `--json` counterpart of a successful CLI command. Kept intentionally minimal and stable
+/// (just the same message a human would see) so callers can rely on its shape across versions -
+/// unlike the free-form success text, which isn't meant to be parsed.
+#[derive(Debug, Serialize)]
+pub(crate) struct CliSuccess {
+    pub(crate) message: String,
+}
+
 #[derive(Debug)]
 pub enum CliError {
     Known {
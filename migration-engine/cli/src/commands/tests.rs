@@ -155,7 +155,7 @@ fn test_drop_sqlite_database(api: TestApi) {
     assert!(!sqlite_path.exists());
 }
 
-#[test_connector(tags(Mysql, Postgres))]
+#[test_connector(tags(Mysql, Postgres, Mssql))]
 fn test_drop_database(api: TestApi) {
     api.run(&["--datasource", &api.connection_string, "drop-database"])
         .unwrap();
@@ -6,8 +6,16 @@ pub trait DatabaseMigrationStepApplier: Send + Sync {
     /// Applies the migration to the database. Returns the number of executed steps.
     async fn apply_migration(&self, migration: &Migration) -> ConnectorResult<u32>;
 
-    /// Render the migration to a runnable script.
-    fn render_script(&self, migration: &Migration, diagnostics: &DestructiveChangeDiagnostics) -> String;
+    /// Render the migration to a runnable script. When `idempotent` is true, guards are rendered
+    /// around statements that would otherwise fail if the script were run more than once against
+    /// the same database (currently just `DROP TABLE`), so the script can be handed to external
+    /// orchestration tools that may re-run it.
+    fn render_script(
+        &self,
+        migration: &Migration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        idempotent: bool,
+    ) -> String;
 
     /// Apply a migration script to the database. The migration persistence is
     /// managed by the core.
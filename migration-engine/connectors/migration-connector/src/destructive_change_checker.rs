@@ -45,6 +45,10 @@ pub struct MigrationWarning {
     pub description: String,
     /// The index of the step in the migration that this warning applies to.
     pub step_index: usize,
+    /// The number of rows in the database that would be affected by this warning, if it could be
+    /// determined. `None` when the warning was produced without a database connection (e.g.
+    /// `createMigration`'s `pure_check`), or when the check does not track an affected row count.
+    pub affected_rows: Option<i64>,
 }
 
 /// An unexecutable migration step detected by the DestructiveChangeChecker.
@@ -6,6 +6,8 @@
 //! - A migration script
 
 use crate::{ConnectorError, ConnectorResult, FormatChecksum, CHECKSUM_STR_LEN};
+use once_cell::sync::Lazy;
+use regex::Regex;
 use sha2::{Digest, Sha256, Sha512};
 use std::{
     error::Error,
@@ -17,6 +19,13 @@ use std::{
 use tracing_error::SpanTrace;
 use user_facing_errors::migration_engine::ProviderSwitchedError;
 
+static CREATE_TABLE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)CREATE\s+TABLE\s+(?:IF\s+NOT\s+EXISTS\s+)?[`"\[]?([\w.]+)[`"\]]?"#).unwrap());
+
+static OTHER_TABLE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)(?:ALTER\s+TABLE|DROP\s+TABLE(?:\s+IF\s+EXISTS)?|CREATE\s+(?:UNIQUE\s+)?INDEX\s+[`"\[]?[\w.]+[`"\]]?\s+ON)\s+[`"\[]?([\w.]+)[`"\]]?"#).unwrap()
+});
+
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
@@ -237,6 +246,17 @@ impl MigrationDirectory {
         Ok(checksum_str == filesystem_script_checksum_str)
     }
 
+    /// Compute the checksum of the migration script, formatted the same way as the `checksum`
+    /// column of an applied migration in `_prisma_migrations` (SHA-256, hex-encoded).
+    pub fn migration_script_checksum(&self) -> Result<String, ReadMigrationScriptError> {
+        let script = self.read_migration_script()?;
+        let mut hasher = Sha256::new();
+        hasher.update(&script);
+        let checksum: [u8; 32] = hasher.finalize().into();
+
+        Ok(checksum.format_checksum())
+    }
+
     /// Write the migration script to the directory.
     #[tracing::instrument]
     pub fn write_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
@@ -270,3 +290,133 @@ impl From<DirEntry> for MigrationDirectory {
         MigrationDirectory { path: entry.path() }
     }
 }
+
+/// Split a migration script produced by `DatabaseMigrationStepApplier::render_script` into its
+/// individual SQL statements, skipping comments and blank lines.
+///
+/// This is not a general-purpose SQL parser: it assumes statements are terminated by a `;` at the
+/// end of a line, which holds for everything our renderers generate, but would not hold for
+/// hand-edited SQL that puts a semicolon inside a string literal or a block comment. It exists so
+/// external tools (linters, formatters) that only care about individual statements don't have to
+/// reimplement the shape of our generated scripts themselves.
+pub fn migration_script_statements(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut in_block_comment = false;
+
+    for line in script.lines() {
+        let trimmed = line.trim();
+
+        if in_block_comment {
+            if trimmed.ends_with("*/") {
+                in_block_comment = false;
+            }
+
+            continue;
+        }
+
+        if trimmed.starts_with("/*") && !trimmed.ends_with("*/") {
+            in_block_comment = true;
+            continue;
+        }
+
+        if trimmed.is_empty() || trimmed.starts_with("--") || trimmed == "*/" {
+            continue;
+        }
+
+        current.push_str(line);
+        current.push('\n');
+
+        if trimmed.ends_with(';') {
+            let statement = current.trim();
+            let statement = statement.strip_suffix(';').unwrap_or(statement).trim();
+
+            statements.push(statement.to_string());
+            current.clear();
+        }
+    }
+
+    statements
+}
+
+/// The table name created by a `CREATE TABLE` statement, and the table names referenced by
+/// `ALTER TABLE`, `DROP TABLE` and `CREATE INDEX ... ON` statements in a migration script.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ScriptTableReferences {
+    /// Tables created by the script.
+    pub created: Vec<String>,
+    /// Tables the script otherwise reads or writes: `ALTER TABLE`, `DROP TABLE`, `CREATE INDEX ... ON`.
+    pub referenced: Vec<String>,
+}
+
+/// Best-effort extraction of the table names a migration script creates and touches.
+///
+/// This is not a SQL parser: it pattern-matches on the handful of statement shapes our own
+/// renderers produce (`CREATE|ALTER|DROP TABLE "name"`, `CREATE INDEX ... ON "name"`), the same way
+/// [`migration_script_statements`] only handles semicolon-terminated lines. It will miss or
+/// misidentify table names in hand-written SQL that doesn't follow those shapes (e.g. quoted
+/// schema-qualified names like `"public"."Cat"`, where only `public` is captured, multi-statement
+/// lines, or table names inside a string literal).
+pub fn scan_table_references(script: &str) -> ScriptTableReferences {
+    let mut refs = ScriptTableReferences::default();
+
+    for statement in migration_script_statements(script) {
+        if let Some(captures) = CREATE_TABLE_RE.captures(&statement) {
+            refs.created.push(captures[1].to_owned());
+        } else if let Some(captures) = OTHER_TABLE_RE.captures(&statement) {
+            refs.referenced.push(captures[1].to_owned());
+        }
+    }
+
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migration_script_statements, scan_table_references};
+
+    #[test]
+    fn migration_script_statements_skips_comments_and_splits_on_semicolons() {
+        let script = "/*\n  Warnings:\n\n  - You are about to drop the column.\n\n*/\n-- add column\nALTER TABLE \"a\" ADD COLUMN \"b\" INTEGER;\n\n-- drop column\nALTER TABLE \"a\" DROP COLUMN \"c\";\n";
+
+        assert_eq!(
+            migration_script_statements(script),
+            vec![
+                "ALTER TABLE \"a\" ADD COLUMN \"b\" INTEGER".to_string(),
+                "ALTER TABLE \"a\" DROP COLUMN \"c\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn migration_script_statements_handles_empty_migrations() {
+        assert!(migration_script_statements("-- This is an empty migration.").is_empty());
+    }
+
+    #[test]
+    fn scan_table_references_finds_created_and_referenced_tables() {
+        let script = r#"
+-- CreateTable
+CREATE TABLE "Cat" (
+    "id" INTEGER NOT NULL
+);
+
+-- AlterTable
+ALTER TABLE "Dog" ADD COLUMN "name" TEXT;
+
+-- DropTable
+DROP TABLE "Bird";
+
+-- CreateIndex
+CREATE UNIQUE INDEX "Cat.id_unique" ON "Cat"("id");
+"#;
+
+        let refs = scan_table_references(script);
+
+        assert_eq!(refs.created, vec!["Cat".to_string()]);
+        assert_eq!(
+            refs.referenced,
+            vec!["Dog".to_string(), "Bird".to_string(), "Cat".to_string()]
+        );
+    }
+}
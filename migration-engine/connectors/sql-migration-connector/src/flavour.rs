@@ -28,6 +28,29 @@ use std::fmt::Debug;
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
+/// The maximum size of identifiers on Postgres, in bytes.
+///
+/// reference: https://www.postgresql.org/docs/12/limits.html
+pub(crate) const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
+
+/// The maximum size of identifiers on MSSQL, in bytes.
+///
+/// reference: https://docs.microsoft.com/en-us/sql/sql-server/maximum-capacity-specifications-for-sql-server
+pub(crate) const MSSQL_IDENTIFIER_SIZE_LIMIT: usize = 128;
+
+/// The maximum size of identifiers on Oracle (12.2+, with `MAX_STRING_SIZE = EXTENDED`), in bytes.
+///
+/// reference: https://docs.oracle.com/en/database/oracle/oracle-database/19/sqlrf/Database-Object-Names-and-Qualifiers.html
+///
+/// Not used yet: there is no `OracleFlavour` to apply it in. See the note on `from_connection_info` below.
+#[allow(dead_code)]
+pub(crate) const ORACLE_IDENTIFIER_SIZE_LIMIT: usize = 128;
+
+// An `OracleFlavour` (identifier length rules, sequence-based autoincrement, MERGE-based upserts)
+// can't be dispatched to from here yet: `quaint::prelude::ConnectionInfo` is defined upstream in
+// the quaint crate and has no `Oracle` variant, so this match can't gain an arm for it without
+// that landing first. The `oracle` provider string and its preview feature are reserved (see
+// `PreviewFeature::OracleDatabase`) so schemas can already declare intent ahead of that work.
 pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour::new(url.clone())),
@@ -101,6 +124,20 @@ pub(crate) trait SqlFlavour:
     fn migrations_table(&self) -> Table<'_> {
         self.migrations_table_name().into()
     }
+
+    /// Statements to run immediately before the statements for an `AlterTable` step that rewrites
+    /// `table_name`, so the rewrite fails fast on a busy table instead of queueing behind
+    /// long-running transactions. Empty unless the connector was asked to take table locks (see
+    /// `PostgresFlavour`); other flavours don't support this yet.
+    fn table_locking_statements(&self, _table_name: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// How many times to retry an `AlterTable` step whose table lock timed out, before giving up.
+    /// Only meaningful together with `table_locking_statements`.
+    fn table_lock_retries(&self) -> u32 {
+        0
+    }
 }
 
 // Utility function shared by multiple flavours to compare shadow database and main connection.
@@ -169,11 +169,21 @@ impl SqlFlavour for MssqlFlavour {
             })
     }
 
-    async fn drop_database(&self, _database_url: &str) -> ConnectorResult<()> {
-        let features = vec!["microsoftSqlServer".into()];
-        return Err(ConnectorError::user_facing(
-            user_facing_errors::migration_engine::PreviewFeaturesBlocked { features },
-        ));
+    async fn drop_database(&self, database_str: &str) -> ConnectorResult<()> {
+        let (db_name, master_uri) = Self::master_url(database_str)?;
+        let conn = connect(&master_uri).await?;
+
+        // Kick out any other sessions first - `DROP DATABASE` otherwise fails as soon as there is
+        // a leftover connection open against the database, which is the common case right after a
+        // test run. Contained-database-authentication users are dropped along with the database
+        // itself, so there's nothing extra to clean up for `CONTAINMENT = PARTIAL` databases here.
+        let single_user = format!("ALTER DATABASE [{}] SET SINGLE_USER WITH ROLLBACK IMMEDIATE", db_name);
+        conn.raw_cmd(&single_user).await?;
+
+        let drop_database = format!("DROP DATABASE [{}]", db_name);
+        conn.raw_cmd(&drop_database).await?;
+
+        Ok(())
     }
 
     async fn drop_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
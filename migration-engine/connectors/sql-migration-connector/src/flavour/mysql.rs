@@ -5,7 +5,10 @@ use crate::{
     error::{quaint_error_to_connector_error, SystemDatabase},
     SqlMigrationConnector,
 };
-use datamodel::{walkers::walk_scalar_fields, Datamodel};
+use datamodel::{
+    walkers::{walk_scalar_fields, TypeWalker},
+    Datamodel,
+};
 use enumflags2::BitFlags;
 use indoc::indoc;
 use migration_connector::{migrations_directory::MigrationDirectory, ConnectorError, ConnectorResult};
@@ -51,6 +54,20 @@ impl MysqlFlavour {
             .contains(Circumstances::IsMysql56)
     }
 
+    pub(crate) fn is_mysql_5_5(&self) -> bool {
+        BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
+            .unwrap_or_default()
+            .contains(Circumstances::IsMysql55)
+    }
+
+    /// MySQL only gained support for fractional seconds in `TIMESTAMP`/`DATETIME` columns (and
+    /// their `CURRENT_TIMESTAMP(n)` defaults) in 5.6.4. 5.5 has no fractional second support at
+    /// all, so DDL relying on it - including our own `_prisma_migrations` table - has to fall back
+    /// to whole-second precision there.
+    pub(crate) fn supports_fractional_seconds(&self) -> bool {
+        !self.is_mysql_5_5()
+    }
+
     pub(crate) fn is_vitess(&self) -> bool {
         BitFlags::<Circumstances>::from_bits(self.circumstances.load(Ordering::Relaxed))
             .unwrap_or_default()
@@ -121,30 +138,36 @@ impl SqlFlavour for MysqlFlavour {
         &self,
         datamodel: &Datamodel,
     ) -> Option<user_facing_errors::common::DatabaseVersionIncompatibility> {
-        if self.is_mysql_5_6() {
-            let mut errors = Vec::new();
+        let mut errors = Vec::new();
+        let database_version;
 
+        if self.is_mysql_5_5() {
+            database_version = "MySQL 5.5";
             check_datamodel_for_mysql_5_6(datamodel, &mut errors);
+            check_datamodel_for_mysql_5_5(datamodel, &mut errors);
+        } else if self.is_mysql_5_6() {
+            database_version = "MySQL 5.6";
+            check_datamodel_for_mysql_5_6(datamodel, &mut errors);
+        } else {
+            return None;
+        }
 
-            if errors.is_empty() {
-                return None;
-            }
-
-            let mut errors_string = String::with_capacity(errors.iter().map(|err| err.len() + 3).sum());
+        if errors.is_empty() {
+            return None;
+        }
 
-            for error in &errors {
-                errors_string.push_str("- ");
-                errors_string.push_str(error);
-                errors_string.push('\n');
-            }
+        let mut errors_string = String::with_capacity(errors.iter().map(|err| err.len() + 3).sum());
 
-            Some(user_facing_errors::common::DatabaseVersionIncompatibility {
-                errors: errors_string,
-                database_version: "MySQL 5.6".into(),
-            })
-        } else {
-            None
+        for error in &errors {
+            errors_string.push_str("- ");
+            errors_string.push_str(error);
+            errors_string.push('\n');
         }
+
+        Some(user_facing_errors::common::DatabaseVersionIncompatibility {
+            errors: errors_string,
+            database_version: database_version.into(),
+        })
     }
 
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
@@ -165,18 +188,37 @@ impl SqlFlavour for MysqlFlavour {
     }
 
     async fn create_migrations_table(&self, connection: &Connection) -> ConnectorResult<()> {
-        let sql = indoc! {r#"
-            CREATE TABLE _prisma_migrations (
-                id                      VARCHAR(36) PRIMARY KEY NOT NULL,
-                checksum                VARCHAR(64) NOT NULL,
-                finished_at             DATETIME(3),
-                migration_name          VARCHAR(255) NOT NULL,
-                logs                    TEXT,
-                rolled_back_at          DATETIME(3),
-                started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
-                applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
-            ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
-        "#};
+        // MySQL 5.5 has no fractional seconds support at all (it was added in 5.6.4), so
+        // `DATETIME(3)` and `DEFAULT CURRENT_TIMESTAMP(3)` are both syntax errors there. Fall back
+        // to whole-second precision on that version instead of failing to even create the
+        // migrations table.
+        let sql = if self.supports_fractional_seconds() {
+            indoc! {r#"
+                CREATE TABLE _prisma_migrations (
+                    id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+                    checksum                VARCHAR(64) NOT NULL,
+                    finished_at             DATETIME(3),
+                    migration_name          VARCHAR(255) NOT NULL,
+                    logs                    TEXT,
+                    rolled_back_at          DATETIME(3),
+                    started_at              DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+                    applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
+                ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+            "#}
+        } else {
+            indoc! {r#"
+                CREATE TABLE _prisma_migrations (
+                    id                      VARCHAR(36) PRIMARY KEY NOT NULL,
+                    checksum                VARCHAR(64) NOT NULL,
+                    finished_at             DATETIME,
+                    migration_name          VARCHAR(255) NOT NULL,
+                    logs                    TEXT,
+                    rolled_back_at          DATETIME,
+                    started_at              DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    applied_steps_count     INTEGER UNSIGNED NOT NULL DEFAULT 0
+                ) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
+            "#}
+        };
 
         Ok(connection.raw_cmd(sql).await?)
     }
@@ -249,6 +291,10 @@ impl SqlFlavour for MysqlFlavour {
                 circumstances |= Circumstances::IsMysql56;
             }
 
+            if version.starts_with("5.5") {
+                circumstances |= Circumstances::IsMysql55;
+            }
+
             if version.contains("MariaDB") {
                 circumstances |= Circumstances::IsMariadb;
             }
@@ -373,6 +419,7 @@ impl SqlFlavour for MysqlFlavour {
 pub enum Circumstances {
     LowerCasesTableNames,
     IsMysql56,
+    IsMysql55,
     IsMariadb,
     IsVitess,
 }
@@ -389,6 +436,29 @@ fn check_datamodel_for_mysql_5_6(datamodel: &Datamodel, errors: &mut Vec<String>
     });
 }
 
+/// MySQL 5.5 predates fractional seconds support (added in 5.6.4) entirely, so a `@db.DateTime(n)`
+/// or `@db.Timestamp(n)` column with `n > 0` cannot be created at all - unlike our own
+/// `_prisma_migrations` table, there is no fallback DDL we can generate for an explicit,
+/// user-chosen native type without silently changing the precision the user asked for.
+fn check_datamodel_for_mysql_5_5(datamodel: &Datamodel, errors: &mut Vec<String>) {
+    walk_scalar_fields(datamodel).for_each(|field| {
+        if let TypeWalker::NativeType(_, native_type) = field.field_type() {
+            let has_fractional_precision = matches!(native_type.name.as_str(), "DateTime" | "Timestamp")
+                && native_type.args.first().map(|arg| arg != "0").unwrap_or(false);
+
+            if has_fractional_precision {
+                errors.push(format!(
+                    "The `{}({})` native type used in {}.{} is not supported on MySQL 5.5, which has no fractional seconds support.",
+                    native_type.name,
+                    native_type.args.join(","),
+                    field.model().name(),
+                    field.name()
+                ))
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,6 +34,32 @@ impl PostgresFlavour {
         self.url.schema()
     }
 
+    /// The `lock_timeout` to set before taking an `ACCESS EXCLUSIVE` table lock ahead of an
+    /// `AlterTable` step, read from the `migration_lock_timeout_ms` connection string parameter.
+    /// Unset by default: without an explicit lock timeout, an `ALTER TABLE` just queues behind
+    /// whatever else holds a conflicting lock on the table, which is the behavior most users
+    /// expect coming in.
+    fn table_lock_timeout_ms(&self) -> Option<u64> {
+        self.url
+            .url()
+            .query_pairs()
+            .find(|(key, _)| key == "migration_lock_timeout_ms")
+            .and_then(|(_, value)| value.parse().ok())
+    }
+
+    /// How many times to retry an `AlterTable` step after its table lock times out, read from the
+    /// `migration_lock_retries` connection string parameter. Defaults to 0 (fail immediately),
+    /// matching the "fail fast rather than queueing" goal of setting a lock timeout in the first
+    /// place.
+    fn table_lock_retries_from_url(&self) -> u32 {
+        self.url
+            .url()
+            .query_pairs()
+            .find(|(key, _)| key == "migration_lock_retries")
+            .and_then(|(_, value)| value.parse().ok())
+            .unwrap_or(0)
+    }
+
     async fn shadow_database_connection(
         &self,
         main_connection: &Connection,
@@ -114,6 +140,20 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    fn table_locking_statements(&self, table_name: &str) -> Vec<String> {
+        match self.table_lock_timeout_ms() {
+            Some(timeout_ms) => vec![
+                format!("SET LOCAL lock_timeout = '{}ms'", timeout_ms),
+                format!("LOCK TABLE {} IN ACCESS EXCLUSIVE MODE", self.quote(table_name)),
+            ],
+            None => Vec::new(),
+        }
+    }
+
+    fn table_lock_retries(&self) -> u32 {
+        self.table_lock_retries_from_url()
+    }
+
     #[tracing::instrument(skip(database_str))]
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).map_err(ConnectorError::url_parse_error)?;
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Shortens `name` to fit within `max_length` bytes, if necessary.
+///
+/// Naively truncating a generated name (e.g. `Table_columnA_columnB_columnC_unique`) risks two
+/// distinct long names colliding once cut down to the same prefix - which is exactly what used to
+/// happen silently on Postgres and MySQL before this. Instead, when `name` doesn't fit, we cut it
+/// down and replace the tail with a short hash of the *full* original name, so two overlong names
+/// sharing a prefix still end up different.
+///
+/// The hash is computed with `DefaultHasher`, which uses a fixed key and is therefore stable
+/// across runs for a given engine build - important, since this name is both rendered into SQL
+/// and compared against introspected schemas later.
+pub(crate) fn shorten_index_name(name: &str, max_length: usize) -> Cow<'_, str> {
+    if name.len() <= max_length {
+        return Cow::Borrowed(name);
+    }
+
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    let suffix = format!("_{:x}", hasher.finish() as u32);
+
+    let prefix_len = max_length.saturating_sub(suffix.len());
+    let mut cutoff = prefix_len.min(name.len());
+
+    // Don't cut in the middle of a multi-byte character.
+    while cutoff > 0 && !name.is_char_boundary(cutoff) {
+        cutoff -= 1;
+    }
+
+    Cow::Owned(format!("{}{}", &name[..cutoff], suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_names_are_untouched() {
+        assert_eq!(shorten_index_name("User_email_key", 63), "User_email_key");
+    }
+
+    #[test]
+    fn long_names_are_shortened_and_stay_within_the_limit() {
+        let name = "a".repeat(100);
+        let shortened = shorten_index_name(&name, 63);
+
+        assert!(shortened.len() <= 63);
+    }
+
+    #[test]
+    fn colliding_prefixes_produce_different_shortened_names() {
+        let a = format!("{}_a", "x".repeat(70));
+        let b = format!("{}_b", "x".repeat(70));
+
+        assert_ne!(shorten_index_name(&a, 63), shorten_index_name(&b, 63));
+    }
+
+    #[test]
+    fn shortening_is_deterministic() {
+        let name = "a".repeat(100);
+
+        assert_eq!(shorten_index_name(&name, 63), shorten_index_name(&name, 63));
+    }
+}
@@ -6,7 +6,9 @@
 mod connection_wrapper;
 mod error;
 mod flavour;
+mod identifier_length;
 mod pair;
+mod sql_audit_log;
 mod sql_database_step_applier;
 mod sql_destructive_change_checker;
 mod sql_migration;
@@ -77,6 +79,16 @@ impl SqlMigrationConnector {
         flavour.qe_setup(database_str).await
     }
 
+    /// Run a raw SQL script against the database at `database_str`, without initializing the
+    /// connector. Used for `dbExecute`, where the target (typically a shadow database) may be
+    /// unrelated to whatever connection the migration engine is otherwise bound to.
+    pub async fn db_execute(database_str: &str, script: &str) -> ConnectorResult<()> {
+        let conn = connect(database_str).await?;
+        conn.raw_cmd(script).await?;
+
+        Ok(())
+    }
+
     fn conn(&self) -> &Connection {
         &self.connection
     }
@@ -150,7 +162,11 @@ impl SqlMigrationConnector {
             return Ok(());
         }
 
-        let migration = self.render_script(&Migration::new(migration), &DestructiveChangeDiagnostics::default());
+        let migration = self.render_script(
+            &Migration::new(migration),
+            &DestructiveChangeDiagnostics::default(),
+            false,
+        );
         connection.raw_cmd(&migration).await?;
 
         Ok(())
@@ -244,7 +260,7 @@ impl MigrationConnector for SqlMigrationConnector {
                             .filter(|field| {
                                 field
                                     .default_value()
-                                    .map(|default| default.is_uuid() || default.is_cuid())
+                                    .map(|default| default.is_uuid() || default.is_cuid() || default.is_env())
                                     .unwrap_or(false)
                             })
                             .is_some()
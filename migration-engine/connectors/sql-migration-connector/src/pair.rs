@@ -1,5 +1,5 @@
 use sql_schema_describer::{
-    walkers::{ColumnWalker, EnumWalker, IndexWalker, SqlSchemaExt, TableWalker},
+    walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, SqlSchemaExt, TableWalker},
     SqlSchema,
 };
 
@@ -101,6 +101,12 @@ impl<'a> Pair<TableWalker<'a>> {
     pub(crate) fn indexes(&self, index_indexes: &Pair<usize>) -> Pair<IndexWalker<'a>> {
         self.as_ref().zip(index_indexes.as_ref()).map(|(t, i)| t.index_at(*i))
     }
+
+    pub(crate) fn foreign_keys(&self, foreign_key_indexes: &Pair<usize>) -> Pair<ForeignKeyWalker<'a>> {
+        self.as_ref()
+            .zip(foreign_key_indexes.as_ref())
+            .map(|(t, i)| t.foreign_key_at(*i))
+    }
 }
 
 impl<T> From<(T, T)> for Pair<T> {
@@ -0,0 +1,37 @@
+//! An optional write-ahead audit trail of the exact SQL statements the migration engine executes.
+//!
+//! Enabled by setting the `MIGRATION_SQL_AUDIT_LOG` environment variable to a file path. Every
+//! statement is appended and fsynced *before* it is sent to the database, so a post-mortem after a
+//! migration that failed partway through in production can reconstruct precisely what ran, and in
+//! what order, independently of the database's own state.
+
+use once_cell::sync::Lazy;
+use std::{fs::OpenOptions, io::Write, path::PathBuf};
+
+static AUDIT_LOG_PATH: Lazy<Option<PathBuf>> =
+    Lazy::new(|| std::env::var("MIGRATION_SQL_AUDIT_LOG").ok().map(PathBuf::from));
+
+/// Appends `statement` to the audit log configured through `MIGRATION_SQL_AUDIT_LOG`, fsyncing
+/// before returning. A no-op if the environment variable isn't set.
+///
+/// Failures to write to the audit log are logged but never propagated: an audit trail we can't
+/// write to should not stop a migration from being applied.
+pub(crate) fn record(statement: &str) {
+    let path = match AUDIT_LOG_PATH.as_ref() {
+        Some(path) => path,
+        None => return,
+    };
+
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| {
+            writeln!(file, "{};", statement)?;
+            file.sync_data()
+        });
+
+    if let Err(err) = result {
+        tracing::warn!(error = %err, path = %path.display(), "Failed to write to the SQL audit log");
+    }
+}
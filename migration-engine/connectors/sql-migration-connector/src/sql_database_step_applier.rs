@@ -9,25 +9,46 @@ use migration_connector::{
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 use user_facing_errors::migration_engine::ApplyMigrationError;
 
+/// Postgres error code for `lock_not_available`, raised when a statement's `lock_timeout` elapses
+/// before it can acquire a lock.
+const POSTGRES_LOCK_NOT_AVAILABLE: &str = "55P03";
+
 #[async_trait::async_trait]
 impl DatabaseMigrationStepApplier for SqlMigrationConnector {
     #[tracing::instrument(skip(self, migration))]
     async fn apply_migration(&self, migration: &Migration) -> ConnectorResult<u32> {
         let migration: &SqlMigration = migration.downcast_ref();
         tracing::debug!("{} steps to execute", migration.steps.len());
+        let schemas = Pair::new(&migration.before, &migration.after);
 
         for (index, step) in migration.steps.iter().enumerate() {
-            for sql_string in render_raw_sql(&step, self.flavour(), Pair::new(&migration.before, &migration.after)) {
-                assert!(!sql_string.is_empty());
-                tracing::debug!(index, %sql_string);
-                self.conn().raw_cmd(&sql_string).await?;
+            let statements = render_raw_sql(&step, self.flavour(), schemas, false);
+
+            match rewritten_table_name(step, *schemas.next()) {
+                Some(table_name) if !statements.is_empty() => {
+                    self.apply_statements_with_table_lock(index, &table_name, &statements)
+                        .await?;
+                }
+                _ => {
+                    for sql_string in statements {
+                        assert!(!sql_string.is_empty());
+                        tracing::debug!(index, %sql_string);
+                        crate::sql_audit_log::record(&sql_string);
+                        self.conn().raw_cmd(&sql_string).await?;
+                    }
+                }
             }
         }
 
         Ok(migration.steps.len() as u32)
     }
 
-    fn render_script(&self, migration: &Migration, diagnostics: &DestructiveChangeDiagnostics) -> String {
+    fn render_script(
+        &self,
+        migration: &Migration,
+        diagnostics: &DestructiveChangeDiagnostics,
+        idempotent: bool,
+    ) -> String {
         let migration: &SqlMigration = migration.downcast_ref();
         if migration.steps.is_empty() {
             return "-- This is an empty migration.".to_string();
@@ -61,8 +82,12 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
         let mut is_first_step = true;
 
         for step in &migration.steps {
-            let statements: Vec<String> =
-                render_raw_sql(step, self.flavour(), Pair::new(&migration.before, &migration.after));
+            let statements: Vec<String> = render_raw_sql(
+                step,
+                self.flavour(),
+                Pair::new(&migration.before, &migration.after),
+                idempotent,
+            );
 
             if !statements.is_empty() {
                 if is_first_step {
@@ -91,6 +116,7 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
 
     async fn apply_script(&self, migration_name: &str, script: &str) -> ConnectorResult<()> {
         self.flavour.scan_migration_script(script);
+        crate::sql_audit_log::record(script);
 
         self.conn().raw_cmd(script).await.map_err(|quaint_error| {
             ConnectorError::user_facing(ApplyMigrationError {
@@ -105,10 +131,70 @@ impl DatabaseMigrationStepApplier for SqlMigrationConnector {
     }
 }
 
+impl SqlMigrationConnector {
+    /// Run the statements for an `AlterTable` step that rewrites `table_name`, preceded by the
+    /// flavour's table locking statements (a `SET LOCAL lock_timeout` and an explicit
+    /// `LOCK TABLE ... IN ACCESS EXCLUSIVE MODE` on Postgres, when configured - see
+    /// `SqlFlavour::table_locking_statements`). All of it is sent as a single multi-statement
+    /// command so the lock timeout set by the first statement is still in effect for the `LOCK
+    /// TABLE` right after it, and so the lock is held for the duration of the rewrite rather than
+    /// released as soon as the `LOCK TABLE` statement itself completes.
+    ///
+    /// If taking the lock times out, retries up to `SqlFlavour::table_lock_retries` times.
+    async fn apply_statements_with_table_lock(
+        &self,
+        index: usize,
+        table_name: &str,
+        statements: &[String],
+    ) -> ConnectorResult<()> {
+        let mut script = String::with_capacity(statements.iter().map(|s| s.len() + 2).sum());
+
+        for statement in self
+            .flavour()
+            .table_locking_statements(table_name)
+            .iter()
+            .chain(statements)
+        {
+            assert!(!statement.is_empty());
+            crate::sql_audit_log::record(statement);
+            script.push_str(statement);
+            script.push_str(";\n");
+        }
+
+        tracing::debug!(index, table_name, %script, "Applying step with a table lock");
+
+        let mut attempts_left = self.flavour().table_lock_retries();
+
+        loop {
+            match self.conn().raw_cmd(&script).await {
+                Ok(()) => return Ok(()),
+                Err(err) if attempts_left > 0 && err.original_code() == Some(POSTGRES_LOCK_NOT_AVAILABLE) => {
+                    attempts_left -= 1;
+                    tracing::warn!(table_name, attempts_left, "Table lock timed out, retrying");
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+fn rewritten_table_name(step: &SqlMigrationStep, next_schema: &SqlSchema) -> Option<String> {
+    match step {
+        SqlMigrationStep::AlterTable(alter_table) => Some(
+            next_schema
+                .table_walker_at(*alter_table.table_index.next())
+                .name()
+                .to_owned(),
+        ),
+        _ => None,
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
     schemas: Pair<&SqlSchema>,
+    idempotent: bool,
 ) -> Vec<String> {
     match step {
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, &schemas),
@@ -125,32 +211,34 @@ fn render_raw_sql(
             vec![renderer.render_create_table(&table)]
         }
         SqlMigrationStep::DropTable { table_index } => {
-            renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
+            renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name(), idempotent)
         }
         SqlMigrationStep::RedefineIndex { table, index } => {
             renderer.render_drop_and_recreate_index(schemas.tables(table).indexes(index).as_ref())
         }
         SqlMigrationStep::AddForeignKey {
             table_index,
-            foreign_key_index,
+            foreign_key_indexes,
         } => {
-            let foreign_key = schemas
-                .next()
-                .table_walker_at(*table_index)
-                .foreign_key_at(*foreign_key_index);
+            let table = schemas.next().table_walker_at(*table_index);
+            let foreign_keys: Vec<_> = foreign_key_indexes
+                .iter()
+                .map(|idx| table.foreign_key_at(*idx))
+                .collect();
 
-            vec![renderer.render_add_foreign_key(&foreign_key)]
+            vec![renderer.render_add_foreign_keys(&foreign_keys)]
         }
         SqlMigrationStep::DropForeignKey {
             table_index,
-            foreign_key_index,
+            foreign_key_indexes,
         } => {
-            let foreign_key = schemas
-                .previous()
-                .table_walker_at(*table_index)
-                .foreign_key_at(*foreign_key_index);
+            let table = schemas.previous().table_walker_at(*table_index);
+            let foreign_keys: Vec<_> = foreign_key_indexes
+                .iter()
+                .map(|idx| table.foreign_key_at(*idx))
+                .collect();
 
-            vec![renderer.render_drop_foreign_key(&foreign_key)]
+            vec![renderer.render_drop_foreign_keys(&foreign_keys)]
         }
         SqlMigrationStep::AlterTable(alter_table) => renderer.render_alter_table(alter_table, &schemas),
         SqlMigrationStep::CreateIndex {
@@ -164,6 +252,9 @@ fn render_raw_sql(
         SqlMigrationStep::AlterIndex { table, index } => {
             renderer.render_alter_index(schemas.tables(table).indexes(index).as_ref())
         }
+        SqlMigrationStep::RenameForeignKey { table, foreign_key } => {
+            vec![renderer.render_rename_foreign_key(schemas.tables(table).foreign_keys(foreign_key).as_ref())]
+        }
         SqlMigrationStep::DropView(drop_view) => {
             let view = schemas.previous().view_walker_at(drop_view.view_index);
 
@@ -37,15 +37,6 @@ use unexecutable_step_check::UnexecutableStepCheck;
 use warning_check::SqlMigrationWarningCheck;
 
 impl SqlMigrationConnector {
-    fn check_table_drop(&self, table_name: &str, plan: &mut DestructiveCheckPlan, step_index: usize) {
-        plan.push_warning(
-            SqlMigrationWarningCheck::NonEmptyTableDrop {
-                table: table_name.to_owned(),
-            },
-            step_index,
-        );
-    }
-
     /// Emit a warning when we drop a column that contains non-null values.
     fn check_column_drop(&self, column: &ColumnWalker<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
         plan.push_warning(
@@ -142,6 +133,7 @@ impl SqlMigrationConnector {
                                     .check_drop_and_recreate_column(&columns, changes, &mut plan, step_index)
                             }
                             TableChange::AddPrimaryKey { .. } => (),
+                            TableChange::UpdateTableOptions => (),
                         }
                     }
                 }
@@ -230,8 +222,8 @@ impl SqlMigrationConnector {
                     }
                 }
                 SqlMigrationStep::DropTable { table_index } => {
-                    self.check_table_drop(
-                        schemas.previous().table_walker_at(*table_index).name(),
+                    self.flavour().check_table_drop(
+                        &schemas.previous().table_walker_at(*table_index),
                         &mut plan,
                         step_index,
                     );
@@ -14,6 +14,13 @@ pub(super) trait Check {
         None
     }
 
+    /// Indicates that a small sample of the current non-null values in the returned table and
+    /// column should be gathered, so checks that could fail at apply time (e.g. a cast that isn't
+    /// guaranteed to succeed) can point at concrete offending-looking data upfront.
+    fn needed_sample_values(&self) -> Option<(&str, &str)> {
+        None
+    }
+
     /// This function will always be called for every check in a migration. Each change must check
     /// for the data it needs in the database inspection results. If there is no data, it should
     /// assume the current state of the database could not be inspected and warn with a best effort
@@ -24,4 +31,12 @@ pub(super) trait Check {
     /// of the database, and that data indicates that the migration step would be executable and
     /// safe.
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String>;
+
+    /// The number of rows this check found to be affected, for checks tracking a row or non-null
+    /// value count, so it can be surfaced to tooling alongside the rendered warning message.
+    /// Returns `None` when the check has no such count, or when the database could not be
+    /// inspected.
+    fn affected_row_count(&self, _database_check_results: &DatabaseInspectionResults) -> Option<i64> {
+        None
+    }
 }
@@ -7,6 +7,9 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to a small sample of the non-null values currently
+    /// in that column.
+    sample_values: HashMap<(Cow<'static, str>, Cow<'static, str>), Vec<String>>,
 }
 
 impl DatabaseInspectionResults {
@@ -30,4 +33,19 @@ impl DatabaseInspectionResults {
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
+
+    pub(super) fn get_sample_values(&self, table: &str, column: &str) -> Option<&[String]> {
+        self.sample_values
+            .get(&(Cow::Borrowed(table), Cow::Borrowed(column)))
+            .map(|values| values.as_slice())
+    }
+
+    pub(super) fn set_sample_values(
+        &mut self,
+        table: Cow<'static, str>,
+        column: Cow<'static, str>,
+        values: Vec<String>,
+    ) {
+        self.sample_values.insert((table, column), values);
+    }
 }
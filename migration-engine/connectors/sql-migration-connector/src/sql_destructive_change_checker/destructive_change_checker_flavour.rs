@@ -3,9 +3,9 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use sql_schema_describer::walkers::ColumnWalker;
+use sql_schema_describer::walkers::{ColumnWalker, TableWalker};
 
-use super::DestructiveCheckPlan;
+use super::{warning_check::SqlMigrationWarningCheck, DestructiveCheckPlan};
 use crate::{pair::Pair, sql_migration::AlterColumn, sql_schema_differ::ColumnChanges};
 
 /// Flavour-specific destructive change checks.
@@ -27,4 +27,16 @@ pub(crate) trait DestructiveChangeCheckerFlavour {
         plan: &mut DestructiveCheckPlan,
         step_index: usize,
     );
+
+    /// Check a DropTable step. The default implementation just warns about
+    /// data loss; flavours that manage system tables specially (e.g. MSSQL
+    /// temporal tables) can override this to refuse the step instead.
+    fn check_table_drop(&self, table: &TableWalker<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
+        plan.push_warning(
+            SqlMigrationWarningCheck::NonEmptyTableDrop {
+                table: table.name().to_owned(),
+            },
+            step_index,
+        );
+    }
 }
@@ -11,9 +11,32 @@ use crate::{
 };
 use datamodel_connector::Connector;
 use sql_datamodel_connector::SqlDatamodelConnectors;
-use sql_schema_describer::walkers::ColumnWalker;
+use sql_schema_describer::{
+    mssql::is_system_versioned_temporal_table,
+    walkers::{ColumnWalker, TableWalker},
+};
 
 impl DestructiveChangeCheckerFlavour for MssqlFlavour {
+    fn check_table_drop(&self, table: &TableWalker<'_>, plan: &mut DestructiveCheckPlan, step_index: usize) {
+        if is_system_versioned_temporal_table(table.table()) {
+            plan.push_unexecutable(
+                UnexecutableStepCheck::DropSystemVersionedTemporalTable {
+                    table: table.name().to_owned(),
+                },
+                step_index,
+            );
+
+            return;
+        }
+
+        plan.push_warning(
+            SqlMigrationWarningCheck::NonEmptyTableDrop {
+                table: table.name().to_owned(),
+            },
+            step_index,
+        );
+    }
+
     fn check_alter_column(
         &self,
         alter_column: &AlterColumn,
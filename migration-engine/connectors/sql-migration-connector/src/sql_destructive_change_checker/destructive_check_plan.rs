@@ -78,6 +78,7 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    affected_rows: warning.affected_row_count(&results),
                 })
             }
         }
@@ -106,6 +107,13 @@ impl DestructiveCheckPlan {
             }
         }
 
+        if let Some((table, column)) = check.needed_sample_values() {
+            if results.get_sample_values(table, column).is_none() {
+                let values = sample_values_in_column(column, table, conn).await?;
+                results.set_sample_values(table.to_owned().into(), column.to_owned().into(), values);
+            }
+        }
+
         Ok(())
     }
 
@@ -132,6 +140,7 @@ impl DestructiveCheckPlan {
                 diagnostics.warnings.push(MigrationWarning {
                     description: message,
                     step_index: *step_index,
+                    affected_rows: warning.affected_row_count(&results),
                 })
             }
         }
@@ -166,6 +175,28 @@ async fn count_rows_in_table(table_name: &str, conn: &Connection) -> ConnectorRe
     Ok(rows_count)
 }
 
+/// Maximum number of example values to gather for [`Check::needed_sample_values`].
+const SAMPLE_VALUES_LIMIT: usize = 3;
+
+/// Fetches up to [`SAMPLE_VALUES_LIMIT`] of the current non-null values in a column, for surfacing
+/// in warnings about column changes that could fail to apply (e.g. an unsafe cast). Duplicate
+/// values are not removed.
+async fn sample_values_in_column(column_name: &str, table: &str, conn: &Connection) -> ConnectorResult<Vec<String>> {
+    use quaint::ast::*;
+
+    let query = Select::from_table(conn.table_name(table))
+        .column(column_name)
+        .so_that(column_name.is_not_null())
+        .limit(SAMPLE_VALUES_LIMIT);
+
+    let result_set = conn.query(query).await?;
+
+    Ok(result_set
+        .into_iter()
+        .filter_map(|row| row.at(0).map(|value| value.to_string()))
+        .collect())
+}
+
 async fn count_values_in_column(column_name: &str, table: &str, conn: &Connection) -> ConnectorResult<i64> {
     use quaint::ast::*;
 
@@ -7,6 +7,7 @@ pub(crate) enum UnexecutableStepCheck {
     MadeOptionalFieldRequired { table: String, column: String },
     MadeScalarFieldIntoArrayField { table: String, column: String },
     DropAndRecreateRequiredColumn { table: String, column: String },
+    DropSystemVersionedTemporalTable { table: String },
 }
 
 impl Check for UnexecutableStepCheck {
@@ -17,6 +18,7 @@ impl Check for UnexecutableStepCheck {
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column: _ }
             | UnexecutableStepCheck::AddedRequiredFieldToTable { table, column: _ }
             | UnexecutableStepCheck::DropAndRecreateRequiredColumn { table, column: _ } => Some(table),
+            UnexecutableStepCheck::DropSystemVersionedTemporalTable { .. } => None,
         }
     }
 
@@ -26,7 +28,8 @@ impl Check for UnexecutableStepCheck {
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column } => Some((table, column)),
             UnexecutableStepCheck::AddedRequiredFieldToTable { .. }
             | UnexecutableStepCheck::AddedRequiredFieldToTableWithPrismaLevelDefault { .. }
-            | UnexecutableStepCheck::DropAndRecreateRequiredColumn { .. } => None,
+            | UnexecutableStepCheck::DropAndRecreateRequiredColumn { .. }
+            | UnexecutableStepCheck::DropSystemVersionedTemporalTable { .. } => None,
         }
     }
 
@@ -122,6 +125,10 @@ impl Check for UnexecutableStepCheck {
                     Some(_) => Some(format!("Changed the type of `{column}` on the `{table}` table. No cast exists, the column would be dropped and recreated, which cannot be done since the column is required and there is data in the table.", column = column, table = table)),
                 }
             }
+            UnexecutableStepCheck::DropSystemVersionedTemporalTable { table } => Some(format!(
+                "The table `{table}` is a system-versioned temporal table managed by SQL Server. It cannot be dropped or redefined until system versioning is turned off with `ALTER TABLE {table} SET (SYSTEM_VERSIONING = OFF)`.",
+                table = table
+            )),
         }
     }
 }
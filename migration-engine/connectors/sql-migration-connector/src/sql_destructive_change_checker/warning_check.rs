@@ -49,6 +49,13 @@ impl Check for SqlMigrationWarningCheck {
         }
     }
 
+    fn needed_sample_values(&self) -> Option<(&str, &str)> {
+        match self {
+            SqlMigrationWarningCheck::NotCastable { table, column, .. } => Some((table, column)),
+            _ => None,
+        }
+    }
+
     fn needed_column_value_count(&self) -> Option<(&str, &str)> {
         match self {
             SqlMigrationWarningCheck::NonEmptyColumnDrop { table, column }
@@ -93,12 +100,15 @@ impl Check for SqlMigrationWarningCheck {
             },
 
             // todo this seems to not be reached when only a table is dropped and recreated
-            SqlMigrationWarningCheck::NotCastable { table, column, previous_type, next_type } => match database_check_results.get_row_and_non_null_value_count(table, column) {
-                (Some(0), _) => None, // it's safe to alter a column on an empty table
-                (_, Some(0)) => None, // it's safe to alter a column if it only contains null values
-                (_, Some(value_count)) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table, which contains {value_count} non-null values. The data in that column will be cast from `{old_type}` to `{new_type}`. This cast may fail. Please make sure the data in the column can be cast.", column_name = column, table_name = table, value_count = value_count, old_type = previous_type, new_type = next_type)),
-                (_, _) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table. The data in that column will be cast from `{old_type}` to `{new_type}`. This cast may fail. Please make sure the data in the column can be cast.", column_name = column, table_name = table, old_type = previous_type, new_type = next_type)),
+            SqlMigrationWarningCheck::NotCastable { table, column, previous_type, next_type } => {
+                let sample = sample_values_suffix(database_check_results.get_sample_values(table, column));
 
+                match database_check_results.get_row_and_non_null_value_count(table, column) {
+                    (Some(0), _) => None, // it's safe to alter a column on an empty table
+                    (_, Some(0)) => None, // it's safe to alter a column if it only contains null values
+                    (_, Some(value_count)) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table, which contains {value_count} non-null values. The data in that column will be cast from `{old_type}` to `{new_type}`. This cast may fail.{sample} Please make sure the data in the column can be cast.", column_name = column, table_name = table, value_count = value_count, old_type = previous_type, new_type = next_type, sample = sample)),
+                    (_, _) => Some(format!("You are about to alter the column `{column_name}` on the `{table_name}` table. The data in that column will be cast from `{old_type}` to `{new_type}`. This cast may fail.{sample} Please make sure the data in the column can be cast.", column_name = column, table_name = table, old_type = previous_type, new_type = next_type, sample = sample)),
+                }
             },
             SqlMigrationWarningCheck::PrimaryKeyChange { table } => match database_check_results.get_row_count(table) {
                 Some(0) => None,
@@ -109,4 +119,32 @@ impl Check for SqlMigrationWarningCheck {
 
         }
     }
+
+    fn affected_row_count(&self, database_check_results: &DatabaseInspectionResults) -> Option<i64> {
+        match self {
+            SqlMigrationWarningCheck::DropAndRecreateColumn { table, column }
+            | SqlMigrationWarningCheck::NonEmptyColumnDrop { table, column }
+            | SqlMigrationWarningCheck::RiskyCast { table, column, .. }
+            | SqlMigrationWarningCheck::NotCastable { table, column, .. } => {
+                database_check_results.get_row_and_non_null_value_count(table, column).1
+            }
+            SqlMigrationWarningCheck::NonEmptyTableDrop { table }
+            | SqlMigrationWarningCheck::PrimaryKeyChange { table } => database_check_results.get_row_count(table),
+            SqlMigrationWarningCheck::UniqueConstraintAddition { .. }
+            | SqlMigrationWarningCheck::EnumValueRemoval { .. } => None,
+        }
+    }
+}
+
+/// Renders a `" e.g. \`a\`, \`b\`, \`c\`."`-style suffix from a sample of current column values, or
+/// an empty string if no sample is available.
+fn sample_values_suffix(sample_values: Option<&[String]>) -> String {
+    match sample_values {
+        Some(values) if !values.is_empty() => {
+            let values: Vec<String> = values.iter().map(|value| format!("`{}`", value)).collect();
+
+            format!(" e.g. {}.", values.join(", "))
+        }
+        _ => String::new(),
+    }
 }
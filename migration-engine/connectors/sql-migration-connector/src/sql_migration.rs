@@ -138,7 +138,9 @@ impl SqlMigration {
                         idx,
                     ));
                 }
-                SqlMigrationStep::AlterIndex { table, .. } | SqlMigrationStep::RedefineIndex { table, .. } => {
+                SqlMigrationStep::AlterIndex { table, .. }
+                | SqlMigrationStep::RedefineIndex { table, .. }
+                | SqlMigrationStep::RenameForeignKey { table, .. } => {
                     drift_items.insert((
                         DriftType::ChangedTable,
                         self.schemas().tables(table).previous().name(),
@@ -206,18 +208,18 @@ impl SqlMigration {
                     }
                 }
                 SqlMigrationStep::DropForeignKey {
-                    foreign_key_index,
+                    foreign_key_indexes,
                     table_index,
                 } => {
-                    let fk = self
-                        .schemas()
-                        .previous()
-                        .table_walker_at(*table_index)
-                        .foreign_key_at(*foreign_key_index);
+                    let table = self.schemas().previous().table_walker_at(*table_index);
+
+                    for foreign_key_index in foreign_key_indexes {
+                        let fk = table.foreign_key_at(*foreign_key_index);
 
-                    out.push_str("  [-] Removed foreign key on columns (");
-                    out.push_str(&fk.constrained_column_names().join(", "));
-                    out.push_str(")\n")
+                        out.push_str("  [-] Removed foreign key on columns (");
+                        out.push_str(&fk.constrained_column_names().join(", "));
+                        out.push_str(")\n")
+                    }
                 }
                 SqlMigrationStep::DropIndex {
                     table_index,
@@ -331,17 +333,17 @@ impl SqlMigration {
                 }
                 SqlMigrationStep::AddForeignKey {
                     table_index,
-                    foreign_key_index,
+                    foreign_key_indexes,
                 } => {
-                    let foreign_key = self
-                        .schemas()
-                        .next()
-                        .table_walker_at(*table_index)
-                        .foreign_key_at(*foreign_key_index);
+                    let table = self.schemas().next().table_walker_at(*table_index);
 
-                    out.push_str("  [+] Added foreign key on columns (");
-                    out.push_str(&foreign_key.constrained_column_names().join(", "));
-                    out.push_str(")\n")
+                    for foreign_key_index in foreign_key_indexes {
+                        let foreign_key = table.foreign_key_at(*foreign_key_index);
+
+                        out.push_str("  [+] Added foreign key on columns (");
+                        out.push_str(&foreign_key.constrained_column_names().join(", "));
+                        out.push_str(")\n")
+                    }
                 }
                 SqlMigrationStep::AlterIndex { table, index } => {
                     let index = self.schemas().tables(table).indexes(index);
@@ -359,6 +361,15 @@ impl SqlMigration {
                     out.push_str(index.previous().name());
                     out.push_str("`\n");
                 }
+                SqlMigrationStep::RenameForeignKey { table, foreign_key } => {
+                    let foreign_key = self.schemas().tables(table).foreign_keys(foreign_key);
+
+                    out.push_str("  [*] Renamed foreign key `");
+                    out.push_str(foreign_key.previous().constraint_name().unwrap_or(""));
+                    out.push_str("` to `");
+                    out.push_str(foreign_key.next().constraint_name().unwrap_or(""));
+                    out.push_str("`\n");
+                }
             }
         }
 
@@ -405,7 +416,9 @@ pub(crate) enum SqlMigrationStep {
     AlterEnum(AlterEnum),
     DropForeignKey {
         table_index: usize,
-        foreign_key_index: usize,
+        /// The indexes of the dropped foreign keys in the table, in the previous schema. More
+        /// than one means they will be dropped in a single statement (see `compact_steps`).
+        foreign_key_indexes: Vec<usize>,
     },
     DropIndex {
         table_index: usize,
@@ -442,8 +455,9 @@ pub(crate) enum SqlMigrationStep {
     AddForeignKey {
         /// The index of the table in the next schema.
         table_index: usize,
-        /// The index of the foreign key in the table.
-        foreign_key_index: usize,
+        /// The indexes of the added foreign keys in the table. More than one means they will be
+        /// added in a single statement (see `compact_steps`).
+        foreign_key_indexes: Vec<usize>,
     },
     AlterIndex {
         table: Pair<usize>,
@@ -453,6 +467,10 @@ pub(crate) enum SqlMigrationStep {
         table: Pair<usize>,
         index: Pair<usize>,
     },
+    RenameForeignKey {
+        table: Pair<usize>,
+        foreign_key: Pair<usize>,
+    },
 }
 
 impl SqlMigrationStep {
@@ -476,6 +494,7 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateTable { .. } => "CreateTable",
             SqlMigrationStep::AlterTable(_) => "AlterTable",
             SqlMigrationStep::RedefineIndex { .. } => "RedefineIndex",
+            SqlMigrationStep::RenameForeignKey { .. } => "RenameForeignKey",
             SqlMigrationStep::DropForeignKey { .. } => "DropForeignKey",
             SqlMigrationStep::DropTable { .. } => "DropTable",
             SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
@@ -517,6 +536,12 @@ pub(crate) enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    /// The table's vendor-specific storage options (Postgres `fillfactor`,
+    /// MySQL `ENGINE`/`ROW_FORMAT`, ...) changed. The renderer reads
+    /// `storage_options` on the tables itself to build the statement: the
+    /// Postgres renderer diffs the previous and next `Table`, the MySQL
+    /// renderer restates every option of the next `Table`.
+    UpdateTableOptions,
 }
 
 impl TableChange {
@@ -29,7 +29,10 @@ use sql_schema_describer::{
 pub(crate) trait SqlRenderer {
     fn quote<'a>(&self, name: &'a str) -> Quoted<&'a str>;
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
+    /// Render an `AddForeignKey` step. `foreign_keys` contains more than one entry when several
+    /// foreign keys were added to the same table and got merged into a single statement (see
+    /// `sql_schema_differ::compact_steps`).
+    fn render_add_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String;
 
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
@@ -59,20 +62,29 @@ pub(crate) trait SqlRenderer {
     /// Render a `DropEnum` step.
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String>;
 
-    /// Render a `DropForeignKey` step.
-    fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
+    /// Render a `DropForeignKey` step. `foreign_keys` contains more than one entry when several
+    /// foreign keys were dropped from the same table and got merged into a single statement (see
+    /// `sql_schema_differ::compact_steps`).
+    fn render_drop_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String;
 
     /// Render a `DropIndex` step.
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
-    /// Render a `DropTable` step.
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    /// Render a `DropTable` step. `idempotent` asks for a guard against the table not existing,
+    /// for scripts that may be run more than once against the same database. This default
+    /// ignores it and always renders a plain `DROP TABLE`.
+    fn render_drop_table(&self, table_name: &str, _idempotent: bool) -> Vec<String> {
         vec![format!("DROP TABLE {}", self.quote(&table_name))]
     }
 
     /// Render a `RedefineTables` step.
     fn render_redefine_tables(&self, tables: &[RedefineTable], schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
+    /// Render a `RenameForeignKey` step.
+    fn render_rename_foreign_key(&self, _foreign_keys: Pair<&ForeignKeyWalker<'_>>) -> String {
+        unreachable!("unreachable render_rename_foreign_key")
+    }
+
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
 
@@ -83,6 +83,39 @@ impl MssqlFlavour {
             render_on_delete(&foreign_key.on_delete_action()),
         )
     }
+
+    /// Renders a single `CONSTRAINT ... FOREIGN KEY (...) REFERENCES ...` clause, without the
+    /// surrounding `ALTER TABLE ... ADD`, so several of these can be joined into one statement.
+    fn render_add_constraint(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+        let mut add_constraint = String::with_capacity(120);
+
+        if let Some(constraint_name) = foreign_key.constraint_name() {
+            write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
+        } else {
+            write!(
+                add_constraint,
+                "CONSTRAINT [FK__{}__{}] ",
+                foreign_key.table().name(),
+                foreign_key.constrained_column_names().join("__"),
+            )
+            .unwrap();
+        }
+
+        write!(
+            add_constraint,
+            "FOREIGN KEY ({})",
+            foreign_key
+                .constrained_column_names()
+                .iter()
+                .map(|col| self.quote(col))
+                .join(", ")
+        )
+        .unwrap();
+
+        add_constraint.push_str(&self.render_references(foreign_key));
+
+        add_constraint
+    }
 }
 
 impl SqlRenderer for MssqlFlavour {
@@ -199,11 +232,14 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_drop_enum on MSSQL")
     }
 
-    fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_drop_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         format!(
-            "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote_with_schema(foreign_key.table().name()),
-            constraint_name = Quoted::mssql_ident(foreign_key.constraint_name().unwrap()),
+            "ALTER TABLE {table} DROP CONSTRAINT {constraint_names}",
+            table = self.quote_with_schema(foreign_keys[0].table().name()),
+            constraint_names = foreign_keys
+                .iter()
+                .map(|foreign_key| Quoted::mssql_ident(foreign_key.constraint_name().unwrap()))
+                .join(", "),
         )
     }
 
@@ -256,7 +292,7 @@ impl SqlRenderer for MssqlFlavour {
             // We must drop foreign keys pointing to this table before removing
             // any of the table constraints.
             for fk in keys {
-                result.push(self.render_drop_foreign_key(&fk));
+                result.push(self.render_drop_foreign_keys(&[fk]));
             }
 
             // Then the indices...
@@ -313,14 +349,14 @@ impl SqlRenderer for MssqlFlavour {
             }
 
             // Drop the old, now empty table.
-            result.extend(self.render_drop_table(tables.previous().name()));
+            result.extend(self.render_drop_table(tables.previous().name(), false));
 
             // Rename the temporary table with the name defined in the migration.
             result.push(self.render_rename_table(&temporary_table_name, tables.next().name()));
 
             // Recreating all foreign keys pointing to this table
             for fk in tables.next().referencing_foreign_keys() {
-                result.push(self.render_add_foreign_key(&fk));
+                result.push(self.render_add_foreign_keys(&[fk]));
             }
 
             // Then the indices...
@@ -344,46 +380,36 @@ impl SqlRenderer for MssqlFlavour {
         )
     }
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
-        let mut add_constraint = String::with_capacity(120);
-
-        write!(
-            add_constraint,
-            "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(foreign_key.table().name())
-        )
-        .unwrap();
-
-        if let Some(constraint_name) = foreign_key.constraint_name() {
-            write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
-        } else {
-            write!(
-                add_constraint,
-                "CONSTRAINT [FK__{}__{}] ",
-                foreign_key.table().name(),
-                foreign_key.constrained_column_names().join("__"),
-            )
-            .unwrap();
-        }
+    fn render_add_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
+        let mut add_constraint = String::with_capacity(120 * foreign_keys.len());
 
         write!(
             add_constraint,
-            "FOREIGN KEY ({})",
-            foreign_key
-                .constrained_column_names()
+            "ALTER TABLE {table} ADD {constraints}",
+            table = self.quote_with_schema(foreign_keys[0].table().name()),
+            constraints = foreign_keys
                 .iter()
-                .map(|col| self.quote(col))
+                .map(|foreign_key| self.render_add_constraint(foreign_key))
                 .join(", ")
         )
         .unwrap();
 
-        add_constraint.push_str(&self.render_references(foreign_key));
-
         add_constraint
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, table_name: &str, idempotent: bool) -> Vec<String> {
+        if idempotent {
+            // SQL Server has no `DROP TABLE IF EXISTS` before 2016, so we guard the statement
+            // with an `OBJECT_ID` check instead.
+            vec![format!(
+                "IF OBJECT_ID('{schema}.{table}', 'U') IS NOT NULL DROP TABLE {quoted}",
+                schema = self.schema_name(),
+                table = table_name,
+                quoted = self.quote_with_schema(&table_name),
+            )]
+        } else {
+            vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+        }
     }
 
     fn render_drop_view(&self, view: &ViewWalker<'_>) -> String {
@@ -70,6 +70,7 @@ impl<'a> AlterTableConstructor<'a> {
                 }) => {
                     self.alter_column(*column_index, &changes);
                 }
+                TableChange::UpdateTableOptions => unreachable!("UpdateTableOptions on MSSQL"),
             };
         }
 
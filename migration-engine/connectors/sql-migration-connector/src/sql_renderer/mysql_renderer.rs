@@ -1,6 +1,7 @@
 use super::{common::Quoted, IteratorJoin, SqlRenderer};
 use crate::{
     flavour::{MysqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT},
+    identifier_length::shorten_index_name,
     pair::Pair,
     sql_migration::{AlterColumn, AlterEnum, AlterTable, RedefineTable, TableChange},
     sql_schema_differ::ColumnChanges,
@@ -47,38 +48,43 @@ impl SqlRenderer for MysqlFlavour {
         Quoted::Backticks(name)
     }
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_add_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         ddl::AlterTable {
-            table_name: foreign_key.table().name().into(),
-            changes: vec![ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
-                constraint_name: foreign_key.constraint_name().map(From::from),
-                constrained_columns: foreign_key
-                    .constrained_column_names()
-                    .iter()
-                    .map(|c| Cow::Borrowed(c.as_str()))
-                    .collect(),
-                referenced_table: foreign_key.referenced_table().name().into(),
-                referenced_columns: foreign_key
-                    .referenced_column_names()
-                    .iter()
-                    .map(String::as_str)
-                    .map(Cow::Borrowed)
-                    .collect(),
-                on_delete: Some(match foreign_key.on_delete_action() {
-                    ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
-                    ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
-                    ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
-                    ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
-                }),
-                on_update: Some(match foreign_key.on_update_action() {
-                    ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
-                    ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
-                    ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
-                    ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
-                }),
-            })],
+            table_name: foreign_keys[0].table().name().into(),
+            changes: foreign_keys
+                .iter()
+                .map(|foreign_key| {
+                    ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
+                        constraint_name: foreign_key.constraint_name().map(From::from),
+                        constrained_columns: foreign_key
+                            .constrained_column_names()
+                            .iter()
+                            .map(|c| Cow::Borrowed(c.as_str()))
+                            .collect(),
+                        referenced_table: foreign_key.referenced_table().name().into(),
+                        referenced_columns: foreign_key
+                            .referenced_column_names()
+                            .iter()
+                            .map(String::as_str)
+                            .map(Cow::Borrowed)
+                            .collect(),
+                        on_delete: Some(match foreign_key.on_delete_action() {
+                            ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
+                            ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                            ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
+                            ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
+                            ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
+                        }),
+                        on_update: Some(match foreign_key.on_update_action() {
+                            ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
+                            ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                            ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
+                            ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
+                            ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
+                        }),
+                    })
+                })
+                .collect(),
         }
         .to_string()
     }
@@ -150,6 +156,15 @@ impl SqlRenderer for MysqlFlavour {
                     lines.push(format!("DROP COLUMN `{}`", columns.previous().name()));
                     lines.push(format!("ADD COLUMN {}", self.render_column(columns.next())));
                 }
+                TableChange::UpdateTableOptions => {
+                    for (option, value) in tables.next().storage_options() {
+                        match option.as_str() {
+                            "engine" => lines.push(format!("ENGINE = {}", value)),
+                            "row_format" => lines.push(format!("ROW_FORMAT = {}", value)),
+                            _ => (),
+                        }
+                    }
+                }
             };
         }
 
@@ -171,16 +186,11 @@ impl SqlRenderer for MysqlFlavour {
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
-        let name = index.name();
-        let name = if name.len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-            &name[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
-        } else {
-            &name
-        };
+        let name = shorten_index_name(index.name(), MYSQL_IDENTIFIER_SIZE_LIMIT);
 
         ddl::CreateIndex {
             unique: index.index_type().is_unique(),
-            index_name: name.into(),
+            index_name: name.into_owned().into(),
             on: (
                 index.table().name().into(),
                 index.columns().map(|c| c.name().into()).collect(),
@@ -196,11 +206,9 @@ impl SqlRenderer for MysqlFlavour {
             indexes: table
                 .indexes()
                 .map(move |index| ddl::IndexClause {
-                    index_name: if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
-                        Some(Cow::Borrowed(&index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]))
-                    } else {
-                        Some(Cow::Borrowed(&index.name()))
-                    },
+                    index_name: Some(Cow::Owned(
+                        shorten_index_name(index.name(), MYSQL_IDENTIFIER_SIZE_LIMIT).into_owned(),
+                    )),
                     unique: index.index_type().is_unique(),
                     columns: index.column_names().iter().map(Cow::from).collect(),
                 })
@@ -213,6 +221,11 @@ impl SqlRenderer for MysqlFlavour {
                 .collect(),
             default_character_set: Some("utf8mb4".into()),
             collate: Some("utf8mb4_unicode_ci".into()),
+            table_options: table
+                .storage_options()
+                .iter()
+                .map(|(option, value)| (Cow::from(option.as_str()), Cow::from(value.as_str())))
+                .collect(),
         }
         .to_string()
     }
@@ -235,11 +248,17 @@ impl SqlRenderer for MysqlFlavour {
         )
     }
 
-    fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_drop_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         format!(
-            "ALTER TABLE {table} DROP FOREIGN KEY {constraint_name}",
-            table = self.quote(foreign_key.table().name()),
-            constraint_name = Quoted::mysql_ident(foreign_key.constraint_name().unwrap()),
+            "ALTER TABLE {table} {drops}",
+            table = self.quote(foreign_keys[0].table().name()),
+            drops = foreign_keys
+                .iter()
+                .map(|foreign_key| format!(
+                    "DROP FOREIGN KEY {constraint_name}",
+                    constraint_name = Quoted::mysql_ident(foreign_key.constraint_name().unwrap())
+                ))
+                .join(", "),
         )
     }
 
@@ -251,11 +270,15 @@ impl SqlRenderer for MysqlFlavour {
         .to_string()
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![sql_ddl::mysql::DropTable {
-            table_name: table_name.into(),
+    fn render_drop_table(&self, table_name: &str, idempotent: bool) -> Vec<String> {
+        if idempotent {
+            vec![format!("DROP TABLE IF EXISTS {}", self.quote(table_name))]
+        } else {
+            vec![sql_ddl::mysql::DropTable {
+                table_name: table_name.into(),
+            }
+            .to_string()]
         }
-        .to_string()]
     }
 
     fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
@@ -421,9 +444,9 @@ impl MysqlAlterColumn {
             return MysqlAlterColumn::DropDefault;
         }
 
-        if changes.column_was_renamed() {
-            unreachable!("MySQL column renaming.")
-        }
+        // Like on Postgres, `column_was_renamed()` can't actually be true today: `DifferDatabase`
+        // only ever pairs up columns that share the same name across schemas. See the doc comment
+        // on `ColumnChange::Renaming`.
 
         let defaults = (
             columns.previous().default().as_ref().map(|d| d.kind()),
@@ -42,29 +42,34 @@ impl SqlRenderer for PostgresFlavour {
         Quoted::postgres_ident(name)
     }
 
-    fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_add_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         ddl::AlterTable {
-            table_name: ddl::PostgresIdentifier::Simple(foreign_key.table().name().into()),
-            clauses: vec![ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
-                constrained_columns: foreign_key.constrained_columns().map(|c| c.name().into()).collect(),
-                referenced_columns: foreign_key.referenced_column_names().iter().map(|c| c.into()).collect(),
-                constraint_name: foreign_key.constraint_name().map(From::from),
-                referenced_table: foreign_key.referenced_table().name().into(),
-                on_delete: Some(match foreign_key.on_delete_action() {
-                    ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
-                    ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
-                    ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
-                    ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
-                }),
-                on_update: Some(match foreign_key.on_update_action() {
-                    ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
-                    ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
-                    ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
-                    ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
-                }),
-            })],
+            table_name: ddl::PostgresIdentifier::Simple(foreign_keys[0].table().name().into()),
+            clauses: foreign_keys
+                .iter()
+                .map(|foreign_key| {
+                    ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
+                        constrained_columns: foreign_key.constrained_columns().map(|c| c.name().into()).collect(),
+                        referenced_columns: foreign_key.referenced_column_names().iter().map(|c| c.into()).collect(),
+                        constraint_name: foreign_key.constraint_name().map(From::from),
+                        referenced_table: foreign_key.referenced_table().name().into(),
+                        on_delete: Some(match foreign_key.on_delete_action() {
+                            ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
+                            ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                            ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
+                            ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
+                            ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
+                        }),
+                        on_update: Some(match foreign_key.on_update_action() {
+                            ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
+                            ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                            ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
+                            ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
+                            ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
+                        }),
+                    })
+                })
+                .collect(),
         }
         .to_string()
     }
@@ -291,6 +296,29 @@ impl SqlRenderer for PostgresFlavour {
                     let col_sql = self.render_column(columns.next());
                     lines.push(format!("ADD COLUMN {}", col_sql));
                 }
+                TableChange::UpdateTableOptions => {
+                    let previous_options = tables.previous().storage_options();
+                    let next_options = tables.next().storage_options();
+
+                    let set_options = next_options
+                        .iter()
+                        .map(|(option, value)| format!("{} = {}", option, value))
+                        .join(", ");
+
+                    if !set_options.is_empty() {
+                        lines.push(format!("SET ({})", set_options));
+                    }
+
+                    let reset_options = previous_options
+                        .keys()
+                        .filter(|option| !next_options.contains_key(*option))
+                        .map(|option| option.to_string())
+                        .join(", ");
+
+                    if !reset_options.is_empty() {
+                        lines.push(format!("RESET ({})", reset_options));
+                    }
+                }
             };
         }
 
@@ -325,6 +353,8 @@ impl SqlRenderer for PostgresFlavour {
             is_unique: index.index_type().is_unique(),
             table_reference: index.table().name().into(),
             columns: index.columns().map(|c| c.name().into()).collect(),
+            nulls_not_distinct: index.nulls_not_distinct(),
+            predicate: index.predicate().map(Cow::Borrowed),
         }
         .to_string()
     }
@@ -344,11 +374,14 @@ impl SqlRenderer for PostgresFlavour {
             String::new()
         };
 
+        let with_options = render_postgres_table_options(table.storage_options());
+
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n){with_options}",
             table_name = self.quote(table_name),
             columns = columns,
             primary_key = pk,
+            with_options = with_options,
         )
     }
 
@@ -361,11 +394,17 @@ impl SqlRenderer for PostgresFlavour {
         vec![sql]
     }
 
-    fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_drop_foreign_keys(&self, foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         format!(
-            "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote(foreign_key.table().name()),
-            constraint_name = Quoted::postgres_ident(foreign_key.constraint_name().unwrap()),
+            "ALTER TABLE {table} {drops}",
+            table = self.quote(foreign_keys[0].table().name()),
+            drops = foreign_keys
+                .iter()
+                .map(|foreign_key| format!(
+                    "DROP CONSTRAINT {constraint_name}",
+                    constraint_name = Quoted::postgres_ident(foreign_key.constraint_name().unwrap())
+                ))
+                .join(", "),
         )
     }
 
@@ -376,11 +415,18 @@ impl SqlRenderer for PostgresFlavour {
         .to_string()
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![ddl::DropTable {
-            table_name: table_name.into(),
+    fn render_drop_table(&self, table_name: &str, idempotent: bool) -> Vec<String> {
+        if idempotent {
+            vec![format!(
+                "DROP TABLE IF EXISTS {}",
+                ddl::PostgresIdentifier::from(table_name)
+            )]
+        } else {
+            vec![ddl::DropTable {
+                table_name: table_name.into(),
+            }
+            .to_string()]
         }
-        .to_string()]
     }
 
     fn render_drop_view(&self, view: &ViewWalker<'_>) -> String {
@@ -394,6 +440,15 @@ impl SqlRenderer for PostgresFlavour {
         unreachable!("render_redefine_table on Postgres")
     }
 
+    fn render_rename_foreign_key(&self, foreign_keys: Pair<&ForeignKeyWalker<'_>>) -> String {
+        format!(
+            "ALTER TABLE {table} RENAME CONSTRAINT {previous} TO {next}",
+            table = self.quote(foreign_keys.next().table().name()),
+            previous = Quoted::postgres_ident(foreign_keys.previous().constraint_name().unwrap()),
+            next = Quoted::postgres_ident(foreign_keys.next().constraint_name().unwrap()),
+        )
+    }
+
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
         ddl::AlterTable {
             table_name: name.into(),
@@ -407,6 +462,21 @@ impl SqlRenderer for PostgresFlavour {
     }
 }
 
+/// Renders the trailing ` WITH (fillfactor = 70, ...)` clause for a `CREATE TABLE`, or an
+/// empty string when the table has no storage options.
+fn render_postgres_table_options(storage_options: &std::collections::BTreeMap<String, String>) -> String {
+    if storage_options.is_empty() {
+        return String::new();
+    }
+
+    let options = storage_options
+        .iter()
+        .map(|(option, value)| format!("{} = {}", option, value))
+        .join(", ");
+
+    format!(" WITH ({})", options)
+}
+
 pub(crate) fn render_column_type(col: &ColumnWalker<'_>) -> Cow<'static, str> {
     let t = col.column_type();
     let is_autoincrement = col.is_autoincrement();
@@ -590,7 +660,11 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
-            ColumnChange::Renaming => unreachable!("column renaming"),
+            // `DifferDatabase` currently pairs columns across schemas by exact name match, so a
+            // `ColumnDiffer` can never actually see `previous.name() != next.name()` - a real rename
+            // would need an explicit hint from outside the two `SqlSchema`s being compared, which
+            // nothing produces today. Nothing to render until that exists.
+            ColumnChange::Renaming => (),
         }
     }
 
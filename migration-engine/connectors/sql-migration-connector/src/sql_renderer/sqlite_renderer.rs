@@ -38,7 +38,7 @@ impl SqlRenderer for SqliteFlavour {
         )
     }
 
-    fn render_add_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_add_foreign_keys(&self, _foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         unreachable!("AddForeignKey on SQLite")
     }
 
@@ -69,6 +69,7 @@ impl SqlRenderer for SqliteFlavour {
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
                 TableChange::DropColumn { .. } => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
+                TableChange::UpdateTableOptions => unreachable!("UpdateTableOptions on SQLite"),
             };
         }
 
@@ -118,7 +119,7 @@ impl SqlRenderer for SqliteFlavour {
         unreachable!("Unreachable render_drop_enum() on SQLite. SQLite does not have enums.")
     }
 
-    fn render_drop_foreign_key(&self, _foreign_key: &ForeignKeyWalker<'_>) -> String {
+    fn render_drop_foreign_keys(&self, _foreign_keys: &[ForeignKeyWalker<'_>]) -> String {
         unreachable!("render_drop_foreign_key on SQLite")
     }
 
@@ -126,14 +127,20 @@ impl SqlRenderer for SqliteFlavour {
         format!("DROP INDEX {}", self.quote(index.name()))
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, table_name: &str, idempotent: bool) -> Vec<String> {
         // Turning off the pragma is safe, because schema validation would forbid foreign keys
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
+        let drop_table = if idempotent {
+            format!("DROP TABLE IF EXISTS {}", self.quote(&table_name))
+        } else {
+            format!("DROP TABLE {}", self.quote(&table_name))
+        };
+
         vec![
             "PRAGMA foreign_keys=off".to_string(),
-            format!("DROP TABLE {}", self.quote(&table_name)),
+            drop_table,
             "PRAGMA foreign_keys=on".to_string(),
         ]
     }
@@ -2,7 +2,7 @@ mod sql_schema_calculator_flavour;
 
 pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 
-use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
+use crate::{flavour::SqlFlavour, identifier_length::shorten_index_name, sql_renderer::IteratorJoin};
 use datamodel::{walkers::RelationFieldWalker, Configuration};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
@@ -52,9 +52,15 @@ fn calculate_model_tables<'a>(
         .filter(|pk| !pk.columns.is_empty());
 
         let single_field_indexes = model.scalar_fields().filter(|f| f.is_unique()).map(|f| sql::Index {
-            name: flavour.single_field_index_name(model.db_name(), f.db_name()),
+            name: shorten_index_name(
+                &flavour.single_field_index_name(model.db_name(), f.db_name()),
+                flavour.max_identifier_length(),
+            )
+            .into_owned(),
             columns: vec![f.db_name().to_owned()],
             tpe: sql::IndexType::Unique,
+            nulls_not_distinct: false,
+            predicate: None,
         });
 
         let multiple_field_indexes = model.indexes().map(|index_definition: &IndexDefinition| {
@@ -81,6 +87,7 @@ fn calculate_model_tables<'a>(
                     qualifier = if index_type.is_unique() { "unique" } else { "index" },
                 )
             });
+            let index_name = shorten_index_name(&index_name, flavour.max_identifier_length()).into_owned();
 
             sql::Index {
                 name: index_name,
@@ -91,6 +98,8 @@ fn calculate_model_tables<'a>(
                     .map(|field| field.db_name().to_owned())
                     .collect(),
                 tpe: index_type,
+                nulls_not_distinct: index_definition.nulls_not_distinct,
+                predicate: index_definition.predicate.clone(),
             }
         });
 
@@ -100,6 +109,11 @@ fn calculate_model_tables<'a>(
             indices: single_field_indexes.chain(multiple_field_indexes).collect(),
             primary_key,
             foreign_keys: Vec::new(),
+            storage_options: Default::default(),
+            check_constraints: Default::default(),
+
+            identity_columns: Default::default(),
+            description: None,
         };
 
         push_inline_relations(model, &mut table);
@@ -168,6 +182,8 @@ fn push_one_to_one_relation_unique_index(column_names: &[String], table: &mut sq
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        nulls_not_distinct: false,
+        predicate: None,
     };
 
     table.indices.push(index);
@@ -211,11 +227,15 @@ fn calculate_relation_tables<'a>(
                     name: format!("{}_AB_unique", &table_name),
                     columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                     tpe: sql::IndexType::Unique,
+                    nulls_not_distinct: false,
+                    predicate: None,
                 },
                 sql::Index {
                     name: format!("{}_B_index", &table_name),
                     columns: vec![m2m.model_b_column().into()],
                     tpe: sql::IndexType::Normal,
+                    nulls_not_distinct: false,
+                    predicate: None,
                 },
             ];
 
@@ -225,12 +245,14 @@ fn calculate_relation_tables<'a>(
                     tpe: column_type_for_implicit_relation(&model_a_id, schema),
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
                 sql::Column {
                     name: m2m.model_b_column().into(),
                     tpe: column_type_for_implicit_relation(&model_b_id, schema),
                     default: None,
                     auto_increment: false,
+                    description: None,
                 },
             ];
 
@@ -240,6 +262,11 @@ fn calculate_relation_tables<'a>(
                 indices: indexes,
                 primary_key: None,
                 foreign_keys,
+                storage_options: Default::default(),
+                check_constraints: Default::default(),
+
+                identity_columns: Default::default(),
+                description: None,
             }
         })
 }
@@ -287,6 +314,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
                         ))
                     }),
                 auto_increment: false,
+                description: None,
             }
         }
         TypeWalker::Base(scalar_type) => (scalar_type, flavour.default_native_type_for_scalar_type(&scalar_type)),
@@ -302,6 +330,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
                 },
                 default: field.default_value().and_then(|v| db_generated(v)),
                 auto_increment: false,
+                description: None,
             }
         }
     };
@@ -325,6 +354,7 @@ fn column_for_scalar_field(field: &ScalarFieldWalker<'_>, flavour: &dyn SqlFlavo
 
     sql::Column {
         auto_increment: has_auto_increment_default || flavour.field_is_implicit_autoincrement_primary_key(field),
+        description: None,
         name: field.db_name().to_owned(),
         tpe: sql::ColumnType {
             full_data_type: String::new(),
@@ -42,4 +42,11 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
     fn single_field_index_name(&self, model_name: &str, field_name: &str) -> String {
         format!("{}.{}_unique", model_name, field_name)
     }
+
+    /// The maximum length, in bytes, of an index or constraint name on this flavour. Names longer
+    /// than this get shortened (see `identifier_length::shorten_index_name`) before being used, to
+    /// avoid the database silently truncating them itself and causing name collisions.
+    fn max_identifier_length(&self) -> usize {
+        usize::MAX
+    }
 }
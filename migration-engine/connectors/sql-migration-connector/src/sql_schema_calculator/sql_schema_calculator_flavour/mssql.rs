@@ -1,10 +1,14 @@
 use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::MssqlFlavour;
+use crate::flavour::{MssqlFlavour, MSSQL_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{walkers::ModelWalker, ScalarType};
 use datamodel_connector::Connector;
 use sql_schema_describer::ForeignKeyAction;
 
 impl SqlSchemaCalculatorFlavour for MssqlFlavour {
+    fn max_identifier_length(&self) -> usize {
+        MSSQL_IDENTIFIER_SIZE_LIMIT
+    }
+
     fn default_native_type_for_scalar_type(&self, scalar_type: &ScalarType) -> serde_json::Value {
         sql_datamodel_connector::SqlDatamodelConnectors::mssql().default_native_type_for_scalar_type(scalar_type)
     }
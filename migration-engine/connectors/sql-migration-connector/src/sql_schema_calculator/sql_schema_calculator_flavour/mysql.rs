@@ -1,5 +1,5 @@
 use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::MysqlFlavour;
+use crate::flavour::{MysqlFlavour, MYSQL_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{
     walkers::{walk_scalar_fields, ScalarFieldWalker},
     Datamodel, ScalarType,
@@ -8,6 +8,10 @@ use datamodel_connector::Connector;
 use sql_schema_describer::{self as sql};
 
 impl SqlSchemaCalculatorFlavour for MysqlFlavour {
+    fn max_identifier_length(&self) -> usize {
+        MYSQL_IDENTIFIER_SIZE_LIMIT
+    }
+
     fn calculate_enums(&self, datamodel: &Datamodel) -> Vec<sql::Enum> {
         // This is a lower bound for the size of the generated enums (we assume
         // each enum is used at least once).
@@ -1,10 +1,14 @@
 use super::SqlSchemaCalculatorFlavour;
-use crate::flavour::PostgresFlavour;
+use crate::flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT};
 use datamodel::{walkers::ScalarFieldWalker, Datamodel, ScalarType, WithDatabaseName};
 use datamodel_connector::Connector;
 use sql_schema_describer::{self as sql};
 
 impl SqlSchemaCalculatorFlavour for PostgresFlavour {
+    fn max_identifier_length(&self) -> usize {
+        POSTGRES_IDENTIFIER_SIZE_LIMIT
+    }
+
     fn calculate_enums(&self, datamodel: &Datamodel) -> Vec<sql::Enum> {
         datamodel
             .enums()
@@ -32,6 +32,12 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
     let tables_to_redefine = differ.flavour.tables_to_redefine(&differ);
     let mut alter_indexes = differ.alter_indexes(&tables_to_redefine);
 
+    let rename_foreign_keys = if differ.flavour.can_rename_foreign_key() {
+        differ.rename_foreign_keys(&tables_to_redefine)
+    } else {
+        Vec::new()
+    };
+
     let redefine_indexes = if differ.flavour.can_alter_index() {
         Vec::new()
     } else {
@@ -78,12 +84,82 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
                         table: idxs.as_ref().map(|(table, _)| *table),
                         index: idxs.as_ref().map(|(_, idx)| *idx),
                     }),
+            )
+            .chain(
+                rename_foreign_keys
+                    .into_iter()
+                    .map(|fks| SqlMigrationStep::RenameForeignKey {
+                        table: fks.as_ref().map(|(table, _)| *table),
+                        foreign_key: fks.as_ref().map(|(_, fk)| *fk),
+                    }),
             ),
     );
 
     steps.sort();
 
-    steps
+    compact_steps(steps)
+}
+
+/// Merges runs of `AddForeignKey`/`DropForeignKey` steps that target the same table into a single
+/// step, so the renderer can emit one `ALTER TABLE ... ADD/DROP CONSTRAINT ..., ADD/DROP CONSTRAINT
+/// ...` statement instead of one statement per foreign key. This matters most on MySQL, where each
+/// `ALTER TABLE` can rebuild the whole table depending on the storage engine.
+///
+/// Relies on `steps` already being sorted: `SqlMigrationStep`'s `Ord` groups same-variant steps
+/// together and orders them by `table_index` first, so every run of foreign key steps for the same
+/// table is contiguous.
+fn compact_steps(steps: Vec<SqlMigrationStep>) -> Vec<SqlMigrationStep> {
+    let mut compacted: Vec<SqlMigrationStep> = Vec::with_capacity(steps.len());
+
+    for step in steps {
+        let should_merge = match (&step, compacted.last()) {
+            (
+                SqlMigrationStep::AddForeignKey { table_index, .. },
+                Some(SqlMigrationStep::AddForeignKey {
+                    table_index: prev_table_index,
+                    ..
+                }),
+            )
+            | (
+                SqlMigrationStep::DropForeignKey { table_index, .. },
+                Some(SqlMigrationStep::DropForeignKey {
+                    table_index: prev_table_index,
+                    ..
+                }),
+            ) => table_index == prev_table_index,
+            _ => false,
+        };
+
+        if should_merge {
+            match (compacted.last_mut().unwrap(), step) {
+                (
+                    SqlMigrationStep::AddForeignKey {
+                        foreign_key_indexes: prev_indexes,
+                        ..
+                    },
+                    SqlMigrationStep::AddForeignKey {
+                        mut foreign_key_indexes,
+                        ..
+                    },
+                )
+                | (
+                    SqlMigrationStep::DropForeignKey {
+                        foreign_key_indexes: prev_indexes,
+                        ..
+                    },
+                    SqlMigrationStep::DropForeignKey {
+                        mut foreign_key_indexes,
+                        ..
+                    },
+                ) => prev_indexes.append(&mut foreign_key_indexes),
+                _ => unreachable!("should_merge only matches AddForeignKey/DropForeignKey pairs"),
+            }
+        } else {
+            compacted.push(step);
+        }
+    }
+
+    compacted
 }
 
 pub(crate) struct SqlSchemaDiffer<'a> {
@@ -103,7 +179,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 for fk in table.foreign_keys() {
                     steps.push(SqlMigrationStep::AddForeignKey {
                         table_index: table.table_index(),
-                        foreign_key_index: fk.foreign_key_index(),
+                        foreign_key_indexes: vec![fk.foreign_key_index()],
                     });
                 }
             }
@@ -125,7 +201,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for fk in dropped_table.foreign_keys() {
                 steps.push(SqlMigrationStep::DropForeignKey {
                     table_index: dropped_table.table_index(),
-                    foreign_key_index: fk.foreign_key_index(),
+                    foreign_key_indexes: vec![fk.foreign_key_index()],
                 });
             }
         }
@@ -139,7 +215,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for created_fk in table.created_foreign_keys() {
                 steps.push(SqlMigrationStep::AddForeignKey {
                     table_index: created_fk.table().table_index(),
-                    foreign_key_index: created_fk.foreign_key_index(),
+                    foreign_key_indexes: vec![created_fk.foreign_key_index()],
                 })
             }
         }
@@ -159,6 +235,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     .chain(SqlSchemaDiffer::add_columns(&differ))
                     .chain(SqlSchemaDiffer::alter_columns(&differ).into_iter())
                     .chain(SqlSchemaDiffer::add_primary_key(&differ))
+                    .chain(SqlSchemaDiffer::update_table_options(&differ))
                     .collect();
 
                 Some(changes)
@@ -234,7 +311,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for dropped_fk in differ.dropped_foreign_keys() {
                 steps.push(SqlMigrationStep::DropForeignKey {
                     table_index: differ.previous().table_index(),
-                    foreign_key_index: dropped_fk.foreign_key_index(),
+                    foreign_key_indexes: vec![dropped_fk.foreign_key_index()],
                 })
             }
         }
@@ -271,6 +348,17 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         }
     }
 
+    fn update_table_options(differ: &TableDiffer<'_, '_>) -> Option<TableChange> {
+        let previous = differ.tables.previous().storage_options();
+        let next = differ.tables.next().storage_options();
+
+        if previous == next {
+            None
+        } else {
+            Some(TableChange::UpdateTableOptions)
+        }
+    }
+
     fn drop_primary_key(differ: &TableDiffer<'_, '_>) -> Option<TableChange> {
         let from_psl_change = differ.dropped_primary_key().map(|_pk| TableChange::DropPrimaryKey);
 
@@ -433,6 +521,27 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         steps
     }
 
+    fn rename_foreign_keys(&self, tables_to_redefine: &HashSet<String>) -> Vec<Pair<(usize, usize)>> {
+        let mut steps = Vec::new();
+
+        for differ in self
+            .table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+        {
+            for pair in differ
+                .foreign_key_pairs()
+                .filter(|pair| self.flavour.foreign_key_should_be_renamed(pair))
+            {
+                steps.push(
+                    pair.as_ref()
+                        .map(|fk| (fk.table().table_index(), fk.foreign_key_index())),
+                );
+            }
+        }
+
+        steps
+    }
+
     fn created_tables(&self) -> impl Iterator<Item = TableWalker<'schema>> + '_ {
         self.db
             .created_tables()
@@ -542,11 +651,21 @@ fn foreign_keys_match(fks: Pair<&ForeignKeyWalker<'_>>, flavour: &dyn SqlFlavour
         .interleave(|fk| fk.referenced_column_names())
         .all(|pair| pair.previous() == pair.next());
 
+    // A foreign key that only differs by its `ON DELETE`/`ON UPDATE` action is not the same
+    // foreign key: on most connectors, actions cannot be altered in place, so we still need to
+    // drop and recreate the constraint for the new action to take effect. If we considered these
+    // a match, `created_foreign_keys`/`dropped_foreign_keys` would not emit any step at all for an
+    // action-only change, silently leaving the database with the old action.
+    let same_on_delete_action = fks.previous().on_delete_action() == fks.next().on_delete_action();
+    let same_on_update_action = fks.previous().on_update_action() == fks.next().on_update_action();
+
     references_same_table
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
         && references_same_columns
+        && same_on_delete_action
+        && same_on_update_action
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
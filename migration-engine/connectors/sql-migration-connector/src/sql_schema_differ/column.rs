@@ -3,6 +3,11 @@ use enumflags2::BitFlags;
 use prisma_value::PrismaValue;
 use sql_schema_describer::{walkers::ColumnWalker, DefaultKind};
 
+/// Note: `Column::description` is not compared here either, for the same reason `check_constraints`
+/// isn't compared in `TableDiffer` (see its doc comment): it's only ever populated for Postgres and
+/// MySQL today, and MySQL bakes column comments into the column definition itself rather than
+/// exposing a standalone `ALTER ... COMMENT` statement, so a real implementation would mean
+/// reworking column-definition rendering everywhere, not just adding a `ColumnChange` variant.
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
     pub(crate) flavour: &'a dyn SqlFlavour,
@@ -123,10 +128,25 @@ fn json_defaults_match(previous: &str, next: &str) -> bool {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
 pub(crate) enum ColumnChange {
+    /// Set when `previous.name() != next.name()`. In practice this can't currently happen:
+    /// `DifferDatabase` builds its previous/next column pairing by matching on identical column
+    /// names within a table, so any two columns that end up in the same `ColumnDiffer` already have
+    /// the same name. A real rename (e.g. renaming a field's `@map`) is invisible to a diff between
+    /// two `SqlSchema`s taken in isolation - detecting it would need an explicit rename mapping
+    /// supplied from outside, threaded down from wherever the previous/next schemas are produced.
+    /// Nothing currently does that, so this flag - and the `RENAME COLUMN`/`sp_rename` step it would
+    /// justify - stays unused; renamed columns are migrated as a drop and an add instead.
     Renaming,
     Arity,
     Default,
     TypeChanged,
+    /// Set when `ColumnWalker::is_autoincrement()` differs between the two sides. Note that
+    /// converting a Postgres column between a plain serial (`DefaultKind::Sequence`) and a
+    /// `GENERATED ... AS IDENTITY` column (see `sql_schema_describer::IdentityGeneration`), or
+    /// between the `ALWAYS`/`BY DEFAULT` identity forms, doesn't set this flag or any other -
+    /// `is_autoincrement()` is true on both sides either way, and nothing else in
+    /// `ColumnDiffer` looks at `Table::identity_columns`. Migrating between the two forms
+    /// today has to be done as a manual, unmanaged SQL migration.
     Sequence,
 }
 
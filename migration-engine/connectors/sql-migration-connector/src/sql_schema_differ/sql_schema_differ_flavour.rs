@@ -3,7 +3,7 @@ use crate::{
     pair::Pair,
     sql_migration::{AlterEnum, AlterTable, SqlMigrationStep},
 };
-use sql_schema_describer::walkers::IndexWalker;
+use sql_schema_describer::walkers::{ForeignKeyWalker, IndexWalker};
 use std::collections::HashSet;
 
 mod mssql;
@@ -31,6 +31,27 @@ pub(crate) trait SqlSchemaDifferFlavour {
         true
     }
 
+    /// If this returns `true`, the differ will generate `RenameForeignKey` steps for foreign
+    /// keys that only changed their constraint name, instead of leaving the old name in place.
+    fn can_rename_foreign_key(&self) -> bool {
+        false
+    }
+
+    /// Return whether a foreign key should be renamed by the migration.
+    ///
+    /// Both sides must have an explicit name for this to fire. `next()`'s constraint name is
+    /// `None` for any foreign key calculated from a Prisma schema (there is no `map:`-style
+    /// argument on `@relation` to set one), so treating a `None` there as "no name" rather than
+    /// "different from whatever `previous()` has" avoids renaming (and panicking on render,
+    /// since `render_rename_foreign_key` unwraps both names) every foreign key that carries a
+    /// database-assigned default constraint name across an otherwise no-op diff.
+    fn foreign_key_should_be_renamed(&self, foreign_keys: &Pair<ForeignKeyWalker<'_>>) -> bool {
+        matches!(
+            (foreign_keys.previous().constraint_name(), foreign_keys.next().constraint_name()),
+            (Some(previous), Some(next)) if previous != next
+        )
+    }
+
     /// Return whether a column's type needs to be migrated, and how.
     fn column_type_change(&self, differ: &ColumnDiffer<'_>) -> Option<ColumnTypeChange> {
         if differ.previous.column_type_family() != differ.next.column_type_family() {
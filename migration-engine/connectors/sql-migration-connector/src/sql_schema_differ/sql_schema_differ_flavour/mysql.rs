@@ -8,7 +8,8 @@ use sql_schema_describer::{walkers::IndexWalker, ColumnTypeFamily};
 
 impl SqlSchemaDifferFlavour for MysqlFlavour {
     fn can_alter_index(&self) -> bool {
-        !self.is_mariadb() && !self.is_mysql_5_6()
+        // RENAME INDEX was only added in MySQL 5.7.
+        !self.is_mariadb() && !self.is_mysql_5_6() && !self.is_mysql_5_5()
     }
 
     fn can_cope_with_foreign_key_column_becoming_nonnullable(&self) -> bool {
@@ -1,6 +1,6 @@
 use super::SqlSchemaDifferFlavour;
 use crate::{
-    flavour::PostgresFlavour,
+    flavour::{PostgresFlavour, POSTGRES_IDENTIFIER_SIZE_LIMIT},
     pair::Pair,
     sql_migration::{AlterEnum, SqlMigrationStep},
     sql_schema_differ::{
@@ -13,11 +13,6 @@ use once_cell::sync::Lazy;
 use regex::RegexSet;
 use sql_schema_describer::walkers::IndexWalker;
 
-/// The maximum length of postgres identifiers, in bytes.
-///
-/// Reference: https://www.postgresql.org/docs/12/limits.html
-const POSTGRES_IDENTIFIER_SIZE_LIMIT: usize = 63;
-
 impl SqlSchemaDifferFlavour for PostgresFlavour {
     fn alter_enums(&self, differ: &SqlSchemaDiffer<'_>) -> Vec<AlterEnum> {
         differ
@@ -39,6 +34,10 @@ impl SqlSchemaDifferFlavour for PostgresFlavour {
             .collect()
     }
 
+    fn can_rename_foreign_key(&self) -> bool {
+        true
+    }
+
     fn create_enums(&self, differ: &SqlSchemaDiffer<'_>, steps: &mut Vec<SqlMigrationStep>) {
         for enm in differ.created_enums() {
             steps.push(SqlMigrationStep::CreateEnum {
@@ -5,6 +5,17 @@ use sql_schema_describer::{
     PrimaryKey,
 };
 
+/// Note: `Table::check_constraints` is not compared here, so a CHECK constraint added, dropped or
+/// changed only on one side of the diff produces no migration step. Columns, indexes and foreign
+/// keys all have a `SqlMigrationStep`/`AlterTable::TableChange` variant an implementer can act on
+/// (add/drop/alter); check constraints don't have one yet, and every `sql_renderer` flavour would
+/// need `ALTER TABLE ... ADD/DROP CONSTRAINT ... CHECK (...)` support to make a diff useful. Since
+/// `check_constraints` is only ever populated for Postgres today (see
+/// `sql_schema_describer::CheckConstraint`), adding one without the others would also make this
+/// diff Postgres-only in a way the rest of `TableDiffer` isn't.
+///
+/// `Table::description` is not compared here for the same reason (see `ColumnDiffer`'s doc comment
+/// for the column-level equivalent, `Column::description`).
 pub(crate) struct TableDiffer<'a, 'b> {
     pub(crate) flavour: &'a dyn SqlFlavour,
     pub(crate) tables: Pair<TableWalker<'a>>,
@@ -66,6 +77,30 @@ impl<'schema, 'b> TableDiffer<'schema, 'b> {
         })
     }
 
+    /// Pairs of foreign keys that reference the same table/columns/actions on both sides of the
+    /// diff (see `super::foreign_keys_match`), and so are candidates for a `RenameForeignKey`
+    /// step when their constraint names differ, instead of being treated as unrelated
+    /// created/dropped constraints.
+    pub(crate) fn foreign_key_pairs<'a>(&'a self) -> impl Iterator<Item = Pair<ForeignKeyWalker<'schema>>> + 'a {
+        let singular_fks = self.previous_foreign_keys().filter(move |left| {
+            // Renaming a foreign key in a situation where several foreign keys match the same
+            // columns/target/actions, but a different name, is highly unstable. We do not
+            // rename them for now.
+            let number_of_identical_fks = self
+                .previous_foreign_keys()
+                .filter(|right| super::foreign_keys_match(Pair::new(left, right), self.flavour))
+                .count();
+
+            number_of_identical_fks == 1
+        });
+
+        singular_fks.filter_map(move |previous_fk| {
+            self.next_foreign_keys()
+                .find(|next_fk| super::foreign_keys_match(Pair::new(&previous_fk, next_fk), self.flavour))
+                .map(|renamed_fk| Pair::new(previous_fk, renamed_fk))
+        })
+    }
+
     pub(crate) fn index_pairs<'a>(&'a self) -> impl Iterator<Item = Pair<IndexWalker<'schema>>> + 'a {
         let singular_indexes = self.previous_indexes().filter(move |left| {
             // Renaming an index in a situation where we have multiple indexes
@@ -73,7 +108,12 @@ impl<'schema, 'b> TableDiffer<'schema, 'b> {
             // We do not rename them for now.
             let number_of_identical_indexes = self
                 .previous_indexes()
-                .filter(|right| left.column_names() == right.column_names() && left.index_type() == right.index_type())
+                .filter(|right| {
+                    left.column_names() == right.column_names()
+                        && left.index_type() == right.index_type()
+                        && left.nulls_not_distinct() == right.nulls_not_distinct()
+                        && left.predicate() == right.predicate()
+                })
                 .count();
 
             number_of_identical_indexes == 1
@@ -157,5 +197,8 @@ impl<'schema, 'b> TableDiffer<'schema, 'b> {
 
 /// Compare two SQL indexes and return whether they only differ by name.
 fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+    first.column_names() == second.column_names()
+        && first.index_type() == second.index_type()
+        && first.nulls_not_distinct() == second.nulls_not_distinct()
+        && first.predicate() == second.predicate()
 }
@@ -21,6 +21,10 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Generate a new migration, based on the provided schema and existing migrations history.
     async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
 
+    /// Run a raw script against a database or shadow database, bypassing the migrations system.
+    /// Mostly useful as a building block for db seeding and other escape-hatch workflows.
+    async fn db_execute(&self, input: &DbExecuteInput) -> CoreResult<DbExecuteOutput>;
+
     /// Debugging method that only panics, for CLI tests.
     async fn debug_panic(&self) -> CoreResult<()>;
 
@@ -36,6 +40,13 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Evaluate the consequences of running the next migration we would generate, given the current state of a Prisma schema.
     async fn evaluate_data_loss(&self, input: &EvaluateDataLossInput) -> CoreResult<EvaluateDataLossOutput>;
 
+    /// Export the ordered lineage of migrations in a migrations directory, with their checksums,
+    /// as a portable document for drift auditing against a `_prisma_migrations` table.
+    async fn export_migration_lineage(
+        &self,
+        input: &ExportMigrationLineageInput,
+    ) -> CoreResult<ExportMigrationLineageOutput>;
+
     /// List the migration directories.
     async fn list_migration_directories(
         &self,
@@ -52,9 +63,24 @@ pub trait GenericApi: Send + Sync + 'static {
         input: &MarkMigrationRolledBackInput,
     ) -> CoreResult<MarkMigrationRolledBackOutput>;
 
+    /// Baseline a database by marking a batch of migrations from the migrations folder as
+    /// applied, without actually applying them.
+    async fn mark_migrations_applied(
+        &self,
+        input: &MarkMigrationsAppliedInput,
+    ) -> CoreResult<MarkMigrationsAppliedOutput>;
+
     /// Prepare to create a migration.
     async fn plan_migration(&self) -> CoreResult<()>;
 
+    /// Preview the SQL script for the migration from the current state of the database (or from
+    /// an empty schema) to the target schema, without touching the shadow database or writing a
+    /// migration file.
+    async fn preview_migration_script(
+        &self,
+        input: &PreviewMigrationScriptInput,
+    ) -> CoreResult<PreviewMigrationScriptOutput>;
+
     /// Reset a database to an empty state (no data, no schema).
     async fn reset(&self) -> CoreResult<()>;
 
@@ -84,6 +110,10 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn db_execute(&self, input: &DbExecuteInput) -> CoreResult<DbExecuteOutput> {
+        db_execute(input).await
+    }
+
     async fn debug_panic(&self) -> CoreResult<()> {
         panic!("This is the debugPanic artificial panic")
     }
@@ -109,6 +139,26 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn export_migration_lineage(
+        &self,
+        input: &ExportMigrationLineageInput,
+    ) -> CoreResult<ExportMigrationLineageOutput> {
+        let migrations_from_filesystem =
+            migrations_directory::list_migrations(&Path::new(&input.migrations_directory_path))?;
+
+        let migrations = migrations_from_filesystem
+            .iter()
+            .map(|migration| {
+                Ok(LineageMigration {
+                    migration_name: migration.migration_name().to_string(),
+                    checksum: migration.migration_script_checksum()?,
+                })
+            })
+            .collect::<Result<Vec<_>, migration_connector::migrations_directory::ReadMigrationScriptError>>()?;
+
+        Ok(ExportMigrationLineageOutput { migrations })
+    }
+
     async fn list_migration_directories(
         &self,
         input: &ListMigrationDirectoriesInput,
@@ -148,10 +198,29 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn mark_migrations_applied(
+        &self,
+        input: &MarkMigrationsAppliedInput,
+    ) -> CoreResult<MarkMigrationsAppliedOutput> {
+        mark_migrations_applied(input, self)
+            .instrument(tracing::info_span!(
+                "MarkMigrationsApplied",
+                migration_names = ?input.migration_names,
+            ))
+            .await
+    }
+
     async fn plan_migration(&self) -> CoreResult<()> {
         unreachable!("PlanMigration command")
     }
 
+    async fn preview_migration_script(
+        &self,
+        input: &PreviewMigrationScriptInput,
+    ) -> CoreResult<PreviewMigrationScriptOutput> {
+        preview_migration_script(input, self).await
+    }
+
     async fn reset(&self) -> CoreResult<()> {
         tracing::debug!("Resetting the database.");
 
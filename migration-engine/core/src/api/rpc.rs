@@ -4,30 +4,38 @@ use std::sync::Arc;
 
 const APPLY_MIGRATIONS: &str = "applyMigrations";
 const CREATE_MIGRATION: &str = "createMigration";
+const DB_EXECUTE: &str = "dbExecute";
 const DEBUG_PANIC: &str = "debugPanic";
 const DEV_DIAGNOSTIC: &str = "devDiagnostic";
 const DIAGNOSE_MIGRATION_HISTORY: &str = "diagnoseMigrationHistory";
 const EVALUATE_DATA_LOSS: &str = "evaluateDataLoss";
+const EXPORT_MIGRATION_LINEAGE: &str = "exportMigrationLineage";
 const GET_DATABASE_VERSION: &str = "getDatabaseVersion";
 const LIST_MIGRATION_DIRECTORIES: &str = "listMigrationDirectories";
 const MARK_MIGRATION_APPLIED: &str = "markMigrationApplied";
 const MARK_MIGRATION_ROLLED_BACK: &str = "markMigrationRolledBack";
+const MARK_MIGRATIONS_APPLIED: &str = "markMigrationsApplied";
 const PLAN_MIGRATION: &str = "planMigration";
+const PREVIEW_MIGRATION_SCRIPT: &str = "previewMigrationScript";
 const RESET: &str = "reset";
 const SCHEMA_PUSH: &str = "schemaPush";
 
 const AVAILABLE_COMMANDS: &[&str] = &[
     APPLY_MIGRATIONS,
     CREATE_MIGRATION,
+    DB_EXECUTE,
     DEBUG_PANIC,
     DEV_DIAGNOSTIC,
     DIAGNOSE_MIGRATION_HISTORY,
     EVALUATE_DATA_LOSS,
+    EXPORT_MIGRATION_LINEAGE,
     GET_DATABASE_VERSION,
     LIST_MIGRATION_DIRECTORIES,
     MARK_MIGRATION_APPLIED,
     MARK_MIGRATION_ROLLED_BACK,
+    MARK_MIGRATIONS_APPLIED,
     PLAN_MIGRATION,
+    PREVIEW_MIGRATION_SCRIPT,
     RESET,
     SCHEMA_PUSH,
 ];
@@ -57,15 +65,19 @@ async fn run_command(
     match cmd {
         APPLY_MIGRATIONS => render(executor.apply_migrations(&params.parse()?).await),
         CREATE_MIGRATION => render(executor.create_migration(&params.parse()?).await),
+        DB_EXECUTE => render(executor.db_execute(&params.parse()?).await),
         DEV_DIAGNOSTIC => render(executor.dev_diagnostic(&params.parse()?).await),
         DEBUG_PANIC => render(executor.debug_panic().await),
         DIAGNOSE_MIGRATION_HISTORY => render(executor.diagnose_migration_history(&params.parse()?).await),
         EVALUATE_DATA_LOSS => render(executor.evaluate_data_loss(&params.parse()?).await),
+        EXPORT_MIGRATION_LINEAGE => render(executor.export_migration_lineage(&params.parse()?).await),
         GET_DATABASE_VERSION => render(executor.version().await),
         LIST_MIGRATION_DIRECTORIES => render(executor.list_migration_directories(&params.parse()?).await),
         MARK_MIGRATION_APPLIED => render(executor.mark_migration_applied(&params.parse()?).await),
         MARK_MIGRATION_ROLLED_BACK => render(executor.mark_migration_rolled_back(&params.parse()?).await),
+        MARK_MIGRATIONS_APPLIED => render(executor.mark_migrations_applied(&params.parse()?).await),
         PLAN_MIGRATION => render(executor.plan_migration().await),
+        PREVIEW_MIGRATION_SCRIPT => render(executor.preview_migration_script(&params.parse()?).await),
         RESET => render(executor.reset().await),
         SCHEMA_PUSH => render(executor.schema_push(&params.parse()?).await),
         other => unreachable!("Unknown command {}", other),
@@ -1,11 +1,11 @@
 use crate::{CoreError, CoreResult};
 use migration_connector::{
-    migrations_directory::{error_on_changed_provider, list_migrations, MigrationDirectory},
+    migrations_directory::{error_on_changed_provider, list_migrations, scan_table_references, MigrationDirectory},
     ConnectorError, MigrationRecord, PersistenceNotInitializedError,
 };
 use serde::{Deserialize, Serialize};
-use std::path::Path;
-use user_facing_errors::migration_engine::FoundFailedMigrations;
+use std::{collections::HashSet, path::Path};
+use user_facing_errors::migration_engine::{FoundFailedMigrations, MigrationReferencesUnknownTable};
 
 /// The input to the `ApplyMigrations` command.
 #[derive(Deserialize, Debug)]
@@ -13,6 +13,13 @@ use user_facing_errors::migration_engine::FoundFailedMigrations;
 pub struct ApplyMigrationsInput {
     /// The location of the migrations directory.
     pub migrations_directory_path: String,
+
+    /// If set, reject the whole migration history before applying anything when a migration
+    /// script references a table that neither it nor an earlier migration in the history creates. This is a
+    /// best-effort, regex-based check (see [`scan_table_references`]), meant to catch the wrong
+    /// migration script having been copied into a directory, not to validate arbitrary SQL.
+    #[serde(default)]
+    pub strict: bool,
 }
 
 /// The output of the `ApplyMigrations` command.
@@ -50,6 +57,10 @@ where
 
     detect_failed_migrations(&migrations_from_database)?;
 
+    if input.strict {
+        detect_out_of_namespace_references(&migrations_from_filesystem)?;
+    }
+
     // We are now on the Happy Path™.
     tracing::debug!("Migration history is OK, applying unapplied migrations.");
     let unapplied_migrations: Vec<&MigrationDirectory> = migrations_from_filesystem
@@ -145,3 +156,32 @@ fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> Cor
 
     Err(CoreError::user_facing(FoundFailedMigrations { details }))
 }
+
+/// Check that every table a migration script touches was created by that same migration or an
+/// earlier one in the history, catching e.g. a migration script that was copied from the wrong
+/// project into this directory. This walks the whole history, not just the unapplied migrations,
+/// since an unapplied migration is allowed to reference a table created by one that was already
+/// applied.
+fn detect_out_of_namespace_references(migrations_from_filesystem: &[MigrationDirectory]) -> CoreResult<()> {
+    tracing::debug!("Checking that migrations only reference tables created by the migration history.");
+
+    let mut known_tables: HashSet<String> = HashSet::new();
+
+    for migration in migrations_from_filesystem {
+        let script = migration.read_migration_script().map_err(ConnectorError::from)?;
+        let table_references = scan_table_references(&script);
+
+        known_tables.extend(table_references.created);
+
+        for table_name in &table_references.referenced {
+            if !known_tables.contains(table_name) {
+                return Err(CoreError::user_facing(MigrationReferencesUnknownTable {
+                    migration_name: migration.migration_name().to_owned(),
+                    table_name: table_name.clone(),
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
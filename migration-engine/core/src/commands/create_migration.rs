@@ -1,4 +1,4 @@
-use crate::{parse_schema, CoreError, CoreResult};
+use crate::{commands::evaluate_data_loss::MigrationFeedback, parse_schema, CoreError, CoreResult};
 use migration_connector::{migrations_directory::*, DiffTarget, MigrationConnector};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -24,6 +24,19 @@ pub struct CreateMigrationInput {
 pub struct CreateMigrationOutput {
     /// The name of the newly generated migration directory, if any.
     pub generated_migration_name: Option<String>,
+    /// Destructive change warnings for the generated migration, so it can be
+    /// assessed in code review without applying it. This is the same check
+    /// `evaluateDataLoss` runs, but without needing a connection to the
+    /// database: it only looks at the migration steps themselves.
+    ///
+    /// This does not include a lock-level or full-table-scan estimate per
+    /// statement: nothing in the migration connectors models that today, and
+    /// it varies with server version, table size and existing indexes in a
+    /// way a static check on the migration steps can't reliably predict.
+    pub warnings: Vec<MigrationFeedback>,
+    /// Steps in the generated migration that could not be executed against
+    /// the local development database, if any.
+    pub unexecutable_steps: Vec<MigrationFeedback>,
 }
 
 /// Create a new migration.
@@ -58,12 +71,34 @@ pub async fn create_migration(
 
         return Ok(CreateMigrationOutput {
             generated_migration_name: None,
+            warnings: Vec::new(),
+            unexecutable_steps: Vec::new(),
         });
     }
 
     let destructive_change_diagnostics = checker.pure_check(&migration);
 
-    let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
+    let warnings = destructive_change_diagnostics
+        .warnings
+        .iter()
+        .map(|warning| MigrationFeedback {
+            message: warning.description.clone(),
+            step_index: warning.step_index,
+            affected_rows: warning.affected_rows,
+        })
+        .collect();
+
+    let unexecutable_steps = destructive_change_diagnostics
+        .unexecutable_migrations
+        .iter()
+        .map(|unexecutable| MigrationFeedback {
+            message: unexecutable.description.clone(),
+            step_index: unexecutable.step_index,
+            affected_rows: None,
+        })
+        .collect();
+
+    let migration_script = applier.render_script(&migration, &destructive_change_diagnostics, false);
 
     // Write the migration script to a file.
     let directory = create_migration_directory(&Path::new(&input.migrations_directory_path), &input.migration_name)
@@ -88,5 +123,7 @@ pub async fn create_migration(
 
     Ok(CreateMigrationOutput {
         generated_migration_name: Some(directory.migration_name().to_owned()),
+        warnings,
+        unexecutable_steps,
     })
 }
@@ -0,0 +1,80 @@
+use crate::{parse_configuration, CoreResult};
+use serde::{Deserialize, Serialize};
+
+/// Input to the `dbExecute` command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbExecuteInput {
+    /// The location of the database to run `script` against.
+    pub datasource_type: DbExecuteDatasourceType,
+    /// The input script.
+    pub script: String,
+}
+
+/// The database `dbExecute` should run its script against.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum DbExecuteDatasourceType {
+    /// A connection string to the target database, e.g. a shadow database that isn't otherwise
+    /// known to the engine.
+    Url {
+        /// The connection string.
+        url: String,
+    },
+    /// A Prisma schema to take the datasource url from, i.e. the same database `migrate` itself
+    /// would target.
+    Schema {
+        /// The Prisma schema.
+        schema: String,
+    },
+}
+
+/// Output of the `dbExecute` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbExecuteOutput {
+    /// A naive, semicolon-based count of the statements in the script that was run. This is an
+    /// approximation for reporting purposes: the script itself is always sent to the database as
+    /// a single unit (see [`db_execute`]), so this number never affects what gets executed, only
+    /// what gets reported back to the caller.
+    pub statements_executed: u32,
+}
+
+/// Run a raw script against a database or shadow database, for seeding and escape-hatch
+/// workflows that would otherwise have to open their own connection outside the engine.
+///
+/// Unlike most other commands, this doesn't go through the connector the engine is already
+/// connected to: the target is resolved fresh from `input.datasource_type` on every call, since
+/// the whole point is to be able to point at a database unrelated to the engine's own connection.
+pub(crate) async fn db_execute(input: &DbExecuteInput) -> CoreResult<DbExecuteOutput> {
+    let url = match &input.datasource_type {
+        DbExecuteDatasourceType::Url { url } => url.clone(),
+        DbExecuteDatasourceType::Schema { schema } => {
+            let (_source, url, _shadow_database_url) = parse_configuration(schema)?;
+            url
+        }
+    };
+
+    run(&url, &input.script).await?;
+
+    Ok(DbExecuteOutput {
+        statements_executed: count_statements(&input.script),
+    })
+}
+
+fn count_statements(script: &str) -> u32 {
+    script
+        .split(';')
+        .filter(|statement| !statement.trim().is_empty())
+        .count() as u32
+}
+
+#[cfg(feature = "sql")]
+async fn run(url: &str, script: &str) -> CoreResult<()> {
+    Ok(sql_migration_connector::SqlMigrationConnector::db_execute(url, script).await?)
+}
+
+#[cfg(not(feature = "sql"))]
+async fn run(_url: &str, _script: &str) -> CoreResult<()> {
+    unimplemented!("dbExecute is only implemented for SQL connectors")
+}
@@ -35,6 +35,8 @@ pub struct MigrationFeedback {
     pub message: String,
     /// The index of the step this pertains to.
     pub step_index: usize,
+    /// The number of rows in the database that would be affected, if it could be determined.
+    pub affected_rows: Option<i64>,
 }
 
 /// Development command for migrations. Evaluate the data loss induced by the
@@ -69,6 +71,7 @@ pub(crate) async fn evaluate_data_loss(
         .map(|warning| MigrationFeedback {
             message: warning.description,
             step_index: warning.step_index,
+            affected_rows: warning.affected_rows,
         })
         .collect();
 
@@ -78,6 +81,7 @@ pub(crate) async fn evaluate_data_loss(
         .map(|unexecutable| MigrationFeedback {
             message: unexecutable.description,
             step_index: unexecutable.step_index,
+            affected_rows: None,
         })
         .collect();
 
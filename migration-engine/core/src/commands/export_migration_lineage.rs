@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The input to the `ExportMigrationLineage` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMigrationLineageInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// A single migration in an exported lineage.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LineageMigration {
+    /// The name of the migration directory.
+    pub migration_name: String,
+    /// The checksum of the migration script, in the same format as the `checksum` column of an
+    /// applied migration in `_prisma_migrations`.
+    pub checksum: String,
+}
+
+/// The output of the `ExportMigrationLineage` command.
+///
+/// This intentionally does not include a schema snapshot after each migration: producing one
+/// means replaying migrations against a shadow database (see
+/// `SqlFlavour::sql_schema_from_migration_history`), which is a per-connector, IO-heavy operation
+/// we already do once, for the final state, in `diagnose_migration_history`. Doing it once per
+/// migration in the lineage, on every export, is a much bigger and slower feature than exporting
+/// the lineage itself, and is left out here. A "verify a database against a lineage" command can
+/// be built as a client of this one, by fetching `_prisma_migrations` and comparing it against
+/// this output, without needing a new engine command for that either.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMigrationLineageOutput {
+    /// The migrations in the migrations directory, in application order.
+    pub migrations: Vec<LineageMigration>,
+}
@@ -0,0 +1,58 @@
+use super::mark_migration_applied::{mark_migration_applied, MarkMigrationAppliedInput};
+use crate::CoreResult;
+use migration_connector::MigrationConnector;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The input to the `markMigrationsApplied` command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkMigrationsAppliedInput {
+    /// The names of the migrations to mark applied, in the order they should be recorded in
+    /// `_prisma_migrations`.
+    pub migration_names: Vec<String>,
+    /// The path to the root of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `markMigrationsApplied` command.
+pub type MarkMigrationsAppliedOutput = HashMap<(), ()>;
+
+/// Baseline an existing database against a migrations history in one call, by marking a batch of
+/// migrations as applied without executing them. Migrations are recorded one by one, in the order
+/// given, going through the same `markMigrationApplied` logic (and so the same checksum
+/// calculation and already-applied/rolled-back handling) a client would otherwise have to call
+/// once per migration.
+pub(crate) async fn mark_migrations_applied(
+    input: &MarkMigrationsAppliedInput,
+    connector: &dyn MigrationConnector,
+) -> CoreResult<MarkMigrationsAppliedOutput> {
+    for migration_name in &input.migration_names {
+        mark_migration_applied(
+            &MarkMigrationAppliedInput {
+                migration_name: migration_name.clone(),
+                migrations_directory_path: input.migrations_directory_path.clone(),
+            },
+            connector,
+        )
+        .await?;
+    }
+
+    Ok(Default::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarkMigrationsAppliedOutput;
+    use std::collections::HashMap;
+
+    #[test]
+    fn mark_migrations_applied_output_serializes_as_expected() {
+        let output: MarkMigrationsAppliedOutput = HashMap::new();
+
+        let expected = serde_json::json!({});
+        let actual = serde_json::to_value(output).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{parse_schema, CoreResult};
+use migration_connector::{ConnectorError, DiffTarget, MigrationConnector};
+use serde::{Deserialize, Serialize};
+
+/// Input to the `previewMigrationScript` command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewMigrationScriptInput {
+    /// The prisma schema to migrate to.
+    pub prisma_schema: String,
+    /// What to diff `prisma_schema` against. Defaults to `database`, matching the previous,
+    /// single-purpose behavior of this command.
+    #[serde(default)]
+    pub from: PreviewMigrationScriptFrom,
+    /// Render idempotent guards (currently: around `DROP TABLE`) so the script can be re-run
+    /// against the same database without failing, for consumption by external orchestration
+    /// tools. Defaults to `false`, matching the non-idempotent scripts `createMigration` writes
+    /// to the migrations directory.
+    #[serde(default)]
+    pub idempotent: bool,
+}
+
+/// The state `prisma_schema` is diffed against to produce the migration script.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "tag", rename_all = "camelCase")]
+pub enum PreviewMigrationScriptFrom {
+    /// Diff against the current state of the database the engine is connected to. This is the
+    /// default, and the only option that existed before `from` was introduced.
+    Database,
+    /// Diff against an empty schema, i.e. render the full script that creates `prisma_schema`
+    /// from scratch. Doesn't touch the database at all: useful for docs tooling and for users
+    /// who want to bootstrap a database themselves, outside Prisma Migrate.
+    Empty,
+}
+
+impl Default for PreviewMigrationScriptFrom {
+    fn default() -> Self {
+        PreviewMigrationScriptFrom::Database
+    }
+}
+
+/// Output of the `previewMigrationScript` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewMigrationScriptOutput {
+    /// The SQL script for the migration that would be generated from the
+    /// state selected by `from` (the database or an empty schema) to the target schema.
+    pub migration_script: String,
+}
+
+/// Preview the SQL script for the migration that would be generated between `input.from` and the
+/// target schema, without touching the shadow database or writing anything to the migrations
+/// directory. Diffing against `PreviewMigrationScriptFrom::Database` goes directly against the
+/// database the engine is connected to, the same source `schemaPush` uses, rather than replaying
+/// the migrations directory history, since replaying migrations is what needs the shadow database.
+pub(crate) async fn preview_migration_script(
+    input: &PreviewMigrationScriptInput,
+    connector: &dyn MigrationConnector,
+) -> CoreResult<PreviewMigrationScriptOutput> {
+    let target_schema = parse_schema(&input.prisma_schema)?;
+
+    if let Some(err) = connector.check_database_version_compatibility(&target_schema.1) {
+        return Err(ConnectorError::user_facing(err));
+    };
+
+    let applier = connector.database_migration_step_applier();
+    let checker = connector.destructive_change_checker();
+
+    let from = match input.from {
+        PreviewMigrationScriptFrom::Database => DiffTarget::Database,
+        PreviewMigrationScriptFrom::Empty => DiffTarget::Empty,
+    };
+
+    let migration = connector
+        .diff(from, DiffTarget::Datamodel((&target_schema.0, &target_schema.1)))
+        .await?;
+
+    let diagnostics = checker.pure_check(&migration);
+    let migration_script = applier.render_script(&migration, &diagnostics, input.idempotent);
+
+    Ok(PreviewMigrationScriptOutput { migration_script })
+}
@@ -115,7 +115,7 @@ pub async fn drop_database(schema: &str) -> CoreResult<()> {
     }
 }
 
-fn parse_configuration(datamodel: &str) -> CoreResult<(Datasource, String, Option<String>)> {
+pub(crate) fn parse_configuration(datamodel: &str) -> CoreResult<(Datasource, String, Option<String>)> {
     let config = datamodel::parse_configuration(&datamodel)
         .map(|validated_config| validated_config.subject)
         .map_err(|err| CoreError::new_schema_parser_error(err.to_pretty_string("schema.prisma", datamodel)))?;
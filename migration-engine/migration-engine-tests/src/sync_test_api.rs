@@ -5,7 +5,7 @@ pub use test_setup::{BitFlags, Capabilities, Tags};
 use crate::{
     multi_engine_test_api::TestApi as RootTestApi, ApplyMigrations, CreateMigration, DevDiagnostic,
     DiagnoseMigrationHistory, EvaluateDataLoss, ListMigrationDirectories, MarkMigrationApplied,
-    MarkMigrationRolledBack, Reset, SchemaAssertion, SchemaPush,
+    MarkMigrationRolledBack, MarkMigrationsApplied, Reset, SchemaAssertion, SchemaPush,
 };
 use migration_connector::MigrationPersistence;
 use quaint::prelude::{ConnectionInfo, Queryable, ResultSet};
@@ -154,6 +154,14 @@ impl TestApi {
         MarkMigrationRolledBack::new(&self.connector, migration_name.into(), &self.root.rt)
     }
 
+    pub fn mark_migrations_applied<'a>(
+        &'a self,
+        migration_names: Vec<String>,
+        migrations_directory: &'a TempDir,
+    ) -> MarkMigrationsApplied<'a> {
+        MarkMigrationsApplied::new(&self.connector, migration_names, migrations_directory, &self.root.rt)
+    }
+
     pub fn migration_persistence<'a>(&'a self) -> &(dyn MigrationPersistence + 'a) {
         &self.connector
     }
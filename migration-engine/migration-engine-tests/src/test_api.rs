@@ -6,6 +6,7 @@ mod evaluate_data_loss;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
+mod mark_migrations_applied;
 mod reset;
 mod schema_push;
 
@@ -17,6 +18,7 @@ pub use evaluate_data_loss::EvaluateDataLoss;
 pub use list_migration_directories::ListMigrationDirectories;
 pub use mark_migration_applied::MarkMigrationApplied;
 pub use mark_migration_rolled_back::MarkMigrationRolledBack;
+pub use mark_migrations_applied::MarkMigrationsApplied;
 pub use reset::Reset;
 pub use schema_push::SchemaPush;
 
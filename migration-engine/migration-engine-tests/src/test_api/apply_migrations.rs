@@ -7,6 +7,7 @@ use tempfile::TempDir;
 pub struct ApplyMigrations<'a> {
     api: &'a dyn GenericApi,
     migrations_directory: &'a TempDir,
+    strict: bool,
     rt: Option<&'a tokio::runtime::Runtime>,
 }
 
@@ -15,6 +16,7 @@ impl<'a> ApplyMigrations<'a> {
         ApplyMigrations {
             api,
             migrations_directory,
+            strict: false,
             rt: None,
         }
     }
@@ -27,15 +29,22 @@ impl<'a> ApplyMigrations<'a> {
         ApplyMigrations {
             api,
             migrations_directory,
+            strict: false,
             rt: Some(rt),
         }
     }
 
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     pub async fn send(self) -> CoreResult<ApplyMigrationsAssertion<'a>> {
         let output = self
             .api
             .apply_migrations(&ApplyMigrationsInput {
                 migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                strict: self.strict,
             })
             .await?;
 
@@ -0,0 +1,63 @@
+use migration_core::{
+    commands::MarkMigrationsAppliedInput, commands::MarkMigrationsAppliedOutput, CoreError, CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See MarkMigrationsApplied::send()"]
+pub struct MarkMigrationsApplied<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    migration_names: Vec<String>,
+    rt: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarkMigrationsApplied<'a> {
+    pub(crate) fn new(
+        api: &'a dyn GenericApi,
+        migration_names: Vec<String>,
+        migrations_directory: &'a TempDir,
+        rt: &'a tokio::runtime::Runtime,
+    ) -> Self {
+        MarkMigrationsApplied {
+            api,
+            migrations_directory,
+            migration_names,
+            rt,
+        }
+    }
+
+    pub fn send_impl(self) -> CoreResult<MarkMigrationsAppliedAssertion<'a>> {
+        let output = self
+            .rt
+            .block_on(self.api.mark_migrations_applied(&MarkMigrationsAppliedInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                migration_names: self.migration_names,
+            }))?;
+
+        Ok(MarkMigrationsAppliedAssertion {
+            _output: output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+
+    pub fn send(self) -> MarkMigrationsAppliedAssertion<'a> {
+        self.send_impl().unwrap()
+    }
+
+    pub fn send_unwrap_err(self) -> CoreError {
+        self.send_impl().unwrap_err()
+    }
+}
+
+pub struct MarkMigrationsAppliedAssertion<'a> {
+    _output: MarkMigrationsAppliedOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for MarkMigrationsAppliedAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MarkMigrationsAppliedAssertion {{ .. }}")
+    }
+}
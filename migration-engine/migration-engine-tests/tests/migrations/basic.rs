@@ -1,5 +1,6 @@
 use migration_engine_tests::sync_test_api::*;
 use sql_schema_describer::{ColumnTypeFamily, DefaultValue};
+use user_facing_errors::{migration_engine::MigrationReferencesUnknownTable, UserFacingError};
 
 #[test_connector]
 fn adding_an_id_field_of_type_int_with_autoincrement_works(api: TestApi) -> TestResult {
@@ -61,6 +62,41 @@ fn a_model_can_be_removed(api: TestApi) {
     assert!(output.is_empty());
 }
 
+#[test_connector]
+fn strict_apply_migrations_rejects_a_migration_referencing_an_unknown_table(api: TestApi) {
+    let directory = api.create_migrations_directory();
+    let schema = api.datasource_block().to_string();
+
+    api.create_migration("01initial", &schema, &directory)
+        .draft(true)
+        .send_sync()
+        .modify_migration(|migration| {
+            migration.clear();
+            migration.push_str("CREATE TABLE \"Cat\" (\"id\" INTEGER PRIMARY KEY);\n");
+        });
+
+    // This looks like it was copied from a different migration history: it alters a table that
+    // no earlier migration in this directory created.
+    api.create_migration("02stray", &schema, &directory)
+        .draft(true)
+        .send_sync()
+        .modify_migration(|migration| {
+            migration.clear();
+            migration.push_str("ALTER TABLE \"Dog\" ADD COLUMN \"name\" TEXT;\n");
+        });
+
+    let error = api
+        .apply_migrations(&directory)
+        .strict(true)
+        .send_unwrap_err()
+        .to_user_facing();
+
+    assert_eq!(
+        error.as_known().unwrap().error_code,
+        MigrationReferencesUnknownTable::ERROR_CODE
+    );
+}
+
 #[test_connector]
 fn adding_a_scalar_field_must_work(api: TestApi) {
     let dm = format!(
@@ -309,3 +309,75 @@ fn changing_all_referenced_columns_of_foreign_key_works(api: TestApi) {
 
     api.schema_push(dm2).send_sync().assert_green_bang();
 }
+
+// Only Postgres can rename a foreign key constraint in place (see
+// `SqlSchemaDifferFlavour::can_rename_foreign_key`).
+#[test_connector(tags(Postgres))]
+fn resending_an_unchanged_relation_does_not_rename_its_foreign_key(api: TestApi) {
+    let dm = r#"
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+    "#;
+
+    api.schema_push(dm).send_sync().assert_green_bang();
+
+    // There is no `map:` argument on `@relation` in this Prisma version, so the constraint name
+    // calculated from the datamodel is always unset, and the one the database ends up with is
+    // whatever Postgres names it by default. Sending the exact same datamodel again must not
+    // mistake that database-assigned default for a name change and try to rename it.
+    api.schema_push(dm).send_sync().assert_green_bang().assert_no_steps();
+
+    api.assert_schema().assert_table("Post", |table| {
+        table
+            .assert_foreign_keys_count(1)
+            .assert_fk_on_columns(&["authorId"], |fk| fk.assert_references("User", &["id"]))
+    });
+}
+
+// Only Postgres can rename a foreign key constraint in place (see
+// `SqlSchemaDifferFlavour::can_rename_foreign_key`).
+#[test_connector(tags(Postgres))]
+fn ambiguous_identical_foreign_keys_are_not_renamed_or_dropped(api: TestApi) {
+    let dm = r#"
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+
+        model User {
+            id    Int    @id
+            posts Post[]
+        }
+    "#;
+
+    api.schema_push(dm).send_sync().assert_green_bang();
+
+    // Give `Post` a second foreign key on the exact same column, referencing the exact same
+    // table, under a different constraint name. The datamodel above still only describes one
+    // such relation, so the differ now sees two structurally identical foreign keys on the
+    // previous side matching a single one on the next side - too ambiguous to safely rename
+    // either of them (see `TableDiffer::foreign_key_pairs`'s `number_of_identical_fks` guard).
+    api.raw_cmd(&format!(
+        "ALTER TABLE \"{schema}\".\"Post\" ADD CONSTRAINT \"Post_authorId_extra_fkey\" FOREIGN KEY (\"authorId\") REFERENCES \"{schema}\".\"User\"(\"id\")",
+        schema = api.schema_name(),
+    ));
+
+    api.assert_schema()
+        .assert_table("Post", |table| table.assert_foreign_keys_count(2));
+
+    // Resending the same datamodel must not panic, drop, or otherwise touch either of the
+    // ambiguous foreign keys - it should just leave them as they are.
+    api.schema_push(dm).send_sync().assert_green_bang();
+
+    api.assert_schema()
+        .assert_table("Post", |table| table.assert_foreign_keys_count(2));
+}
@@ -48,3 +48,130 @@ mod bigint_cursor {
         Ok(())
     }
 }
+
+/// Cursoring on a compound `@@unique` should page over all of its columns, not just the first one.
+#[test_suite(schema(compound_unique_schema))]
+mod compound_unique_cursor {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn compound_unique_schema() -> String {
+        let schema = indoc! {"
+            model TestModel {
+                #id(id, Int, @id)
+                a Int
+                b Int
+                @@unique([a, b])
+            }
+        "};
+
+        schema.to_owned()
+    }
+
+    #[connector_test]
+    async fn cursor_on_compound_unique(runner: &Runner) -> TestResult<()> {
+        test_data(runner).await?;
+
+        insta::assert_snapshot!(
+            run_query!(runner, r#"
+              query {
+                findManyTestModel(cursor: { a_b: { a: 2, b: 2 } }, orderBy: [{ a: asc }, { b: asc }]) {
+                  id
+                  a
+                  b
+                }
+              }
+            "#),
+            @r###"{"data":{"findManyTestModel":[{"id":2,"a":2,"b":2},{"id":3,"a":2,"b":3},{"id":4,"a":3,"b":1}]}}"###
+        );
+
+        Ok(())
+    }
+
+    async fn test_data(runner: &Runner) -> TestResult<()> {
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 1, a: 1, b: 5 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 2, a: 2, b: 2 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 3, a: 2, b: 3 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        runner
+            .query(r#"mutation { createOneTestModel(data: { id: 4, a: 3, b: 1 }) { id }}"#)
+            .await?
+            .assert_success();
+
+        Ok(())
+    }
+}
+
+/// A user-provided `orderBy` isn't necessarily unique. Paging through duplicate values with a
+/// cursor must still visit every row exactly once, relying on the primary identifier that gets
+/// appended as an implicit tiebreaker (see `finalize_arguments`).
+#[test_suite(schema(non_unique_order_schema))]
+mod non_unique_order_by_cursor {
+    use indoc::indoc;
+    use query_engine_tests::{run_query_json, Runner, TestResult};
+
+    fn non_unique_order_schema() -> String {
+        let schema = indoc! {"
+            model TestModel {
+                #id(id, Int, @id)
+                value Int
+            }
+        "};
+
+        schema.to_owned()
+    }
+
+    #[connector_test]
+    async fn paging_over_duplicate_values_visits_every_row_once(runner: &Runner) -> TestResult<()> {
+        test_data(runner).await?;
+
+        let mut seen = vec![];
+        let mut cursor_id = 1;
+
+        // Two pages of 3, cursored off the last id of the previous page, walk all 6 rows even
+        // though `value` repeats within each page - without the pk tiebreaker, ties could come
+        // back in a different order on the second query and get skipped or repeated.
+        for skip in [0, 1] {
+            let result = run_query_json!(
+                runner,
+                format!(
+                    "query {{ findManyTestModel(cursor: {{ id: {} }}, skip: {}, take: 3, orderBy: {{ value: asc }}) {{ id }} }}",
+                    cursor_id, skip
+                )
+            );
+
+            let page = result["data"]["findManyTestModel"].as_array().unwrap().clone();
+            cursor_id = page.last().unwrap()["id"].as_i64().unwrap();
+            seen.extend(page.into_iter().map(|record| record["id"].as_i64().unwrap()));
+        }
+
+        assert_eq!(seen, vec![1, 2, 3, 4, 5, 6]);
+
+        Ok(())
+    }
+
+    async fn test_data(runner: &Runner) -> TestResult<()> {
+        for (id, value) in [(1, 1), (2, 1), (3, 1), (4, 2), (5, 2), (6, 2)] {
+            runner
+                .query(format!(
+                    "mutation {{ createOneTestModel(data: {{ id: {}, value: {} }}) {{ id }} }}",
+                    id, value
+                ))
+                .await?
+                .assert_success();
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,11 @@
+//! Test harness for running the query engine's connector conformance suite (see
+//! `query-engine-tests`) against any connector tagged in [`ConnectorTag`].
+//!
+//! Workspace-internal, not published: it depends on `query-core`, `migration-core` and
+//! `request-handlers` by path and reaches into their internals (e.g. `migration_core::qe_setup`
+//! below), none of which expose a stable public API to build a third-party-facing crate on. See
+//! `test-setup`'s crate doc for the same reasoning.
+
 mod config;
 mod connector_tag;
 mod datamodel_rendering;
@@ -33,6 +33,7 @@ impl RunnerInterface for DirectRunner {
             true,
             data_source.capabilities(),
             preview_features,
+            false,
         ));
 
         Ok(Self {
@@ -31,6 +31,10 @@ pub enum MongoError {
 
     #[error("{0}")]
     JsonError(#[from] serde_json::Error),
+
+    /// Raised when turning a `runCommandRaw` / `aggregateRaw` JSON argument into a BSON document.
+    #[error("{0}")]
+    BsonSerializationError(#[from] mongodb::bson::ser::Error),
 }
 
 // Error translation is WIP.
@@ -41,6 +45,9 @@ impl MongoError {
             MongoError::UnhandledError(reason) => ConnectorError::from_kind(ErrorKind::UnsupportedFeature(reason)),
             MongoError::UuidError(err) => ConnectorError::from_kind(ErrorKind::ConversionError(err.into())),
             MongoError::JsonError(err) => ConnectorError::from_kind(ErrorKind::ConversionError(err.into())),
+            MongoError::BsonSerializationError(err) => {
+                ConnectorError::from_kind(ErrorKind::ConversionError(err.into()))
+            }
 
             err @ MongoError::ConversionError { .. } => {
                 ConnectorError::from_kind(ErrorKind::ConversionError(err.into()))
@@ -48,86 +55,109 @@ impl MongoError {
 
             err @ MongoError::MalformedObjectId(_) => ConnectorError::from_kind(ErrorKind::ConversionError(err.into())),
 
-            MongoError::DriverError(err) => match err.kind.as_ref() {
-                mongodb::error::ErrorKind::InvalidArgument { .. } => {
-                    ConnectorError::from_kind(ErrorKind::QueryError(Box::new(err.clone())))
-                }
-                mongodb::error::ErrorKind::Authentication { message, .. } => {
-                    // Todo this mapping is only half correct.
-                    ConnectorError::from_kind(ErrorKind::AuthenticationFailed { user: message.clone() })
+            MongoError::DriverError(err) => {
+                if err.labels().contains("TransientTransactionError")
+                    || err.labels().contains("UnknownTransactionCommitResult")
+                {
+                    return ConnectorError::from_kind(ErrorKind::RawError {
+                        code: "TransactionError".to_owned(),
+                        message: format!("The transaction could not be committed and should be retried: {}", err),
+                    });
                 }
 
-                mongodb::error::ErrorKind::Write(write_failure) => match write_failure {
-                    mongodb::error::WriteFailure::WriteConcernError(concern_error) => match concern_error.code {
-                        11000 => ConnectorError::from_kind(unique_violation_error(concern_error.message.as_str())),
-                        code => ConnectorError::from_kind(ErrorKind::RawError {
-                            code: code.to_string(),
-                            message: concern_error.message.clone(),
-                        }),
+                match err.kind.as_ref() {
+                    mongodb::error::ErrorKind::InvalidArgument { .. } => {
+                        ConnectorError::from_kind(ErrorKind::QueryError(Box::new(err.clone())))
+                    }
+                    mongodb::error::ErrorKind::Authentication { message, .. } => {
+                        // Todo this mapping is only half correct.
+                        ConnectorError::from_kind(ErrorKind::AuthenticationFailed { user: message.clone() })
+                    }
+
+                    mongodb::error::ErrorKind::Write(write_failure) => match write_failure {
+                        mongodb::error::WriteFailure::WriteConcernError(concern_error) => ConnectorError::from_kind(
+                            known_server_error(concern_error.code, concern_error.message.as_str()),
+                        ),
+
+                        mongodb::error::WriteFailure::WriteError(write_error) => ConnectorError::from_kind(
+                            known_server_error(write_error.code, write_error.message.as_str()),
+                        ),
+
+                        _ => ConnectorError::from_kind(ErrorKind::QueryError(Box::new(err.clone()))),
                     },
 
-                    mongodb::error::WriteFailure::WriteError(write_error) => match write_error.code {
-                        11000 => ConnectorError::from_kind(unique_violation_error(write_error.message.as_str())),
-                        code => ConnectorError::from_kind(ErrorKind::RawError {
-                            code: code.to_string(),
-                            message: write_error.message.clone(),
-                        }),
-                    },
+                    mongodb::error::ErrorKind::BulkWrite(err) => {
+                        let mut errors = match err.write_errors {
+                            Some(ref errors) => errors
+                                .iter()
+                                .map(|err| match err.code {
+                                    11000 | 13 | 50 => known_server_error(err.code, err.message.as_str()),
+                                    code => ErrorKind::RawError {
+                                        code: code.to_string(),
+                                        message: format!(
+                                            "Bulk write error on write index '{}': {}",
+                                            err.index, err.message
+                                        ),
+                                    },
+                                })
+                                .collect_vec(),
+
+                            None => vec![],
+                        };
 
-                    _ => ConnectorError::from_kind(ErrorKind::QueryError(Box::new(err.clone()))),
-                },
-
-                mongodb::error::ErrorKind::BulkWrite(err) => {
-                    let mut errors = match err.write_errors {
-                        Some(ref errors) => errors
-                            .iter()
-                            .map(|err| match err.code {
-                                11000 => unique_violation_error(err.message.as_str()),
-                                _ => ErrorKind::RawError {
-                                    code: err.code.to_string(),
-                                    message: format!(
-                                        "Bulk write error on write index '{}': {}",
-                                        err.index, err.message
-                                    ),
+                        if let Some(ref err) = err.write_concern_error {
+                            let kind = match err.code {
+                                11000 | 13 | 50 => known_server_error(err.code, err.message.as_str()),
+                                code => ErrorKind::RawError {
+                                    code: code.to_string(),
+                                    message: format!("Bulk write concern error: {}", err.message),
                                 },
-                            })
-                            .collect_vec(),
-
-                        None => vec![],
-                    };
-
-                    if let Some(ref err) = err.write_concern_error {
-                        let kind = match err.code {
-                            11000 => unique_violation_error(err.message.as_str()),
-                            _ => ErrorKind::RawError {
-                                code: err.code.to_string(),
-                                message: format!("Bulk write concern error: {}", err.message),
-                            },
-                        };
+                            };
 
-                        errors.push(kind);
-                    };
+                            errors.push(kind);
+                        };
 
-                    ConnectorError::from_kind(ErrorKind::MultiError(MultiError { errors }))
-                }
+                        ConnectorError::from_kind(ErrorKind::MultiError(MultiError { errors }))
+                    }
 
-                mongodb::error::ErrorKind::BsonDeserialization(err) => ConnectorError::from_kind(
-                    ErrorKind::InternalConversionError(format!("BSON decode error: {}", err)),
-                ),
+                    mongodb::error::ErrorKind::BsonDeserialization(err) => ConnectorError::from_kind(
+                        ErrorKind::InternalConversionError(format!("BSON decode error: {}", err)),
+                    ),
 
-                mongodb::error::ErrorKind::BsonSerialization(err) => ConnectorError::from_kind(
-                    ErrorKind::InternalConversionError(format!("BSON encode error: {}", err)),
-                ),
+                    mongodb::error::ErrorKind::BsonSerialization(err) => ConnectorError::from_kind(
+                        ErrorKind::InternalConversionError(format!("BSON encode error: {}", err)),
+                    ),
 
-                _ => ConnectorError::from_kind(ErrorKind::RawError {
-                    code: "unknown".to_owned(),
-                    message: format!("{}", err),
-                }),
-            },
+                    _ => ConnectorError::from_kind(ErrorKind::RawError {
+                        code: "unknown".to_owned(),
+                        message: format!("{}", err),
+                    }),
+                }
+            }
         }
     }
 }
 
+/// Maps a MongoDB server error code to Prisma's known error catalog, falling
+/// back to a `RawError` carrying the original code and message for anything
+/// we don't have a dedicated mapping for yet.
+fn known_server_error(code: i32, message: &str) -> ErrorKind {
+    match code {
+        11000 => unique_violation_error(message),
+        13 => ErrorKind::AuthenticationFailed {
+            user: message.to_owned(),
+        },
+        50 => ErrorKind::RawError {
+            code: code.to_string(),
+            message: format!("The operation exceeded its time limit: {}", message),
+        },
+        code => ErrorKind::RawError {
+            code: code.to_string(),
+            message: message.to_owned(),
+        },
+    }
+}
+
 fn unique_violation_error(message: &str) -> ErrorKind {
     ErrorKind::UniqueConstraintViolation {
         constraint: match parse_unique_index_violation(message) {
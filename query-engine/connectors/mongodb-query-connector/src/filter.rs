@@ -53,6 +53,10 @@ pub(crate) fn convert_filter(filter: Filter, invert: bool) -> crate::Result<Mong
         Filter::Relation(rfilter) => relation_filter(rfilter, invert)?,
         // Filter::BoolFilter(b) => {} // Potentially not doable.
         Filter::Aggregation(filter) => aggregation_filter(filter, invert)?,
+        // There is no `Filter::Composite` variant (and no object-shaped `PrismaValue`) yet, so
+        // `some`/`every`/`none` filtering over embedded arrays of composite types can't be wired up
+        // on the query-core side. See `quantifier_filter` below for the Mongo-side operator mapping
+        // this would reuse once composite types exist in the datamodel.
         _ => todo!("Incomplete filter implementation."),
     };
 
@@ -106,9 +110,20 @@ fn scalar_filter(filter: ScalarFilter, invert: bool, include_field_wrapper: bool
         connector_interface::ScalarProjection::Compound(_) => unimplemented!("Compound filter case."),
     };
 
+    let condition = filter.condition.invert(invert);
+
+    // `$text` is a whole-document operator backed by a text index, not something that can be
+    // scoped to a single field like the other filters below, so it bypasses the field wrapper
+    // entirely instead of being nested under `field.db_name()`.
+    if let ScalarCondition::Search(val) = condition {
+        return Ok(MongoFilter::Scalar(
+            doc! { "$text": { "$search": (&field, val).into_bson()? } },
+        ));
+    }
+
     let filter = match filter.mode {
-        QueryMode::Default => default_scalar_filter(&field, filter.condition.invert(invert))?,
-        QueryMode::Insensitive => insensitive_scalar_filter(&field, filter.condition.invert(invert))?,
+        QueryMode::Default => default_scalar_filter(&field, condition)?,
+        QueryMode::Insensitive => insensitive_scalar_filter(&field, condition)?,
     };
 
     if include_field_wrapper {
@@ -169,6 +184,7 @@ fn default_scalar_filter(field: &ScalarFieldRef, condition: ScalarCondition) ->
             }
             _ => unimplemented!("Only equality JSON filtering is supported on MongoDB."),
         },
+        ScalarCondition::Search(_) => unreachable!("Search filters are handled in `scalar_filter` directly"),
     })
 }
 
@@ -212,6 +228,7 @@ fn insensitive_scalar_filter(field: &ScalarFieldRef, condition: ScalarCondition)
             doc! { "$nin": to_regex_list(field, "^", vals, "$", true)? }
         }
         ScalarCondition::JsonCompare(_) => unimplemented!("JSON filtering is not yet supported on MongoDB"),
+        ScalarCondition::Search(_) => unreachable!("Search filters are handled in `scalar_filter` directly"),
     })
 }
 
@@ -311,7 +328,31 @@ fn relation_filter(filter: RelationFilter, invert: bool) -> crate::Result<MongoF
     let mut join_stage = JoinStage::new(from_field);
     join_stage.extend_nested(nested_joins);
 
-    let filter_doc = match filter.condition {
+    let filter_doc = quantifier_filter(filter.condition, nested_filter, is_empty);
+
+    if invert {
+        Ok(MongoFilter::relation(
+            doc! { relation_name: { "$not": filter_doc }},
+            vec![join_stage],
+        ))
+    } else {
+        Ok(MongoFilter::relation(
+            doc! { relation_name: filter_doc },
+            vec![join_stage],
+        ))
+    }
+}
+
+/// Renders a `some` / `every` / `none` (/ `is`) quantifier over an array of embedded documents
+/// down to the `$elemMatch` / `$all` / negation combination that expresses it in MongoDB.
+///
+/// This is shared by [`relation_filter`], which quantifies over the array of documents a join
+/// produces for a to-many relation. Embedded arrays of composite types would need exactly the same
+/// quantifier semantics without a join stage, but this codebase doesn't have a composite type
+/// representation yet (no `Filter::Composite`, no object-shaped `PrismaValue`), so wiring that up on
+/// top of this helper is left for when that datamodel support lands.
+fn quantifier_filter(condition: RelationCondition, nested_filter: Document, is_empty: bool) -> Document {
+    match condition {
         connector_interface::RelationCondition::EveryRelatedRecord => {
             if is_empty {
                 doc! { "$not": { "$all": [{ "$elemMatch": { "_id": { "$exists": 0 }} }] }}
@@ -332,18 +373,6 @@ fn relation_filter(filter: RelationFilter, invert: bool) -> crate::Result<MongoF
         connector_interface::RelationCondition::ToOneRelatedRecord => {
             doc! { "$all": [{ "$elemMatch": nested_filter }]}
         }
-    };
-
-    if invert {
-        Ok(MongoFilter::relation(
-            doc! { relation_name: { "$not": filter_doc }},
-            vec![join_stage],
-        ))
-    } else {
-        Ok(MongoFilter::relation(
-            doc! { relation_name: filter_doc },
-            vec![join_stage],
-        ))
     }
 }
 
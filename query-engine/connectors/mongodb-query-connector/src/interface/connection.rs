@@ -1,7 +1,7 @@
 use crate::{
     error::MongoError,
     root_queries::{aggregate, read, write},
-    MongoDbTransaction,
+    ExecutorOptions, MongoDbTransaction,
 };
 use async_trait::async_trait;
 use connector_interface::{
@@ -14,6 +14,9 @@ use std::future::Future;
 pub struct MongoDbConnection {
     /// Handle to a mongo database.
     pub(crate) database: Database,
+
+    /// `allowDiskUse` / `batchSize`, read off the connection string. See `ExecutorOptions`.
+    pub(crate) executor_options: ExecutorOptions,
 }
 
 #[async_trait]
@@ -21,8 +24,19 @@ impl Connection for MongoDbConnection {
     async fn start_transaction<'a>(
         &'a self,
     ) -> connector_interface::Result<Box<dyn connector_interface::Transaction + 'a>> {
-        self.catch(async move { Ok(Box::new(MongoDbTransaction::new(self.database.clone())) as Box<dyn Transaction>) })
-            .await
+        self.catch(async move {
+            // Requires the target deployment to be a replica set or a sharded cluster backed by
+            // one - MongoDB standalone servers don't support multi-document transactions.
+            let mut session = self.database.client().start_session(None).await?;
+            session.start_transaction(None).await?;
+
+            Ok(Box::new(MongoDbTransaction::new(
+                self.database.clone(),
+                session,
+                self.executor_options,
+            )) as Box<dyn Transaction>)
+        })
+        .await
     }
 }
 
@@ -41,7 +55,7 @@ impl MongoDbConnection {
 #[async_trait]
 impl WriteOperations for MongoDbConnection {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector_interface::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.database, model, args).await })
+        self.catch(async move { write::create_record(&self.database, model, args, None).await })
             .await
     }
 
@@ -51,7 +65,7 @@ impl WriteOperations for MongoDbConnection {
         args: Vec<WriteArgs>,
         skip_duplicates: bool,
     ) -> connector_interface::Result<usize> {
-        self.catch(async move { write::create_records(&self.database, model, args, skip_duplicates).await })
+        self.catch(async move { write::create_records(&self.database, model, args, skip_duplicates, None).await })
             .await
     }
 
@@ -61,7 +75,7 @@ impl WriteOperations for MongoDbConnection {
         record_filter: connector_interface::RecordFilter,
         args: WriteArgs,
     ) -> connector_interface::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.database, model, record_filter, args).await })
+        self.catch(async move { write::update_records(&self.database, model, record_filter, args, None).await })
             .await
     }
 
@@ -70,7 +84,7 @@ impl WriteOperations for MongoDbConnection {
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
     ) -> connector_interface::Result<usize> {
-        self.catch(async move { write::delete_records(&self.database, model, record_filter).await })
+        self.catch(async move { write::delete_records(&self.database, model, record_filter, None).await })
             .await
     }
 
@@ -80,7 +94,7 @@ impl WriteOperations for MongoDbConnection {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector_interface::Result<()> {
-        self.catch(async move { write::m2m_connect(&self.database, field, parent_id, child_ids).await })
+        self.catch(async move { write::m2m_connect(&self.database, field, parent_id, child_ids, None).await })
             .await
     }
 
@@ -90,7 +104,7 @@ impl WriteOperations for MongoDbConnection {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector_interface::Result<()> {
-        self.catch(async move { write::m2m_disconnect(&self.database, field, parent_id, child_ids).await })
+        self.catch(async move { write::m2m_disconnect(&self.database, field, parent_id, child_ids, None).await })
             .await
     }
 
@@ -109,6 +123,20 @@ impl WriteOperations for MongoDbConnection {
     ) -> connector_interface::Result<serde_json::Value> {
         Err(MongoError::Unsupported("Raw queries".to_owned()).into_connector_error())
     }
+
+    async fn run_command_raw(&self, command: String) -> connector_interface::Result<serde_json::Value> {
+        self.catch(async move { write::run_command_raw(&self.database, command).await })
+            .await
+    }
+
+    async fn aggregate_raw(
+        &self,
+        pipeline: Vec<String>,
+        options: Option<String>,
+    ) -> connector_interface::Result<serde_json::Value> {
+        self.catch(async move { write::aggregate_raw(&self.database, pipeline, options).await })
+            .await
+    }
 }
 
 #[async_trait]
@@ -121,7 +149,7 @@ impl ReadOperations for MongoDbConnection {
         aggr_selections: &[RelAggregationSelection],
     ) -> connector_interface::Result<Option<SingleRecord>> {
         self.catch(async move {
-            read::get_single_record(&self.database, model, filter, selected_fields, aggr_selections).await
+            read::get_single_record(&self.database, model, filter, selected_fields, aggr_selections, None).await
         })
         .await
     }
@@ -140,6 +168,8 @@ impl ReadOperations for MongoDbConnection {
                 query_arguments,
                 selected_fields,
                 aggregation_selections,
+                self.executor_options,
+                None,
             )
             .await
         })
@@ -151,8 +181,10 @@ impl ReadOperations for MongoDbConnection {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector_interface::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.database, from_field, from_record_ids).await })
-            .await
+        self.catch(
+            async move { read::get_related_m2m_record_ids(&self.database, from_field, from_record_ids, None).await },
+        )
+        .await
     }
 
     async fn aggregate_records(
@@ -164,7 +196,17 @@ impl ReadOperations for MongoDbConnection {
         having: Option<connector_interface::Filter>,
     ) -> connector_interface::Result<Vec<connector_interface::AggregationRow>> {
         self.catch(async move {
-            aggregate::aggregate(&self.database, model, query_arguments, selections, group_by, having).await
+            aggregate::aggregate(
+                &self.database,
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+                self.executor_options,
+                None,
+            )
+            .await
         })
         .await
     }
@@ -4,6 +4,7 @@ mod transaction;
 pub use connection::*;
 pub use transaction::*;
 
+use crate::ExecutorOptions;
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
@@ -21,9 +22,26 @@ pub struct MongoDb {
 
     /// The database used for all connections.
     database: String,
+
+    /// `allowDiskUse` / `batchSize` read off the connection string. See `ExecutorOptions`.
+    executor_options: ExecutorOptions,
 }
 
 impl MongoDb {
+    /// `readPreference`, `readConcernLevel` and `w` (write concern) are not modeled as
+    /// structured options here: the underlying `mongodb` driver already reads all three
+    /// straight off the connection string (e.g.
+    /// `mongodb://.../mydb?readPreference=secondaryPreferred&readConcernLevel=majority&w=majority`)
+    /// when we hand it to `ClientOptions::parse` below, so datasource-level configuration
+    /// works today without any code here. Overriding them per request would need a new
+    /// field threaded through `QueryArguments` and every `ReadOperations`/`WriteOperations`
+    /// call, which are shared with the SQL connectors and not something to grow for one
+    /// connector's use case; there's no client-facing option for it to carry today either.
+    ///
+    /// `allowDiskUse` and `batchSize`, on the other hand, are not connection-string options the
+    /// driver understands - they're per-query options on `find`/`aggregate` - so those two are
+    /// read off the URL here instead and threaded down to the `get_many_records` and
+    /// `aggregate_records` queries. See `ExecutorOptions`.
     pub async fn new(_source: &Datasource, url: &str) -> connector_interface::Result<Self> {
         let database_str = url;
         let url = Url::parse(database_str).map_err(|_err| {
@@ -34,6 +52,8 @@ impl MongoDb {
         })?;
 
         let database = url.path().trim_start_matches('/').to_string();
+        let executor_options = executor_options_from_url(&url);
+
         let client_options = ClientOptions::parse(database_str).await.map_err(|_err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
                 details: "Invalid MongoDB connection string".to_owned(),
@@ -44,7 +64,11 @@ impl MongoDb {
         let client = Client::with_options(client_options)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        Ok(Self { client, database })
+        Ok(Self {
+            client,
+            database,
+            executor_options,
+        })
     }
 
     pub fn db_name(&self) -> &str {
@@ -52,6 +76,31 @@ impl MongoDb {
     }
 }
 
+/// Reads `allowDiskUse` and `batchSize` off the connection string's query parameters. Both are
+/// optional; an unset or unparsable `allowDiskUse` falls back to the previous hardcoded `true`,
+/// and an unset or unparsable `batchSize` leaves the driver's own default in place.
+fn executor_options_from_url(url: &Url) -> ExecutorOptions {
+    let mut options = ExecutorOptions::default();
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "allowDiskUse" => {
+                if let Ok(allow_disk_use) = value.parse() {
+                    options.allow_disk_use = allow_disk_use;
+                }
+            }
+            "batchSize" => {
+                if let Ok(batch_size) = value.parse() {
+                    options.batch_size = Some(batch_size);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    options
+}
+
 #[async_trait]
 impl Connector for MongoDb {
     async fn get_connection(
@@ -59,6 +108,7 @@ impl Connector for MongoDb {
     ) -> connector_interface::Result<Box<dyn connector_interface::Connection + Send + Sync>> {
         Ok(Box::new(MongoDbConnection {
             database: self.client.database(&self.database),
+            executor_options: self.executor_options,
         }))
     }
 
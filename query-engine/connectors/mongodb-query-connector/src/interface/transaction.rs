@@ -2,21 +2,43 @@ use super::*;
 use crate::{
     error::MongoError,
     root_queries::{aggregate, read, write},
+    ExecutorOptions,
 };
 use connector_interface::{ReadOperations, RelAggregationSelection, Transaction, WriteOperations};
 use futures::Future;
-use mongodb::Database;
+use mongodb::{ClientSession, Database};
+use tokio::sync::Mutex;
 
-/// Not really a transaction right now, just something to
-/// satisfy the core interface until we figure something out.
+/// A real multi-document transaction, backed by a MongoDB session that's already had
+/// `start_transaction` called on it (see `MongoDbConnection::start_transaction`). Every write
+/// issued through this handle runs inside that session, so nested write graphs - a create
+/// followed by a bunch of connects, say - either all land or all get rolled back together.
+/// `run_command_raw` and `aggregate_raw` are the exception: they are not sent on that session,
+/// so they run outside the transaction.
+///
+/// The session is behind a `Mutex` rather than needing `&mut self` because `WriteOperations`
+/// hands out `&self`; only one write is ever in flight against a given transaction at a time; so
+/// the lock is never contended.
 pub struct MongoDbTransaction {
     /// Handle to a mongo database.
     pub(crate) database: Database,
+
+    /// The session this transaction runs on. Every `root_queries::write` call made through this
+    /// `MongoDbTransaction` is threaded a lock on this session, except `run_command_raw` and
+    /// `aggregate_raw`.
+    session: Mutex<ClientSession>,
+
+    /// `allowDiskUse` / `batchSize`, read off the connection string. See `ExecutorOptions`.
+    pub(crate) executor_options: ExecutorOptions,
 }
 
 impl MongoDbTransaction {
-    pub(crate) fn new(database: Database) -> Self {
-        Self { database }
+    pub(crate) fn new(database: Database, session: ClientSession, executor_options: ExecutorOptions) -> Self {
+        Self {
+            database,
+            session: Mutex::new(session),
+            executor_options,
+        }
     }
 
     async fn catch<O>(
@@ -33,13 +55,19 @@ impl MongoDbTransaction {
 #[async_trait]
 impl Transaction for MongoDbTransaction {
     async fn commit(&self) -> connector_interface::Result<()> {
-        // Totally committed.
-        Ok(())
+        self.catch(async move {
+            self.session.lock().await.commit_transaction().await?;
+            Ok(())
+        })
+        .await
     }
 
     async fn rollback(&self) -> connector_interface::Result<()> {
-        // Totally rolled back.
-        Ok(())
+        self.catch(async move {
+            self.session.lock().await.abort_transaction().await?;
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -50,8 +78,11 @@ impl WriteOperations for MongoDbTransaction {
         model: &ModelRef,
         args: connector_interface::WriteArgs,
     ) -> connector_interface::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.database, model, args).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::create_record(&self.database, model, args, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn create_records(
@@ -60,8 +91,11 @@ impl WriteOperations for MongoDbTransaction {
         args: Vec<connector_interface::WriteArgs>,
         skip_duplicates: bool,
     ) -> connector_interface::Result<usize> {
-        self.catch(async move { write::create_records(&self.database, model, args, skip_duplicates).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::create_records(&self.database, model, args, skip_duplicates, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn update_records(
@@ -70,8 +104,11 @@ impl WriteOperations for MongoDbTransaction {
         record_filter: connector_interface::RecordFilter,
         args: connector_interface::WriteArgs,
     ) -> connector_interface::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.database, model, record_filter, args).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::update_records(&self.database, model, record_filter, args, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn delete_records(
@@ -79,8 +116,11 @@ impl WriteOperations for MongoDbTransaction {
         model: &ModelRef,
         record_filter: connector_interface::RecordFilter,
     ) -> connector_interface::Result<usize> {
-        self.catch(async move { write::delete_records(&self.database, model, record_filter).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::delete_records(&self.database, model, record_filter, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn m2m_connect(
@@ -89,8 +129,11 @@ impl WriteOperations for MongoDbTransaction {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector_interface::Result<()> {
-        self.catch(async move { write::m2m_connect(&self.database, field, parent_id, child_ids).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::m2m_connect(&self.database, field, parent_id, child_ids, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn m2m_disconnect(
@@ -99,8 +142,11 @@ impl WriteOperations for MongoDbTransaction {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector_interface::Result<()> {
-        self.catch(async move { write::m2m_disconnect(&self.database, field, parent_id, child_ids).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            write::m2m_disconnect(&self.database, field, parent_id, child_ids, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn execute_raw(
@@ -118,6 +164,20 @@ impl WriteOperations for MongoDbTransaction {
     ) -> connector_interface::Result<serde_json::Value> {
         Err(MongoError::Unsupported("Raw queries".to_owned()).into_connector_error())
     }
+
+    async fn run_command_raw(&self, command: String) -> connector_interface::Result<serde_json::Value> {
+        self.catch(async move { write::run_command_raw(&self.database, command).await })
+            .await
+    }
+
+    async fn aggregate_raw(
+        &self,
+        pipeline: Vec<String>,
+        options: Option<String>,
+    ) -> connector_interface::Result<serde_json::Value> {
+        self.catch(async move { write::aggregate_raw(&self.database, pipeline, options).await })
+            .await
+    }
 }
 
 #[async_trait]
@@ -130,7 +190,16 @@ impl ReadOperations for MongoDbTransaction {
         aggr_selections: &[RelAggregationSelection],
     ) -> connector_interface::Result<Option<SingleRecord>> {
         self.catch(async move {
-            read::get_single_record(&self.database, model, filter, selected_fields, aggr_selections).await
+            let mut session = self.session.lock().await;
+            read::get_single_record(
+                &self.database,
+                model,
+                filter,
+                selected_fields,
+                aggr_selections,
+                Some(&mut *session),
+            )
+            .await
         })
         .await
     }
@@ -143,12 +212,15 @@ impl ReadOperations for MongoDbTransaction {
         aggregation_selections: &[RelAggregationSelection],
     ) -> connector_interface::Result<ManyRecords> {
         self.catch(async move {
+            let mut session = self.session.lock().await;
             read::get_many_records(
                 &self.database,
                 model,
                 query_arguments,
                 selected_fields,
                 aggregation_selections,
+                self.executor_options,
+                Some(&mut *session),
             )
             .await
         })
@@ -160,8 +232,11 @@ impl ReadOperations for MongoDbTransaction {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector_interface::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.database, from_field, from_record_ids).await })
-            .await
+        self.catch(async move {
+            let mut session = self.session.lock().await;
+            read::get_related_m2m_record_ids(&self.database, from_field, from_record_ids, Some(&mut *session)).await
+        })
+        .await
     }
 
     async fn aggregate_records(
@@ -173,7 +248,18 @@ impl ReadOperations for MongoDbTransaction {
         having: Option<connector_interface::Filter>,
     ) -> connector_interface::Result<Vec<connector_interface::AggregationRow>> {
         self.catch(async move {
-            aggregate::aggregate(&self.database, model, query_arguments, selections, group_by, having).await
+            let mut session = self.session.lock().await;
+            aggregate::aggregate(
+                &self.database,
+                model,
+                query_arguments,
+                selections,
+                group_by,
+                having,
+                self.executor_options,
+                Some(&mut *session),
+            )
+            .await
         })
         .await
     }
@@ -16,13 +16,37 @@ use error::MongoError;
 use futures::stream::StreamExt;
 use mongodb::{
     bson::{Bson, Document},
-    Cursor,
+    ClientSession, Cursor, SessionCursor,
 };
 
 pub use interface::*;
 
 type Result<T> = std::result::Result<T, MongoError>;
 
+/// Query execution knobs read off the datasource connection string (`allowDiskUse`, `batchSize`)
+/// and threaded down to the `find`/`aggregate` queries built by `MongoReadQueryBuilder`
+/// (`get_many_records` and `aggregate_records`). Other lookups, such as `get_single_record`, run
+/// with the driver's defaults.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ExecutorOptions {
+    /// Whether aggregation pipeline stages may spill to disk when a stage exceeds the 100MB
+    /// in-memory limit. Defaults to `true`, matching the behavior before this was configurable.
+    pub(crate) allow_disk_use: bool,
+
+    /// Cursor batch size for `find` and `aggregate` queries. Left to the driver's own default
+    /// (currently 101 documents for the first batch) when unset.
+    pub(crate) batch_size: Option<u32>,
+}
+
+impl Default for ExecutorOptions {
+    fn default() -> Self {
+        Self {
+            allow_disk_use: true,
+            batch_size: None,
+        }
+    }
+}
+
 trait IntoBson {
     fn into_bson(self) -> Result<Bson>;
 }
@@ -46,6 +70,16 @@ impl BsonTransform for Bson {
 
 // Todo: Move to approriate place
 /// Consumes a cursor stream until exhausted.
+///
+/// This still collects the whole cursor into a `Vec` rather than streaming batches through to
+/// the caller. Doing that for real would mean `ReadOperations::get_many_records`/
+/// `aggregate_records` returning something streamable instead of `ManyRecords`/`Vec<AggregationRow>`,
+/// which is `connector_interface`'s shared contract with the SQL connectors and is relied on by
+/// `query-core`'s in-memory pagination, ordering and distinct processing on the result set as a
+/// whole - not something this connector can change on its own. `batch_size` (see
+/// `ExecutorOptions`) at least controls how many documents the driver pulls from the server per
+/// round trip while this loop drains the cursor, which is the piece that's actually reachable
+/// from here.
 async fn vacuum_cursor(mut cursor: Cursor<Document>) -> crate::Result<Vec<Document>> {
     let mut docs = vec![];
 
@@ -58,3 +92,25 @@ async fn vacuum_cursor(mut cursor: Cursor<Document>) -> crate::Result<Vec<Docume
 
     Ok(docs)
 }
+
+/// Same as `vacuum_cursor`, but for a `find_with_session`/`aggregate_with_session` cursor. Unlike
+/// the plain `Cursor`, a `SessionCursor` doesn't implement `Stream` - it isn't allowed to hold on
+/// to the session across `.await` points, so every batch fetch takes it by the caller's borrow
+/// instead. That's what makes it possible to read inside a `MongoDbTransaction`: results come back
+/// on the same session the writes went through, so they see the transaction's own uncommitted
+/// writes instead of racing them under snapshot isolation.
+async fn vacuum_session_cursor(
+    mut cursor: SessionCursor<Document>,
+    session: &mut ClientSession,
+) -> crate::Result<Vec<Document>> {
+    let mut docs = vec![];
+
+    while let Some(result) = cursor.next(session).await {
+        match result {
+            Ok(document) => docs.push(document),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(docs)
+}
@@ -1,7 +1,7 @@
 use crate::join::JoinStage;
 use itertools::Itertools;
 use mongodb::bson::Document;
-use prisma_models::{OrderBy, SortOrder};
+use prisma_models::{OrderBy, SortAggregation, SortOrder};
 
 #[derive(Debug)]
 pub(crate) struct OrderByData {
@@ -159,10 +159,15 @@ impl OrderByBuilder {
 
         for data in self.order_bys.into_iter() {
             let field = if is_group_by {
-                // Explanation: All group by fields go into the _id key of the result document.
-                // As it is the only point where the flat scalars are contained for the group,
-                // we beed to refer to the object
-                format!("_id.{}", data.scalar_field_name())
+                match data.order_by.sort_aggregation {
+                    // Aggregations end up as top-level fields on the group document (e.g.
+                    // `count_id`, `avg_float`), computed by `with_groupings`, not nested under `_id`.
+                    Some(aggregation) => format!("{}_{}", aggregation_prefix(aggregation), data.scalar_field_name()),
+                    // Explanation: All group by fields go into the _id key of the result document.
+                    // As it is the only point where the flat scalars are contained for the group,
+                    // we need to refer to the object
+                    None => format!("_id.{}", data.scalar_field_name()),
+                }
             } else {
                 data.full_reference_path(false)
             };
@@ -181,3 +186,15 @@ impl OrderByBuilder {
         (Some(order_doc), joins)
     }
 }
+
+/// The prefix used for the `$group` stage field computing this aggregation, mirroring the naming
+/// scheme in `MongoReadQueryBuilder::with_groupings` (`count_field`, `avg_field`, etc.).
+fn aggregation_prefix(aggregation: SortAggregation) -> &'static str {
+    match aggregation {
+        SortAggregation::Count => "count",
+        SortAggregation::Avg => "avg",
+        SortAggregation::Sum => "sum",
+        SortAggregation::Min => "min",
+        SortAggregation::Max => "max",
+    }
+}
@@ -4,14 +4,14 @@ use crate::{
     filter::convert_filter,
     join::JoinStage,
     orderby::OrderByBuilder,
-    vacuum_cursor, BsonTransform, IntoBson,
+    vacuum_cursor, vacuum_session_cursor, BsonTransform, ExecutorOptions, IntoBson,
 };
 use connector_interface::{AggregationSelection, Filter, QueryArguments};
 use itertools::Itertools;
 use mongodb::{
     bson::{doc, Bson, Document},
     options::{AggregateOptions, FindOptions},
-    Collection,
+    ClientSession, Collection,
 };
 use prisma_models::{ModelProjection, ModelRef, ScalarFieldRef};
 
@@ -24,11 +24,19 @@ pub enum MongoReadQuery {
 }
 
 impl MongoReadQuery {
-    pub async fn execute(self, on_collection: Collection) -> crate::Result<Vec<Document>> {
+    /// `session` is `Some` when this read is part of a nested write graph running inside a
+    /// `MongoDbTransaction`, and makes the read observe that transaction's own uncommitted writes
+    /// instead of running outside of it under snapshot isolation.
+    pub async fn execute(
+        self,
+        on_collection: Collection,
+        executor_options: ExecutorOptions,
+        session: Option<&mut ClientSession>,
+    ) -> crate::Result<Vec<Document>> {
         log_query(on_collection.name(), &self);
         match self {
-            MongoReadQuery::Find(q) => q.execute(on_collection).await,
-            MongoReadQuery::Pipeline(q) => q.execute(on_collection).await,
+            MongoReadQuery::Find(q) => q.execute(on_collection, executor_options, session).await,
+            MongoReadQuery::Pipeline(q) => q.execute(on_collection, executor_options, session).await,
         }
     }
 }
@@ -38,11 +46,32 @@ pub struct PipelineQuery {
 }
 
 impl PipelineQuery {
-    pub async fn execute(self, on_collection: Collection) -> crate::Result<Vec<Document>> {
-        let opts = AggregateOptions::builder().allow_disk_use(true).build();
-        let cursor = on_collection.aggregate(self.stages, opts).await?;
+    pub async fn execute(
+        self,
+        on_collection: Collection,
+        executor_options: ExecutorOptions,
+        session: Option<&mut ClientSession>,
+    ) -> crate::Result<Vec<Document>> {
+        let mut opts = AggregateOptions::builder().allow_disk_use(executor_options.allow_disk_use);
+
+        if let Some(batch_size) = executor_options.batch_size {
+            opts = opts.batch_size(batch_size);
+        }
+
+        match session {
+            Some(session) => {
+                let cursor = on_collection
+                    .aggregate_with_session(self.stages, opts.build(), session)
+                    .await?;
+
+                Ok(vacuum_session_cursor(cursor, session).await?)
+            }
+            None => {
+                let cursor = on_collection.aggregate(self.stages, opts.build()).await?;
 
-        Ok(vacuum_cursor(cursor).await?)
+                Ok(vacuum_cursor(cursor).await?)
+            }
+        }
     }
 }
 
@@ -52,10 +81,30 @@ pub struct FindQuery {
 }
 
 impl FindQuery {
-    pub async fn execute(self, on_collection: Collection) -> crate::Result<Vec<Document>> {
-        let cursor = on_collection.find(self.filter, self.options).await?;
+    pub async fn execute(
+        mut self,
+        on_collection: Collection,
+        executor_options: ExecutorOptions,
+        session: Option<&mut ClientSession>,
+    ) -> crate::Result<Vec<Document>> {
+        if let Some(batch_size) = executor_options.batch_size {
+            self.options.batch_size = Some(batch_size);
+        }
+
+        match session {
+            Some(session) => {
+                let cursor = on_collection
+                    .find_with_session(self.filter, self.options, session)
+                    .await?;
+
+                Ok(vacuum_session_cursor(cursor, session).await?)
+            }
+            None => {
+                let cursor = on_collection.find(self.filter, self.options).await?;
 
-        Ok(vacuum_cursor(cursor).await?)
+                Ok(vacuum_cursor(cursor).await?)
+            }
+        }
     }
 }
 
@@ -1,8 +1,10 @@
-use crate::{output_meta, query_builder::MongoReadQueryBuilder, value::value_from_bson};
+use crate::{output_meta, query_builder::MongoReadQueryBuilder, value::value_from_bson, ExecutorOptions};
 use connector_interface::*;
-use mongodb::{bson::Document, Database};
+use mongodb::{bson::Document, ClientSession, Database};
 use prisma_models::prelude::*;
 
+/// `session` is `Some` when this aggregation is part of a nested write graph running inside a
+/// `MongoDbTransaction`, and lets it observe that transaction's own uncommitted writes.
 pub async fn aggregate(
     database: &Database,
     model: &ModelRef,
@@ -10,6 +12,8 @@ pub async fn aggregate(
     selections: Vec<AggregationSelection>,
     group_by: Vec<ScalarFieldRef>,
     having: Option<Filter>,
+    executor_options: ExecutorOptions,
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<Vec<AggregationRow>> {
     let coll = database.collection(&model.db_name());
     let query = MongoReadQueryBuilder::from_args(query_arguments)?
@@ -17,7 +21,7 @@ pub async fn aggregate(
         .with_having(having)?
         .build()?;
 
-    let docs = query.execute(coll).await?;
+    let docs = query.execute(coll, executor_options, session).await?;
     if docs.is_empty() {
         Ok(empty_aggregation(selections))
     } else {
@@ -1,12 +1,14 @@
 use super::*;
 use crate::{
-    filter::convert_filter, output_meta, query_builder::MongoReadQueryBuilder, vacuum_cursor, BsonTransform, IntoBson,
+    filter::convert_filter, output_meta, query_builder::MongoReadQueryBuilder, vacuum_cursor, vacuum_session_cursor,
+    BsonTransform, ExecutorOptions, IntoBson,
 };
 use connector_interface::{Filter, QueryArguments, RelAggregationSelection};
-use mongodb::Database;
-use mongodb::{bson::doc, options::FindOptions};
+use mongodb::{bson::doc, options::FindOptions, ClientSession, Database};
 use prisma_models::*;
 
+/// `session` is `Some` when this read is part of a nested write graph running inside a
+/// `MongoDbTransaction`, and lets it observe that transaction's own uncommitted writes.
 // TODO: Handle aggregation selections
 pub async fn get_single_record(
     database: &Database,
@@ -14,6 +16,7 @@ pub async fn get_single_record(
     filter: &Filter,
     selected_fields: &ModelProjection,
     _aggr_selections: &[RelAggregationSelection],
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<Option<SingleRecord>> {
     let coll = database.collection(model.db_name());
     let meta_mapping = output_meta::from_selected_fields(selected_fields);
@@ -22,8 +25,18 @@ pub async fn get_single_record(
         .projection(selected_fields.clone().into_bson()?.into_document()?)
         .build();
 
-    let cursor = coll.find(Some(filter), Some(find_options)).await?;
-    let docs = vacuum_cursor(cursor).await?;
+    let docs = match session {
+        Some(session) => {
+            let cursor = coll
+                .find_with_session(Some(filter), Some(find_options), session)
+                .await?;
+            vacuum_session_cursor(cursor, session).await?
+        }
+        None => {
+            let cursor = coll.find(Some(filter), Some(find_options)).await?;
+            vacuum_cursor(cursor).await?
+        }
+    };
 
     if docs.is_empty() {
         Ok(None)
@@ -41,7 +54,11 @@ pub async fn get_single_record(
 // - [ ] OrderBy relation.
 // - [x] Skip, take
 // - [ ] Cursor
-// - [x] Distinct select (inherently given from core).
+// - [x] Distinct select (inherently given from core - see `InMemoryRecordProcessor::apply_distinct`).
+//       This is intentional, not a gap to fill in here: distinct always needs the record's unique
+//       identifiers selected alongside it to rebuild full records, which rules out a `$group`-based
+//       push-down in the same way it rules out `DISTINCT` in the SQL connectors. Because it operates
+//       on materialized field values, it already covers fields backed by embedded/nested documents.
 // - [ ] Relation aggregation count
 pub async fn get_many_records(
     database: &Database,
@@ -49,6 +66,8 @@ pub async fn get_many_records(
     query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
     _aggregation_selections: &[RelAggregationSelection],
+    executor_options: ExecutorOptions,
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<ManyRecords> {
     let coll = database.collection(model.db_name());
     let reverse_order = query_arguments.take.map(|t| t < 0).unwrap_or(false);
@@ -64,7 +83,7 @@ pub async fn get_many_records(
         .with_model_projection(selected_fields.clone())?
         .build()?;
 
-    let docs = query.execute(coll).await?;
+    let docs = query.execute(coll, executor_options, session).await?;
     for doc in docs {
         let record = document_to_record(doc, &field_names, &meta_mapping)?;
         records.push(record)
@@ -81,6 +100,7 @@ pub async fn get_related_m2m_record_ids(
     database: &Database,
     from_field: &RelationFieldRef,
     from_record_ids: &[RecordProjection],
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>> {
     if from_record_ids.is_empty() {
         return Ok(vec![]);
@@ -104,8 +124,16 @@ pub async fn get_related_m2m_record_ids(
         .projection(doc! { id_field.db_name(): 1, relation_ids_field_name: 1 })
         .build();
 
-    let cursor = coll.find(filter, Some(find_options)).await?;
-    let docs = vacuum_cursor(cursor).await?;
+    let docs = match session {
+        Some(session) => {
+            let cursor = coll.find_with_session(filter, Some(find_options), session).await?;
+            vacuum_session_cursor(cursor, session).await?
+        }
+        None => {
+            let cursor = coll.find(filter, Some(find_options)).await?;
+            vacuum_cursor(cursor).await?
+        }
+    };
 
     let parent_id_meta = output_meta::from_field(&id_field);
     let id_holder_field = model.fields().find_from_scalar(relation_ids_field_name).unwrap();
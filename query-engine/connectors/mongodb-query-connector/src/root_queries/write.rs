@@ -1,21 +1,25 @@
 use super::*;
-use crate::{filter::convert_filter, output_meta, vacuum_cursor, IntoBson};
+use crate::{filter::convert_filter, output_meta, vacuum_cursor, vacuum_session_cursor, IntoBson};
 use connector_interface::*;
 use mongodb::{
-    bson::{doc, Document},
+    bson::{doc, Bson, Document},
     error::ErrorKind,
     options::{FindOptions, InsertManyOptions},
-    Database,
+    ClientSession, Database,
 };
 use prisma_models::{ModelRef, PrismaValue, RecordProjection};
 use std::convert::TryInto;
 
 /// Create a single record to the database resulting in a
 /// `RecordProjection` as an identifier pointing to the just-created document.
+///
+/// `session` is `Some` when this write is part of a nested write graph running inside a
+/// `MongoDbTransaction`, and lets the insert take part in that transaction.
 pub async fn create_record(
     database: &Database,
     model: &ModelRef,
     mut args: WriteArgs,
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<RecordProjection> {
     let coll = database.collection::<Document>(model.db_name());
 
@@ -49,7 +53,10 @@ pub async fn create_record(
         doc.insert(field.db_name().to_owned(), bson);
     }
 
-    let insert_result = coll.insert_one(doc, None).await?;
+    let insert_result = match session {
+        Some(session) => coll.insert_one_with_session(doc, None, session).await?,
+        None => coll.insert_one(doc, None).await?,
+    };
     let id_value = value_from_bson(insert_result.inserted_id, &id_meta)?;
 
     Ok(RecordProjection::from((id_field, id_value)))
@@ -60,6 +67,7 @@ pub async fn create_records(
     model: &ModelRef,
     args: Vec<WriteArgs>,
     skip_duplicates: bool,
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<usize> {
     let coll = database.collection::<Document>(model.db_name());
     let num_records = args.len();
@@ -89,7 +97,12 @@ pub async fn create_records(
     // the operation and throw an error afterwards that we must handle.
     let options = Some(InsertManyOptions::builder().ordered(!skip_duplicates).build());
 
-    match coll.insert_many(docs, options).await {
+    let insert_result = match session {
+        Some(session) => coll.insert_many_with_session(docs, options, session).await,
+        None => coll.insert_many(docs, options).await,
+    };
+
+    match insert_result {
         Ok(insert_result) => Ok(insert_result.inserted_ids.len()),
         Err(err) if skip_duplicates => match err.kind.as_ref() {
             ErrorKind::BulkWrite(ref failure) => match failure.write_errors {
@@ -109,6 +122,7 @@ pub async fn update_records(
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
+    mut session: Option<&mut ClientSession>,
 ) -> crate::Result<Vec<RecordProjection>> {
     let coll = database.collection::<Document>(model.db_name());
 
@@ -127,13 +141,25 @@ pub async fn update_records(
             .map(|p| (&id_field, p.values().next().unwrap()).into_bson())
             .collect::<crate::Result<Vec<_>>>()?
     } else {
+        // Run on `session` when we're inside a `MongoDbTransaction`, so this lookup sees the
+        // transaction's own uncommitted writes instead of racing them under snapshot isolation.
         let (filter, _joins) = convert_filter(record_filter.filter, false)?.render();
         let find_options = FindOptions::builder()
             .projection(doc! { id_field.db_name(): 1 })
             .build();
 
-        let cursor = coll.find(Some(filter), Some(find_options)).await?;
-        let docs = vacuum_cursor(cursor).await?;
+        let docs = match session.as_deref_mut() {
+            Some(session) => {
+                let cursor = coll
+                    .find_with_session(Some(filter), Some(find_options), session)
+                    .await?;
+                vacuum_session_cursor(cursor, session).await?
+            }
+            None => {
+                let cursor = coll.find(Some(filter), Some(find_options)).await?;
+                vacuum_cursor(cursor).await?
+            }
+        };
 
         docs.into_iter()
             .map(|mut doc| doc.remove(id_field.db_name()).unwrap())
@@ -167,7 +193,10 @@ pub async fn update_records(
     }
 
     if !update_doc.is_empty() {
-        coll.update_many(filter, update_doc, None).await?;
+        match session.as_deref_mut() {
+            Some(session) => coll.update_many_with_session(filter, update_doc, None, session).await?,
+            None => coll.update_many(filter, update_doc, None).await?,
+        };
     }
 
     let ids = ids
@@ -188,6 +217,7 @@ pub async fn delete_records(
     database: &Database,
     model: &ModelRef,
     record_filter: RecordFilter,
+    session: Option<&mut ClientSession>,
 ) -> crate::Result<usize> {
     let coll = database.collection::<Document>(model.db_name());
 
@@ -204,7 +234,11 @@ pub async fn delete_records(
         filter
     };
 
-    let delete_result = coll.delete_many(filter, None).await?;
+    let delete_result = match session {
+        Some(session) => coll.delete_many_with_session(filter, None, session).await?,
+        None => coll.delete_many(filter, None).await?,
+    };
+
     Ok(delete_result.deleted_count as usize)
 }
 
@@ -215,6 +249,7 @@ pub async fn m2m_connect(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
+    mut session: Option<&mut ClientSession>,
 ) -> crate::Result<()> {
     let parent_model = field.model();
     let child_model = field.related_model();
@@ -239,14 +274,29 @@ pub async fn m2m_connect(
     let parent_update = doc! { "$addToSet": { parent_ids_scalar_field_name: { "$each": child_ids.clone() } } };
 
     // First update the parent and add all child IDs to the m:n scalar field.
-    parent_coll.update_one(parent_filter, parent_update, None).await?;
+    match session.as_deref_mut() {
+        Some(session) => {
+            parent_coll
+                .update_one_with_session(parent_filter, parent_update, None, session)
+                .await?
+        }
+        None => parent_coll.update_one(parent_filter, parent_update, None).await?,
+    };
 
     // Then update all children and add the parent
     let child_filter = doc! { "_id": { "$in": child_ids } };
     let child_ids_scalar_field_name = field.related_field().relation_info.fields.get(0).unwrap().clone();
     let child_update = doc! { "$addToSet": { child_ids_scalar_field_name: parent_id } };
 
-    child_coll.update_many(child_filter, child_update, None).await?;
+    match session.as_deref_mut() {
+        Some(session) => {
+            child_coll
+                .update_many_with_session(child_filter, child_update, None, session)
+                .await?
+        }
+        None => child_coll.update_many(child_filter, child_update, None).await?,
+    };
+
     Ok(())
 }
 
@@ -255,6 +305,7 @@ pub async fn m2m_disconnect(
     field: &RelationFieldRef,
     parent_id: &RecordProjection,
     child_ids: &[RecordProjection],
+    mut session: Option<&mut ClientSession>,
 ) -> crate::Result<()> {
     let parent_model = field.model();
     let child_model = field.related_model();
@@ -279,14 +330,78 @@ pub async fn m2m_disconnect(
     let parent_update = doc! { "$pullAll": { parent_ids_scalar_field_name: child_ids.clone() } };
 
     // First update the parent and remove all child IDs to the m:n scalar field.
-    parent_coll.update_one(parent_filter, parent_update, None).await?;
+    match session.as_deref_mut() {
+        Some(session) => {
+            parent_coll
+                .update_one_with_session(parent_filter, parent_update, None, session)
+                .await?
+        }
+        None => parent_coll.update_one(parent_filter, parent_update, None).await?,
+    };
 
     // Then update all children and add the parent
     let child_filter = doc! { "_id": { "$in": child_ids } };
     let child_ids_scalar_field_name = field.related_field().relation_info.fields.get(0).unwrap().clone();
-
     let child_update = doc! { "$pull": { child_ids_scalar_field_name: parent_id } };
-    child_coll.update_many(child_filter, child_update, None).await?;
+
+    match session.as_deref_mut() {
+        Some(session) => {
+            child_coll
+                .update_many_with_session(child_filter, child_update, None, session)
+                .await?
+        }
+        None => child_coll.update_many(child_filter, child_update, None).await?,
+    };
 
     Ok(())
 }
+
+/// Runs a JSON-encoded MongoDB command document as-is via `db.runCommand`, returning the raw
+/// server response as JSON.
+pub async fn run_command_raw(database: &Database, command: String) -> crate::Result<serde_json::Value> {
+    let command: serde_json::Value = serde_json::from_str(&command)?;
+    let command = mongodb::bson::to_document(&command)?;
+
+    let response = database.run_command(command, None).await?;
+
+    Ok(serde_json::to_value(&response)?)
+}
+
+/// Runs a JSON-encoded aggregation pipeline as a database-level `aggregate` command (`{aggregate: 1, ...}`),
+/// so pipelines aren't tied to a single collection. `options` is an optional JSON-encoded document merged
+/// into the command, e.g. `{ "allowDiskUse": true }`. Returns the cursor's first batch as JSON.
+pub async fn aggregate_raw(
+    database: &Database,
+    pipeline: Vec<String>,
+    options: Option<String>,
+) -> crate::Result<serde_json::Value> {
+    let stages = pipeline
+        .into_iter()
+        .map(|stage| -> crate::Result<Document> {
+            let stage: serde_json::Value = serde_json::from_str(&stage)?;
+            Ok(mongodb::bson::to_document(&stage)?)
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let mut command = doc! { "aggregate": 1, "pipeline": stages, "cursor": {} };
+
+    if let Some(options) = options {
+        let options: serde_json::Value = serde_json::from_str(&options)?;
+        let options = mongodb::bson::to_document(&options)?;
+
+        for (key, value) in options {
+            command.insert(key, value);
+        }
+    }
+
+    let response = database.run_command(command, None).await?;
+    let batch = response
+        .get("cursor")
+        .and_then(Bson::as_document)
+        .and_then(|cursor| cursor.get("firstBatch"))
+        .and_then(Bson::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    Ok(serde_json::to_value(&batch)?)
+}
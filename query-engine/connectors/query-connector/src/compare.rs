@@ -43,6 +43,13 @@ pub trait ScalarCompare {
     where
         T: Into<PrismaValue>;
 
+    /// Full-text search. Only supported by connectors that expose the
+    /// `TextSearch` capability, gated by the `FullTextSearch` preview
+    /// feature.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
+
     fn less_than<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
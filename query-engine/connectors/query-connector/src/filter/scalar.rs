@@ -131,6 +131,7 @@ pub enum ScalarCondition {
     In(PrismaListValue),
     NotIn(PrismaListValue),
     JsonCompare(JsonCondition),
+    Search(PrismaValue),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -158,6 +159,10 @@ impl ScalarCondition {
                 Self::GreaterThanOrEquals(v) => Self::LessThan(v),
                 Self::In(v) => Self::NotIn(v),
                 Self::NotIn(v) => Self::In(v),
+                // Full-text search has no negated counterpart in the Prisma API, so a `NOT`
+                // wrapping a search filter is left as-is; the `NOT` node itself still negates
+                // the rest of the filter tree it belongs to.
+                Self::Search(v) => Self::Search(v),
                 Self::JsonCompare(json_compare) => {
                     let inverted_cond = json_compare.condition.invert(true);
 
@@ -295,6 +300,18 @@ impl ScalarCompare for ScalarFieldRef {
         })
     }
 
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(Arc::clone(self)),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
     /// Field is less than the given value.
     fn less_than<T>(&self, val: T) -> Filter
     where
@@ -465,6 +482,18 @@ impl ScalarCompare for ModelProjection {
         })
     }
 
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
     /// Field is less than the given value.
     fn less_than<T>(&self, val: T) -> Filter
     where
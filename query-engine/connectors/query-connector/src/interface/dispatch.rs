@@ -151,4 +151,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
             Self::Transaction(tx) => tx.execute_raw(query, parameters).await,
         }
     }
+
+    async fn run_command_raw(&self, command: String) -> crate::Result<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.run_command_raw(command).await,
+            Self::Transaction(tx) => tx.run_command_raw(command).await,
+        }
+    }
+
+    async fn aggregate_raw(&self, pipeline: Vec<String>, options: Option<String>) -> crate::Result<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.aggregate_raw(pipeline, options).await,
+            Self::Transaction(tx) => tx.aggregate_raw(pipeline, options).await,
+        }
+    }
 }
@@ -319,4 +319,13 @@ pub trait WriteOperations {
     ///
     /// Returns resulting rows as JSON.
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value>;
+
+    /// Run a MongoDB command document as-is against the database. Only supported by the
+    /// MongoDB connector; other connectors return an `UnsupportedFeature` error.
+    async fn run_command_raw(&self, command: String) -> crate::Result<serde_json::Value>;
+
+    /// Run a MongoDB aggregation pipeline as-is against the database, with an optional
+    /// JSON-encoded document of extra command options (e.g. `allowDiskUse`). Only supported by
+    /// the MongoDB connector; other connectors return an `UnsupportedFeature` error.
+    async fn aggregate_raw(&self, pipeline: Vec<String>, options: Option<String>) -> crate::Result<serde_json::Value>;
 }
@@ -30,6 +30,11 @@ pub struct QueryArguments {
     pub distinct: Option<ModelProjection>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
+    /// The name of an index to hint the database's query planner towards, set via the `indexHint`
+    /// query argument (only valid on models with `@@allowIndexHints`). Note: connectors don't
+    /// currently act on this - actually rendering the hint into generated SQL requires support
+    /// from `quaint` that doesn't exist yet, so this is plumbed through and validated but unused.
+    pub index_hint: Option<String>,
 }
 
 impl QueryArguments {
@@ -44,6 +49,7 @@ impl QueryArguments {
             distinct: None,
             ignore_take: false,
             ignore_skip: false,
+            index_hint: None,
         }
     }
 
@@ -148,6 +154,7 @@ impl QueryArguments {
                 let distinct = self.distinct;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
+                let index_hint = self.index_hint;
 
                 filter
                     .batched()
@@ -162,6 +169,7 @@ impl QueryArguments {
                         distinct: distinct.clone(),
                         ignore_skip,
                         ignore_take,
+                        index_hint: index_hint.clone(),
                     })
                     .collect()
             }
@@ -1,5 +1,5 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, sql_info::SqlInfo, QueryExt, SqlError};
+use crate::{database::operations::*, sql_info::SqlInfo, table_statistics::TableRowCountCache, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector::RelAggregationSelection;
 use connector_interface::{
@@ -10,10 +10,12 @@ use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
 use std::future::Future;
+use std::sync::Arc;
 
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    stats: Arc<TableRowCountCache>,
 }
 
 impl<C> SqlConnection<C>
@@ -22,7 +24,11 @@ where
 {
     pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        Self {
+            inner,
+            connection_info,
+            stats: Arc::new(TableRowCountCache::new()),
+        }
     }
 
     async fn catch<O>(
@@ -46,9 +52,11 @@ where
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
 
+        let stats = self.stats.clone();
+
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info, stats)) as Box<dyn Transaction>)
         })
         .await
     }
@@ -66,8 +74,19 @@ where
         selected_fields: &ModelProjection,
         aggr_selections: &[RelAggregationSelection],
     ) -> connector::Result<Option<SingleRecord>> {
+        let family = SqlInfo::from(&self.connection_info).family;
+
         self.catch(async move {
-            read::get_single_record(&self.inner, model, filter, selected_fields, aggr_selections).await
+            read::get_single_record(
+                &self.inner,
+                model,
+                filter,
+                selected_fields,
+                aggr_selections,
+                &self.stats,
+                &family,
+            )
+            .await
         })
         .await
     }
@@ -79,8 +98,19 @@ where
         selected_fields: &ModelProjection,
         aggr_selections: &[RelAggregationSelection],
     ) -> connector::Result<ManyRecords> {
+        let family = SqlInfo::from(&self.connection_info).family;
+
         self.catch(async move {
-            read::get_many_records(&self.inner, model, query_arguments, selected_fields, aggr_selections).await
+            read::get_many_records(
+                &self.inner,
+                model,
+                query_arguments,
+                selected_fields,
+                aggr_selections,
+                &self.stats,
+                &family,
+            )
+            .await
         })
         .await
     }
@@ -149,7 +179,9 @@ where
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let family = SqlInfo::from(&self.connection_info).family;
+
+        self.catch(async move { write::delete_records(&self.inner, model, record_filter, &family, false).await })
             .await
     }
 
@@ -182,4 +214,20 @@ where
         self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
             .await
     }
+
+    async fn run_command_raw(&self, _command: String) -> connector::Result<serde_json::Value> {
+        Err(connector::error::ConnectorError::from_kind(
+            connector::error::ErrorKind::UnsupportedFeature("runCommandRaw (MongoDB only)".to_owned()),
+        ))
+    }
+
+    async fn aggregate_raw(
+        &self,
+        _pipeline: Vec<String>,
+        _options: Option<String>,
+    ) -> connector::Result<serde_json::Value> {
+        Err(connector::error::ConnectorError::from_kind(
+            connector::error::ErrorKind::UnsupportedFeature("aggregateRaw (MongoDB only)".to_owned()),
+        ))
+    }
 }
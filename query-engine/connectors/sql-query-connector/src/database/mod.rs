@@ -28,6 +28,15 @@ pub trait FromSource {
     ///    want to handle this as early as possible and in a single place.
     ///
     /// 2. The url may be modified with the config dir, in the case of NAPI.
+    ///
+    /// Looked at adding transparent replica read failover here: a `FromSource` impl that opens a
+    /// second, replica-tagged connection pool alongside the primary one, with `ReadOperations`
+    /// routed to it and retried against the primary on a connection error. That needs a
+    /// replica URL to route to in the first place - a new datasource-level setting (or a
+    /// generated-client-level one) plus threading it through every engine that constructs a
+    /// connector this way, not just this trait. That's a bigger, cross-cutting change than fits
+    /// here, so it's left undone rather than landing a retry helper with no routing layer to call
+    /// it - every read would still go straight to the primary today.
     async fn from_source(source: &Datasource, url: &str) -> connector_interface::Result<Self>
     where
         Self: Connector + Sized;
@@ -42,3 +51,93 @@ async fn catch<O>(
         Err(err) => Err(err.into_connector_error(connection_info)),
     }
 }
+
+/// Appends the datasource's `pool_options` (`connectionLimit`, `poolTimeout`, `socketTimeout`,
+/// `statementCacheSize`, `pgbouncer`) to `url` as `key=value` query string parameters (the format
+/// Postgres, MySQL and SQLite URLs use), so they reach quaint the same way they would if the user
+/// had put them directly on the URL - these values are already validated integers/booleans by the
+/// time datamodel core hands them to us, so there's nothing left to validate here. A value already
+/// present on the URL itself is left alone: an explicit URL parameter is more specific than the
+/// datasource block default. MSSQL uses a different, semicolon-delimited URL format; see
+/// `url_with_mssql_pool_options` for that one.
+pub fn url_with_pool_options(url: &str, source: &Datasource) -> String {
+    let mut url_parts = url.splitn(2, '?');
+    let base = url_parts.next().unwrap_or(url);
+    let existing_params = url_parts.next().unwrap_or("");
+
+    let extra_params = match pool_option_pairs(source, existing_params, "&") {
+        Some(params) => params,
+        None => return url.to_owned(),
+    };
+
+    let mut result = base.to_owned();
+    result.push('?');
+
+    if !existing_params.is_empty() {
+        result.push_str(existing_params);
+        result.push('&');
+    }
+
+    result.push_str(&extra_params);
+
+    result
+}
+
+/// The `url_with_pool_options` equivalent for MSSQL's `sqlserver://host;key=value;...` URL format.
+pub fn url_with_mssql_pool_options(url: &str, source: &Datasource) -> String {
+    let extra_params = match pool_option_pairs(source, url, ";") {
+        Some(params) => params,
+        None => return url.to_owned(),
+    };
+
+    let mut result = url.to_owned();
+
+    if !result.ends_with(';') {
+        result.push(';');
+    }
+
+    result.push_str(&extra_params);
+
+    result
+}
+
+/// Builds the `key=value` pairs (joined by `separator`) for whichever of `source.pool_options`'
+/// fields aren't already present in `existing_params`. Returns `None` if there's nothing to add.
+fn pool_option_pairs(source: &Datasource, existing_params: &str, separator: &str) -> Option<String> {
+    let pool_options = source.pool_options.as_ref()?;
+
+    let has_param = |key: &str| {
+        existing_params
+            .split(|c| c == '&' || c == ';')
+            .any(|pair| pair.split('=').next() == Some(key))
+    };
+
+    let mut pairs = Vec::new();
+
+    if let (Some(connection_limit), false) = (pool_options.connection_limit, has_param("connection_limit")) {
+        pairs.push(format!("connection_limit={}", connection_limit));
+    }
+
+    if let (Some(pool_timeout), false) = (pool_options.pool_timeout, has_param("pool_timeout")) {
+        pairs.push(format!("pool_timeout={}", pool_timeout));
+    }
+
+    if let (Some(socket_timeout), false) = (pool_options.socket_timeout, has_param("socket_timeout")) {
+        pairs.push(format!("socket_timeout={}", socket_timeout));
+    }
+
+    if let (Some(statement_cache_size), false) = (pool_options.statement_cache_size, has_param("statement_cache_size"))
+    {
+        pairs.push(format!("statement_cache_size={}", statement_cache_size));
+    }
+
+    if let (Some(pgbouncer), false) = (pool_options.pgbouncer, has_param("pgbouncer")) {
+        pairs.push(format!("pgbouncer={}", pgbouncer));
+    }
+
+    if pairs.is_empty() {
+        None
+    } else {
+        Some(pairs.join(separator))
+    }
+}
@@ -17,8 +17,9 @@ pub struct Mssql {
 
 #[async_trait]
 impl FromSource for Mssql {
-    async fn from_source(_source: &Datasource, url: &str) -> connector_interface::Result<Self> {
-        let database_str = url;
+    async fn from_source(source: &Datasource, url: &str) -> connector_interface::Result<Self> {
+        let database_str = super::url_with_mssql_pool_options(url, source);
+        let database_str = database_str.as_str();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -2,6 +2,8 @@ use crate::{
     column_metadata,
     query_arguments_ext::QueryArgumentsExt,
     query_builder::{self, read},
+    sql_info::SqlFamily,
+    table_statistics::TableRowCountCache,
     QueryExt, SqlError,
 };
 use connector_interface::*;
@@ -9,6 +11,32 @@ use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
 use quaint::ast::*;
 
+/// Tables referenced by relation joins a nested aggregation selection needs, collected so their
+/// row counts can be looked up in one shot before building the query.
+fn aggregation_join_tables(aggr_selections: &[RelAggregationSelection]) -> Vec<String> {
+    aggr_selections
+        .iter()
+        .map(|aggr_sel| match aggr_sel {
+            RelAggregationSelection::Count(rf) => rf.related_model().db_name().to_owned(),
+        })
+        .collect()
+}
+
+/// Tables referenced by the relation joins a query might need - either to satisfy an `orderBy`
+/// across a relation, or a nested aggregation selection - collected so their row counts can be
+/// looked up in one shot before building the query.
+fn join_candidate_tables(query_arguments: &QueryArguments, aggr_selections: &[RelAggregationSelection]) -> Vec<String> {
+    let mut tables: Vec<String> = query_arguments
+        .order_by
+        .iter()
+        .flat_map(|order_by| order_by.path.iter().map(|rf| rf.related_model().db_name().to_owned()))
+        .collect();
+
+    tables.extend(aggregation_join_tables(aggr_selections));
+
+    tables
+}
+
 #[tracing::instrument(skip(conn, model, filter, selected_fields))]
 pub async fn get_single_record(
     conn: &dyn QueryExt,
@@ -16,8 +44,20 @@ pub async fn get_single_record(
     filter: &Filter,
     selected_fields: &ModelProjection,
     aggr_selections: &[RelAggregationSelection],
+    stats: &TableRowCountCache,
+    family: &SqlFamily,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), aggr_selections, filter);
+    // `filter` alone carries no ordering, so only nested aggregation joins are relevant here.
+    let join_order_hint = stats
+        .estimated_row_counts(conn, family, &aggregation_join_tables(aggr_selections))
+        .await;
+    let query = read::get_records(
+        &model,
+        selected_fields.as_columns(),
+        aggr_selections,
+        filter,
+        &join_order_hint,
+    );
 
     let mut field_names: Vec<_> = selected_fields.db_names().collect();
     let mut aggr_field_names: Vec<_> = aggr_selections.iter().map(|aggr_sel| aggr_sel.db_alias()).collect();
@@ -53,8 +93,13 @@ pub async fn get_many_records(
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
     aggr_selections: &[RelAggregationSelection],
+    stats: &TableRowCountCache,
+    family: &SqlFamily,
 ) -> crate::Result<ManyRecords> {
     let reversed = query_arguments.needs_reversed_order();
+    let join_order_hint = stats
+        .estimated_row_counts(conn, family, &join_candidate_tables(&query_arguments, aggr_selections))
+        .await;
 
     let mut field_names: Vec<_> = selected_fields.db_names().collect();
     let mut aggr_field_names: Vec<_> = aggr_selections.iter().map(|aggr_sel| aggr_sel.db_alias()).collect();
@@ -87,7 +132,13 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), aggr_selections, args);
+            let query = read::get_records(
+                model,
+                selected_fields.as_columns(),
+                aggr_selections,
+                args,
+                &join_order_hint,
+            );
 
             futures.push(conn.filter(query.into(), meta.as_slice()));
         }
@@ -102,7 +153,13 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), aggr_selections, query_arguments);
+        let query = read::get_records(
+            model,
+            selected_fields.as_columns(),
+            aggr_selections,
+            query_arguments,
+            &join_order_hint,
+        );
 
         for item in conn.filter(query.into(), meta.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
@@ -1,4 +1,9 @@
-use crate::{error::SqlError, query_builder::write, sql_info::SqlInfo, QueryExt};
+use crate::{
+    error::SqlError,
+    query_builder::write,
+    sql_info::{SqlFamily, SqlInfo},
+    QueryExt,
+};
 use connector_interface::*;
 use itertools::Itertools;
 use prisma_models::*;
@@ -12,6 +17,12 @@ use user_facing_errors::query_engine::DatabaseConstraint;
 /// `RecordProjection` as an identifier pointing to the just-created record.
 #[tracing::instrument(skip(conn, model, args))]
 pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
+    // Databases without a working `RETURNING` clause (MySQL, SQLite) can only tell us the
+    // last insert id, which is useless if the primary key isn't an autoincrement column, e.g.
+    // when it's `dbgenerated()` (`UUID()`, a trigger, ...). In that case we fall back to reading
+    // the row back by any unique field the caller did provide, so the returned id still reflects
+    // whatever the database actually generated.
+    let unique_filter = unique_filter_from_args(model, &args);
     let (insert, returned_id) = write::create_record(model, args);
 
     let result_set = match conn.insert(insert).await {
@@ -70,7 +81,38 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
             Ok(identifier)
         }
 
-        (_, _, _) => panic!("Could not figure out an ID in create"),
+        // The id is entirely DB-generated (e.g. `dbgenerated("UUID()")`, a trigger) and the
+        // database gave us neither a RETURNING result nor a usable last insert id. Read the row
+        // back using a unique field that was part of the write instead.
+        (_, _, _) => match unique_filter {
+            Some(filter) => match conn.filter_ids(model, filter).await?.pop() {
+                Some(identifier) => Ok(identifier),
+                None => panic!("Could not figure out an ID in create"),
+            },
+            None => panic!("Could not figure out an ID in create"),
+        },
+    }
+}
+
+/// Builds a filter identifying the row a create call is about to insert, based on any
+/// unique field present in the write arguments. Used as a read-back key for databases that
+/// can't tell us DB-generated values (e.g. `dbgenerated()` defaults) any other way.
+fn unique_filter_from_args(model: &ModelRef, args: &WriteArgs) -> Option<Filter> {
+    let filters: Vec<Filter> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| field.is_unique() && args.has_arg_for(field.db_name()))
+        .filter_map(|field| {
+            let value: PrismaValue = args.get_field_value(field.db_name())?.clone().try_into().ok()?;
+            Some(field.equals(value))
+        })
+        .collect();
+
+    if filters.is_empty() {
+        None
+    } else {
+        Some(Filter::and(filters))
     }
 }
 
@@ -245,12 +287,26 @@ pub async fn update_records(
 }
 
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
+///
+/// `in_transaction` must be `true` when `conn` is already running inside an open transaction
+/// (i.e. called through `SqlConnectorTransaction`, as opposed to a plain pooled connection) - see
+/// `truncate_all` for why this changes how its fast path is allowed to behave.
 #[tracing::instrument(skip(conn, model, record_filter))]
 pub async fn delete_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
     record_filter: RecordFilter,
+    sql_family: &SqlFamily,
+    in_transaction: bool,
 ) -> crate::Result<usize> {
+    let is_delete_all = record_filter.filter == Filter::empty() && record_filter.selectors.is_none();
+
+    if is_delete_all {
+        if let Some(count) = truncate_all(conn, model, sql_family, in_transaction).await? {
+            return Ok(count);
+        }
+    }
+
     let ids = conn.filter_selectors(model, record_filter).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
@@ -266,6 +322,103 @@ pub async fn delete_records(
     Ok(count)
 }
 
+/// Opt-in fast path for an unconditional `deleteMany()` (no `where`, no explicit selectors):
+/// issue a single `TRUNCATE` instead of selecting every row's id and deleting it in batches, which
+/// is what makes mass cleanup between tests slow on large tables. Off by default and gated behind
+/// `QE_TRUNCATE_DELETE_MANY=1`, since `TRUNCATE ... RESTART IDENTITY` also resets the table's
+/// auto-increment/identity sequence, which a plain `deleteMany()` isn't expected to do.
+///
+/// Returns `Ok(None)` (rather than deleting anything) whenever the fast path isn't applicable, so
+/// the caller falls back to the normal batched delete: the flag isn't set, the flavour is SQLite
+/// (which already optimizes an unconditional `DELETE FROM` internally, so `TRUNCATE` buys
+/// nothing), or the database refuses the `TRUNCATE` because another table still has a foreign key
+/// pointing at this one.
+async fn truncate_all(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    sql_family: &SqlFamily,
+    in_transaction: bool,
+) -> crate::Result<Option<usize>> {
+    if std::env::var("QE_TRUNCATE_DELETE_MANY").as_deref() != Ok("1") {
+        return Ok(None);
+    }
+
+    let table_name = match sql_family {
+        SqlFamily::SQLite => return Ok(None),
+        _ => quote_identifier(sql_family, model.db_name()),
+    };
+
+    // `TRUNCATE` doesn't report the number of rows it removed, so the row count has to be read
+    // beforehand to still be able to answer the `deleteMany()` result contract. Bracketing the
+    // count and the truncate in their own transaction closes the window a concurrent writer could
+    // otherwise use to insert or delete rows in between, which would make the reported count lie.
+    //
+    // This is a plain `BEGIN`/`COMMIT` rather than going through quaint's `TransactionCapable`:
+    // `conn` here is only a `&dyn QueryExt`, which doesn't say whether it's already an open
+    // transaction. We're told that explicitly instead via `in_transaction`, because on MySQL
+    // `BEGIN` doesn't nest or no-op like it does on Postgres/MSSQL - it implicitly commits
+    // whatever transaction is already open on the connection. Emitting a `BEGIN` from inside an
+    // interactive transaction or nested write would silently commit the caller's transaction
+    // partway through. When we're already inside one, the two statements just run as part of it,
+    // which is exactly the atomicity we want; otherwise we give them a transaction of their own.
+    if !in_transaction {
+        conn.raw_cmd("BEGIN").await?;
+    }
+
+    let count_query = format!("SELECT COUNT(*) AS count FROM {}", table_name);
+    let count = match conn.raw_json(count_query, vec![]).await {
+        Ok(serde_json::Value::Array(rows)) => rows
+            .first()
+            .and_then(|row| row.get("count"))
+            .and_then(|count| count.as_u64())
+            .unwrap_or(0) as usize,
+        _ => {
+            if !in_transaction {
+                conn.raw_cmd("ROLLBACK").await.ok();
+            }
+            return Ok(None);
+        }
+    };
+
+    if count == 0 {
+        if !in_transaction {
+            conn.raw_cmd("COMMIT").await?;
+        }
+        return Ok(Some(0));
+    }
+
+    let truncate = match sql_family {
+        SqlFamily::Postgres => format!("TRUNCATE TABLE {} RESTART IDENTITY", table_name),
+        SqlFamily::MySQL | SqlFamily::MSSQL => format!("TRUNCATE TABLE {}", table_name),
+        SqlFamily::SQLite => unreachable!("returned above"),
+    };
+
+    match conn.raw_count(truncate, vec![]).await {
+        Ok(_) => {
+            if !in_transaction {
+                conn.raw_cmd("COMMIT").await?;
+            }
+            Ok(Some(count))
+        }
+        // Most commonly a foreign key from another table pointing at this one; fall back silently.
+        Err(_) => {
+            if !in_transaction {
+                conn.raw_cmd("ROLLBACK").await.ok();
+            }
+            Ok(None)
+        }
+    }
+}
+
+fn quote_identifier(sql_family: &SqlFamily, identifier: &str) -> String {
+    match sql_family {
+        SqlFamily::Postgres => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        SqlFamily::MySQL => format!("`{}`", identifier.replace('`', "``")),
+        SqlFamily::MSSQL => format!("[{}]", identifier.replace(']', "]]")),
+        SqlFamily::SQLite => identifier.to_owned(),
+    }
+}
+
 /// Connect relations defined in `child_ids` to a parent defined in `parent_id`.
 /// The relation information is in the `RelationFieldRef`.
 #[tracing::instrument(skip(conn, field, parent_id, child_ids))]
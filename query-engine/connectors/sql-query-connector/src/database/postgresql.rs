@@ -16,8 +16,9 @@ pub struct PostgreSql {
 
 #[async_trait]
 impl FromSource for PostgreSql {
-    async fn from_source(_source: &Datasource, url: &str) -> connector_interface::Result<Self> {
-        let database_str = url;
+    async fn from_source(source: &Datasource, url: &str) -> connector_interface::Result<Self> {
+        let database_str = super::url_with_pool_options(url, source);
+        let database_str = database_str.as_str();
 
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
@@ -26,7 +27,7 @@ impl FromSource for PostgreSql {
             })
         })?;
 
-        let mut builder = Quaint::builder(url)
+        let mut builder = Quaint::builder(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
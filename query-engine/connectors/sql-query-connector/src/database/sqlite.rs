@@ -27,8 +27,9 @@ impl Sqlite {
 
 #[async_trait]
 impl FromSource for Sqlite {
-    async fn from_source(_source: &Datasource, url: &str) -> connector_interface::Result<Sqlite> {
-        let database_str = url;
+    async fn from_source(source: &Datasource, url: &str) -> connector_interface::Result<Sqlite> {
+        let database_str = super::url_with_pool_options(url, source);
+        let database_str = database_str.as_str();
 
         let connection_info = ConnectionInfo::from_url(database_str)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
@@ -1,5 +1,5 @@
 use crate::SqlError;
-use crate::{database::operations::*, sql_info::SqlInfo};
+use crate::{database::operations::*, sql_info::SqlInfo, table_statistics::TableRowCountCache};
 use async_trait::async_trait;
 use connector::RelAggregationSelection;
 use connector_interface::{
@@ -9,18 +9,25 @@ use connector_interface::{
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::prelude::ConnectionInfo;
+use std::sync::Arc;
 
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    stats: Arc<TableRowCountCache>,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
-    pub fn new(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(
+        tx: quaint::connector::Transaction<'tx>,
+        connection_info: &ConnectionInfo,
+        stats: Arc<TableRowCountCache>,
+    ) -> Self {
         let connection_info = connection_info.clone();
         Self {
             inner: tx,
             connection_info,
+            stats,
         }
     }
 
@@ -59,8 +66,19 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         selected_fields: &ModelProjection,
         aggr_selections: &[RelAggregationSelection],
     ) -> connector::Result<Option<SingleRecord>> {
+        let family = SqlInfo::from(&self.connection_info).family;
+
         self.catch(async move {
-            read::get_single_record(&self.inner, model, filter, selected_fields, aggr_selections).await
+            read::get_single_record(
+                &self.inner,
+                model,
+                filter,
+                selected_fields,
+                aggr_selections,
+                &self.stats,
+                &family,
+            )
+            .await
         })
         .await
     }
@@ -72,8 +90,19 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         selected_fields: &ModelProjection,
         aggr_selections: &[RelAggregationSelection],
     ) -> connector::Result<ManyRecords> {
+        let family = SqlInfo::from(&self.connection_info).family;
+
         self.catch(async move {
-            read::get_many_records(&self.inner, model, query_arguments, selected_fields, aggr_selections).await
+            read::get_many_records(
+                &self.inner,
+                model,
+                query_arguments,
+                selected_fields,
+                aggr_selections,
+                &self.stats,
+                &family,
+            )
+            .await
         })
         .await
     }
@@ -139,7 +168,9 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let family = SqlInfo::from(&self.connection_info).family;
+
+        self.catch(async move { write::delete_records(&self.inner, model, record_filter, &family, true).await })
             .await
     }
 
@@ -172,4 +203,20 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
             .await
     }
+
+    async fn run_command_raw(&self, _command: String) -> connector::Result<serde_json::Value> {
+        Err(connector::error::ConnectorError::from_kind(
+            connector::error::ErrorKind::UnsupportedFeature("runCommandRaw (MongoDB only)".to_owned()),
+        ))
+    }
+
+    async fn aggregate_raw(
+        &self,
+        _pipeline: Vec<String>,
+        _options: Option<String>,
+    ) -> connector::Result<serde_json::Value> {
+        Err(connector::error::ConnectorError::from_kind(
+            connector::error::ErrorKind::UnsupportedFeature("aggregateRaw (MongoDB only)".to_owned()),
+        ))
+    }
 }
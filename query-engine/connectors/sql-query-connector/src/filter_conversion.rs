@@ -180,6 +180,10 @@ impl AliasedCondition for ScalarListFilter {
     }
 }
 
+/// Renders `has`/`hasEvery`/`hasSome`/`isEmpty` in terms of Postgres's array operators: `@>`
+/// (contains) for `has`/`hasEvery`, `&&` (overlaps) for `hasSome`, and an equality/inequality
+/// check against `'{}'` for `isEmpty`. Only reachable for connectors with the `ScalarLists`
+/// capability, currently Postgres, so there's no `ANY`/MySQL/SQLite fallback to render here.
 fn convert_scalar_list_filter(
     comparable: impl Comparable<'static>,
     cond: ScalarListCondition,
@@ -563,6 +567,10 @@ fn default_scalar_filter(
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
         ScalarCondition::JsonCompare(_) => unreachable!(),
+        // Full-text search is currently only implemented for the MongoDB connector. The schema
+        // builder only exposes the `search` filter for connectors advertising the `TextSearch`
+        // capability, so this can't be reached on SQL connectors today.
+        ScalarCondition::Search(_) => unreachable!("Full-text search is not supported by SQL connectors"),
     };
 
     ConditionTree::single(condition)
@@ -574,19 +582,40 @@ fn insensitive_scalar_filter(
     fields: &[ScalarFieldRef],
     is_parent_aggregation: bool,
 ) -> ConditionTree<'static> {
-    // Current workaround: We assume we can use ILIKE when we see `mode: insensitive`, because postgres is the only DB that has
-    // insensitive. We need a connector context for filter building that is unexpectedly complicated to integrate.
+    // `mode: insensitive` is implemented as a portable `LOWER(column) op LOWER(value)`
+    // comparison rather than an engine-specific case-insensitive operator (e.g. Postgres'
+    // `ILIKE`), because this function has no connector context to know which SQL flavour
+    // it is building for. This is the same trade-off the ordering comparisons below already
+    // make, and it lets `mode: insensitive` behave identically on every connector that
+    // advertises `InsensitiveFilters` instead of only the one that happens to have a
+    // built-in case-insensitive operator.
     let condition = match cond {
         ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
         ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
-        ScalarCondition::Equals(value) => comparable.compare_raw("ILIKE", format!("{}", value)),
-        ScalarCondition::NotEquals(value) => comparable.compare_raw("NOT ILIKE", format!("{}", value)),
-        ScalarCondition::Contains(value) => comparable.compare_raw("ILIKE", format!("%{}%", value)),
-        ScalarCondition::NotContains(value) => comparable.compare_raw("NOT ILIKE", format!("%{}%", value)),
-        ScalarCondition::StartsWith(value) => comparable.compare_raw("ILIKE", format!("{}%", value)),
-        ScalarCondition::NotStartsWith(value) => comparable.compare_raw("NOT ILIKE", format!("{}%", value)),
-        ScalarCondition::EndsWith(value) => comparable.compare_raw("ILIKE", format!("%{}", value)),
-        ScalarCondition::NotEndsWith(value) => comparable.compare_raw("NOT ILIKE", format!("%{}", value)),
+        ScalarCondition::Equals(value) => {
+            lower_if(comparable, !is_parent_aggregation).equals(format!("{}", value).to_lowercase())
+        }
+        ScalarCondition::NotEquals(value) => {
+            lower_if(comparable, !is_parent_aggregation).not_equals(format!("{}", value).to_lowercase())
+        }
+        ScalarCondition::Contains(value) => {
+            lower_if(comparable, !is_parent_aggregation).like(format!("%{}%", value).to_lowercase())
+        }
+        ScalarCondition::NotContains(value) => {
+            lower_if(comparable, !is_parent_aggregation).not_like(format!("%{}%", value).to_lowercase())
+        }
+        ScalarCondition::StartsWith(value) => {
+            lower_if(comparable, !is_parent_aggregation).begins_with(format!("{}", value).to_lowercase())
+        }
+        ScalarCondition::NotStartsWith(value) => {
+            lower_if(comparable, !is_parent_aggregation).not_begins_with(format!("{}", value).to_lowercase())
+        }
+        ScalarCondition::EndsWith(value) => {
+            lower_if(comparable, !is_parent_aggregation).ends_into(format!("{}", value).to_lowercase())
+        }
+        ScalarCondition::NotEndsWith(value) => {
+            lower_if(comparable, !is_parent_aggregation).not_ends_into(format!("{}", value).to_lowercase())
+        }
         ScalarCondition::LessThan(value) => {
             let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
 
@@ -662,6 +691,7 @@ fn insensitive_scalar_filter(
             }
         },
         ScalarCondition::JsonCompare(_) => unreachable!(),
+        ScalarCondition::Search(_) => unreachable!("Full-text search is not supported by SQL connectors"),
     };
 
     ConditionTree::single(condition)
@@ -7,6 +7,11 @@ pub struct AliasedJoin {
     pub(crate) data: JoinData<'static>,
     // Alias used for the join. eg: LEFT JOIN ... AS <alias>
     pub(crate) alias: String,
+    // Database name of the related model's table, used to look up row count statistics for join
+    // ordering. Set even when `data` joins a derived subquery (e.g. an aggregation join) rather
+    // than the table directly, since the related table's size is still the best proxy we have for
+    // how expensive that join is.
+    pub(crate) table_name: String,
 }
 
 #[derive(Debug, Clone)]
@@ -86,6 +91,7 @@ fn compute_aggr_join_one2m(
     AliasedJoin {
         data: join,
         alias: join_alias.to_owned(),
+        table_name: rf.related_model().db_name().to_owned(),
     }
 }
 
@@ -157,6 +163,7 @@ fn compute_aggr_join_m2m(
     AliasedJoin {
         alias: join_alias.to_owned(),
         data: join,
+        table_name: rf.related_model().db_name().to_owned(),
     }
 }
 
@@ -198,6 +205,7 @@ pub fn compute_one2m_join(base_model: &ModelRef, rf: &RelationFieldRef, join_pre
 
     AliasedJoin {
         alias: right_table_alias.to_owned(),
+        table_name: related_model.db_name().to_owned(),
         data: related_model
             .as_table()
             .alias(right_table_alias)
@@ -15,8 +15,10 @@ mod ordering;
 mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
+mod replication;
 mod row;
 mod sql_info;
+mod table_statistics;
 
 use column_metadata::*;
 use filter_conversion::*;
@@ -25,5 +27,6 @@ use row::*;
 
 pub use database::*;
 pub use error::SqlError;
+pub use replication::{ReplicationChange, ReplicationChangeKind, ReplicationSlot};
 
 type Result<T> = std::result::Result<T, error::SqlError>;
@@ -10,6 +10,11 @@ pub struct RelAggregationJoins {
     pub(crate) columns: Vec<Column<'static>>,
 }
 
+/// Builds the joins (and the columns to select from them) needed to answer a `_count` selection
+/// on one or more relation fields alongside the parent record, e.g. `_count { comments }` on a
+/// `findManyPost` query. Each selection becomes a `LEFT JOIN` onto a `GROUP BY`'d subquery that
+/// counts related rows per parent id (see `join_utils::compute_aggr_join`), so a page of records
+/// and their relation counts come back in a single round trip instead of one count query per row.
 pub fn build(aggr_selections: &[RelAggregationSelection]) -> RelAggregationJoins {
     let mut joins = vec![];
     let mut columns: Vec<Column<'static>> = vec![];
@@ -3,12 +3,19 @@ use connector_interface::{filter::Filter, AggregationSelection, QueryArguments,
 use itertools::Itertools;
 use prisma_models::*;
 use quaint::ast::*;
+use std::collections::HashMap;
+
+/// Approximate row counts per table name, used to order relation joins smallest-first. An empty
+/// map (the default for callers that haven't fetched statistics) simply preserves the original,
+/// selection-order join order.
+pub type JoinOrderHint = HashMap<String, i64>;
 
 pub trait SelectDefinition {
     fn into_select(
         self,
         _: &ModelRef,
         aggr_selections: &[RelAggregationSelection],
+        join_order_hint: &JoinOrderHint,
     ) -> (Select<'static>, Vec<Column<'static>>);
 }
 
@@ -17,9 +24,10 @@ impl SelectDefinition for Filter {
         self,
         model: &ModelRef,
         aggr_selections: &[RelAggregationSelection],
+        join_order_hint: &JoinOrderHint,
     ) -> (Select<'static>, Vec<Column<'static>>) {
         let args = QueryArguments::from((model.clone(), self));
-        args.into_select(model, aggr_selections)
+        args.into_select(model, aggr_selections, join_order_hint)
     }
 }
 
@@ -28,23 +36,30 @@ impl SelectDefinition for &Filter {
         self,
         model: &ModelRef,
         aggr_selections: &[RelAggregationSelection],
+        join_order_hint: &JoinOrderHint,
     ) -> (Select<'static>, Vec<Column<'static>>) {
-        self.clone().into_select(model, aggr_selections)
+        self.clone().into_select(model, aggr_selections, join_order_hint)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef, _: &[RelAggregationSelection]) -> (Select<'static>, Vec<Column<'static>>) {
+    fn into_select(
+        self,
+        _: &ModelRef,
+        _: &[RelAggregationSelection],
+        _: &JoinOrderHint,
+    ) -> (Select<'static>, Vec<Column<'static>>) {
         (self, vec![])
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    #[tracing::instrument(skip(self, model, aggr_selections))]
+    #[tracing::instrument(skip(self, model, aggr_selections, join_order_hint))]
     fn into_select(
         self,
         model: &ModelRef,
         aggr_selections: &[RelAggregationSelection],
+        join_order_hint: &JoinOrderHint,
     ) -> (Select<'static>, Vec<Column<'static>>) {
         let (orderings, ordering_joins) = ordering::build(&self, &model);
         let (table_opt, cursor_condition) = cursor_condition::build(&self, &model, &ordering_joins);
@@ -64,18 +79,27 @@ impl SelectDefinition for QueryArguments {
             (filter, cursor) => ConditionTree::and(filter, cursor),
         };
 
-        // Add joins necessary to the ordering
-        let joined_table = ordering_joins
+        // Each entry is a chain of joins that must stay in relative order (a multi-hop relation
+        // path), but the chains themselves - and the single-join nested aggregations - don't
+        // depend on each other and can be freely reordered. Put the ones touching the smallest
+        // tables first, since that tends to produce better plans on optimizers that don't reorder
+        // joins themselves.
+        let mut join_groups: Vec<Vec<_>> = ordering_joins.into_iter().map(|j| j.joins).collect();
+        join_groups.extend(aggregation_joins.joins.iter().cloned().map(|join| vec![join]));
+
+        join_groups.sort_by_key(|group| {
+            group
+                .first()
+                .and_then(|join| join_order_hint.get(&join.table_name))
+                .copied()
+                .unwrap_or(i64::MAX)
+        });
+
+        let joined_table = join_groups
             .into_iter()
-            .flat_map(|j| j.joins)
+            .flatten()
             .fold(model.as_table(), |acc, join| acc.left_join(join.data));
 
-        // Add joins necessary to the nested aggregations
-        let joined_table = aggregation_joins
-            .joins
-            .into_iter()
-            .fold(joined_table, |acc, join| acc.left_join(join.data));
-
         let select_ast = Select::from_table(joined_table)
             .so_that(conditions)
             .offset(skip as usize);
@@ -95,17 +119,18 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-#[tracing::instrument(skip(model, columns, aggr_selections, query))]
+#[tracing::instrument(skip(model, columns, aggr_selections, query, join_order_hint))]
 pub fn get_records<T>(
     model: &ModelRef,
     columns: impl Iterator<Item = Column<'static>>,
     aggr_selections: &[RelAggregationSelection],
     query: T,
+    join_order_hint: &JoinOrderHint,
 ) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    let (select, aggr_columns) = query.into_select(model, aggr_selections);
+    let (select, aggr_columns) = query.into_select(model, aggr_selections, join_order_hint);
     let select = columns.fold(select, |acc, col| acc.column(col));
 
     aggr_columns.into_iter().fold(select, |acc, col| acc.column(col))
@@ -140,7 +165,7 @@ where
 #[tracing::instrument(skip(model, selections, args))]
 pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
     let columns = extract_columns(model, &selections);
-    let sub_query = get_records(model, columns.into_iter(), &[], args);
+    let sub_query = get_records(model, columns.into_iter(), &[], args, &JoinOrderHint::new());
     let sub_table = Table::from(sub_query).alias("sub");
 
     selections
@@ -186,7 +211,7 @@ pub fn group_by_aggregate(
     group_by: Vec<ScalarFieldRef>,
     having: Option<Filter>,
 ) -> Select<'static> {
-    let (base_query, _) = args.into_select(model, &[]);
+    let (base_query, _) = args.into_select(model, &[], &JoinOrderHint::new());
 
     let select_query = selections.iter().fold(base_query, |select, next_op| match next_op {
         AggregationSelection::Field(field) => select.column(field.as_column()),
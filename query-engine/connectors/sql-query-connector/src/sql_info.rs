@@ -55,6 +55,24 @@ impl SqlInfo {
     }
 }
 
+impl SqlInfo {
+    /// Whether the connector can deduplicate rows in the database via `SELECT DISTINCT ON`
+    /// (Postgres) instead of `query-core` fetching every row and deduplicating in memory (see
+    /// `InMemoryRecordProcessor::apply_distinct`).
+    ///
+    /// This is a capability flag only - nothing consults it yet. Actually pushing `distinct`
+    /// down requires two things this crate doesn't have today: a `quaint` `Select` builder API
+    /// for `DISTINCT ON`/window-function deduplication (`quaint` is an external dependency we
+    /// can't extend from here), and a way for `connector_interface`'s connector-agnostic
+    /// `QueryArguments::requires_inmemory_processing` to ask a specific connector whether it can
+    /// handle `distinct` itself instead of always forcing in-memory processing. Wiring either of
+    /// those up is future work; MySQL, SQL Server and SQLite would still need the window-function
+    /// fallback described above even after Postgres gets `DISTINCT ON` support.
+    pub fn supports_distinct_on(&self) -> bool {
+        matches!(self.family, SqlFamily::Postgres)
+    }
+}
+
 impl From<&ConnectionInfo> for SqlInfo {
     fn from(ci: &ConnectionInfo) -> Self {
         match ci {
@@ -0,0 +1,85 @@
+use crate::{sql_info::SqlFamily, QueryExt};
+use quaint::connector::ResultRow;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// Lazily fetches and caches approximate row counts for tables, so relation joins can be ordered
+/// smallest-table-first instead of in whatever order the query happened to add them. Estimates
+/// come from statistics the database already maintains for its own query planner (`pg_class` on
+/// Postgres, `information_schema.tables` on MySQL) rather than a live `COUNT(*)`, since those are
+/// cheap to read and "close enough" is all that's needed to pick a join order.
+///
+/// The cache lives on the connection, so it only helps for as long as that connection (or
+/// transaction) is reused, but that is enough to avoid re-querying statistics for every row of a
+/// batched or repeated read.
+#[derive(Debug, Default)]
+pub struct TableRowCountCache {
+    counts: RwLock<HashMap<String, i64>>,
+}
+
+impl TableRowCountCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached (or freshly fetched) approximate row count for each of `tables`. Tables
+    /// this connector doesn't have statistics for (SQLite, MSSQL, or ones the query errored on)
+    /// are simply absent from the result. `into_select` then sorts joins on such tables after
+    /// every join that has an estimate, keeping their relative order; when no table has an
+    /// estimate, the join order is left as it was.
+    pub async fn estimated_row_counts(
+        &self,
+        conn: &dyn QueryExt,
+        family: &SqlFamily,
+        tables: &[String],
+    ) -> HashMap<String, i64> {
+        let mut missing = vec![];
+        {
+            let cache = self.counts.read().await;
+            for table in tables {
+                if !cache.contains_key(table) {
+                    missing.push(table.clone());
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            let mut cache = self.counts.write().await;
+            for table in &missing {
+                if let Ok(Some(count)) = fetch_row_count_estimate(conn, family, table).await {
+                    cache.insert(table.clone(), count);
+                }
+            }
+        }
+
+        let cache = self.counts.read().await;
+        tables
+            .iter()
+            .filter_map(|table| cache.get(table).map(|count| (table.clone(), *count)))
+            .collect()
+    }
+}
+
+async fn fetch_row_count_estimate(conn: &dyn QueryExt, family: &SqlFamily, table: &str) -> crate::Result<Option<i64>> {
+    let row: Option<ResultRow> = match family {
+        SqlFamily::Postgres => conn
+            .query_raw(
+                "SELECT reltuples::bigint AS estimate FROM pg_catalog.pg_class WHERE relname = $1",
+                &[table.into()],
+            )
+            .await?
+            .into_iter()
+            .next(),
+        SqlFamily::MySQL => conn
+            .query_raw(
+                "SELECT table_rows AS estimate FROM information_schema.tables WHERE table_name = ?",
+                &[table.into()],
+            )
+            .await?
+            .into_iter()
+            .next(),
+        SqlFamily::SQLite | SqlFamily::MSSQL => return Ok(None),
+    };
+
+    Ok(row.and_then(|row| row.get("estimate").and_then(|value| value.as_i64())))
+}
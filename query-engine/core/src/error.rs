@@ -114,12 +114,19 @@ impl From<CoreError> for user_facing_errors::Error {
                     QueryParserErrorKind::RequiredValueNotSetError => {
                         user_facing_errors::KnownError::new(user_facing_errors::query_engine::MissingRequiredValue {
                             path: format!("{}", query_parser_error.path),
+                            path_pointer: query_parser_error.path.json_pointer(),
+                        })
+                    }
+                    _ => {
+                        let expected_type = query_parser_error.error_kind.expected_type();
+
+                        user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryValidationFailed {
+                            query_validation_error: format!("{}", query_parser_error.error_kind),
+                            query_position: format!("{}", query_parser_error.path),
+                            query_pointer: query_parser_error.path.json_pointer(),
+                            expected_type,
                         })
                     }
-                    _ => user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryValidationFailed {
-                        query_validation_error: format!("{}", query_parser_error.error_kind),
-                        query_position: format!("{}", query_parser_error.path),
-                    }),
                 };
 
                 known_error.into()
@@ -161,6 +168,12 @@ impl From<CoreError> for user_facing_errors::Error {
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::WriteOperationsNotAllowed { query }) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::WriteOperationsNotAllowed {
+                    query,
+                })
+                .into()
+            }
             CoreError::InterpreterError(InterpreterError::InterpretationError(msg, Some(cause))) => {
                 match cause.as_ref() {
                     InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::RecordNotFound(cause)) => {
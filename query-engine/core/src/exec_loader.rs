@@ -133,7 +133,11 @@ async fn postgres(source: &Datasource, url: &str) -> crate::Result<(String, Box<
     let database_str = url;
     let psql = PostgreSql::from_source(source, url).await?;
 
-    let url = Url::parse(database_str)?;
+    // Pick up `pgbouncer` whether it was set directly on the URL or via the datasource's
+    // structured `pool_options`, since `PostgreSql::from_source` merges the latter into the URL
+    // internally before quaint ever sees it.
+    let effective_url = sql_connector::url_with_pool_options(database_str, source);
+    let url = Url::parse(&effective_url)?;
     let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
 
     let force_transactions = params
@@ -1,8 +1,9 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
+use super::{pipeline::QueryPipeline, EngineMetrics, EngineMetricsSnapshot, PhaseTimings, QueryExecutor};
 use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
 use futures::future;
+use std::{sync::Arc, time::Instant};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -12,6 +13,10 @@ pub struct InterpretingExecutor<C> {
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Query counters and latency totals, polled through `QueryExecutor::metrics`. Kept behind an
+    /// `Arc` so the non-transactional batch path can share it with the tasks it spawns.
+    metrics: Arc<EngineMetrics>,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -22,6 +27,7 @@ where
         InterpretingExecutor {
             connector,
             force_transactions,
+            metrics: Arc::new(EngineMetrics::default()),
         }
     }
 
@@ -32,12 +38,14 @@ where
         conn: Box<dyn Connection>,
         force_transactions: bool,
         query_schema: QuerySchemaRef,
-    ) -> crate::Result<ResponseData> {
+    ) -> crate::Result<(ResponseData, PhaseTimings)> {
         // Parse, validate, and extract query graph from query document.
+        let graph_building_started_at = Instant::now();
         let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+        let graph_building = graph_building_started_at.elapsed();
         let is_transactional = force_transactions || query_graph.needs_transaction();
 
-        if is_transactional {
+        let result = if is_transactional {
             let tx = conn.start_transaction().await?;
             let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
             let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
@@ -52,7 +60,12 @@ where
         } else {
             let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
             QueryPipeline::new(query_graph, interpreter, serializer).execute().await
-        }
+        };
+
+        result.map(|(response, mut timings)| {
+            timings.graph_building = graph_building;
+            (response, timings)
+        })
     }
 }
 
@@ -82,22 +95,34 @@ where
         if transactional {
             let queries = operations
                 .into_iter()
-                .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
+                .map(|op| {
+                    let started_at = Instant::now();
+                    QueryGraphBuilder::new(query_schema.clone())
+                        .build(op)
+                        .map(|(graph, info)| (graph, info, started_at.elapsed()))
+                })
                 .collect::<std::result::Result<Vec<_>, _>>()?;
 
             let conn = self.connector.get_connection().await?;
             let tx = conn.start_transaction().await?;
             let mut results = Vec::with_capacity(queries.len());
 
-            for (query, info) in queries {
+            for (query, info, graph_building) in queries {
+                let started_at = Instant::now();
                 let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
                 let result = QueryPipeline::new(query, interpreter, info).execute().await;
 
+                let timings = PhaseTimings {
+                    graph_building,
+                    ..result.as_ref().map(|(_, timings)| *timings).unwrap_or_default()
+                };
+                self.metrics.record_query(started_at.elapsed(), timings, result.is_ok());
+
                 if result.is_err() {
                     tx.rollback().await?;
                 }
 
-                results.push(Ok(result?));
+                results.push(Ok(result?.0));
             }
 
             tx.commit().await?;
@@ -107,12 +132,19 @@ where
 
             for operation in operations {
                 let conn = self.connector.get_connection().await?;
-                futures.push(tokio::spawn(Self::execute_single_operation(
-                    operation,
-                    conn,
-                    self.force_transactions,
-                    query_schema.clone(),
-                )));
+                let force_transactions = self.force_transactions;
+                let query_schema = query_schema.clone();
+                let metrics = self.metrics.clone();
+
+                futures.push(tokio::spawn(async move {
+                    let started_at = Instant::now();
+                    let result =
+                        Self::execute_single_operation(operation, conn, force_transactions, query_schema).await;
+                    let timings = result.as_ref().map(|(_, timings)| *timings).unwrap_or_default();
+                    metrics.record_query(started_at.elapsed(), timings, result.is_ok());
+
+                    result.map(|(response, _)| response)
+                }));
             }
 
             let responses: Vec<_> = future::join_all(futures)
@@ -128,10 +160,20 @@ where
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
     async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
         let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+        let started_at = Instant::now();
+        let result =
+            Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await;
+        let timings = result.as_ref().map(|(_, timings)| *timings).unwrap_or_default();
+        self.metrics.record_query(started_at.elapsed(), timings, result.is_ok());
+
+        result.map(|(response, _)| response)
     }
 
     fn primary_connector(&self) -> &(dyn Connector + Send + Sync) {
         &self.connector
     }
+
+    fn metrics(&self) -> EngineMetricsSnapshot {
+        self.metrics.snapshot()
+    }
 }
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// Wall-clock time spent in each phase of executing a single operation, timed by
+/// `InterpretingExecutor::execute_single_operation` and `QueryPipeline::execute` and fed into
+/// [`EngineMetrics::record_query`]. Lets a slow query be attributed to the phase that was actually
+/// slow instead of just the total.
+///
+/// There is no separate "schema lookup" phase: the query schema is built once at startup, and the
+/// lookups a query does against it happen inside `QueryGraphBuilder::build`, so they are counted
+/// as part of `graph_building`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PhaseTimings {
+    /// Parsing the operation and building/validating the `QueryGraph` from it.
+    pub graph_building: Duration,
+    /// Translating the graph into an `Expression` and interpreting it against the connector.
+    pub connector_execution: Duration,
+    /// Turning the `ExpressionResult` into the response IR.
+    pub serialization: Duration,
+}
+
+/// Query counters and latency totals collected by a [`QueryExecutor`](super::QueryExecutor)
+/// while it runs. Cheap to update (a handful of atomic adds per query) and cheap to read, so it
+/// can be polled at any time, e.g. to feed a Prometheus exporter on the client side.
+#[derive(Debug, Default)]
+pub struct EngineMetrics {
+    queries_total: AtomicU64,
+    queries_failed_total: AtomicU64,
+    query_duration_micros_total: AtomicU64,
+    graph_building_micros_total: AtomicU64,
+    connector_execution_micros_total: AtomicU64,
+    serialization_micros_total: AtomicU64,
+}
+
+impl EngineMetrics {
+    pub(crate) fn record_query(&self, duration: Duration, timings: PhaseTimings, succeeded: bool) {
+        self.queries_total.fetch_add(1, Ordering::Relaxed);
+        self.query_duration_micros_total
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+        self.graph_building_micros_total
+            .fetch_add(timings.graph_building.as_micros() as u64, Ordering::Relaxed);
+        self.connector_execution_micros_total
+            .fetch_add(timings.connector_execution.as_micros() as u64, Ordering::Relaxed);
+        self.serialization_micros_total
+            .fetch_add(timings.serialization.as_micros() as u64, Ordering::Relaxed);
+
+        if !succeeded {
+            self.queries_failed_total.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// A point-in-time snapshot of the counters, safe to serialize and hand off to the client.
+    pub fn snapshot(&self) -> EngineMetricsSnapshot {
+        EngineMetricsSnapshot {
+            queries_total: self.queries_total.load(Ordering::Relaxed),
+            queries_failed_total: self.queries_failed_total.load(Ordering::Relaxed),
+            query_duration_micros_total: self.query_duration_micros_total.load(Ordering::Relaxed),
+            graph_building_micros_total: self.graph_building_micros_total.load(Ordering::Relaxed),
+            connector_execution_micros_total: self.connector_execution_micros_total.load(Ordering::Relaxed),
+            serialization_micros_total: self.serialization_micros_total.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// See [`EngineMetrics`]. Connection pool statistics (wait time, open connections) are not
+/// included: nothing in the `Connector` trait currently exposes them in a connector-agnostic
+/// way, so surfacing them would mean guessing at the pooling internals of each SQL flavour.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineMetricsSnapshot {
+    pub queries_total: u64,
+    pub queries_failed_total: u64,
+    pub query_duration_micros_total: u64,
+    /// Time spent building and validating query graphs, summed across all queries. See
+    /// [`PhaseTimings::graph_building`].
+    pub graph_building_micros_total: u64,
+    /// Time spent translating and interpreting queries against the connector, summed across all
+    /// queries. See [`PhaseTimings::connector_execution`].
+    pub connector_execution_micros_total: u64,
+    /// Time spent serializing results into the response IR, summed across all queries. See
+    /// [`PhaseTimings::serialization`].
+    pub serialization_micros_total: u64,
+}
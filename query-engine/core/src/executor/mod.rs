@@ -6,9 +6,11 @@
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
+mod metrics;
 mod pipeline;
 
 pub use interpreting_executor::*;
+pub use metrics::{EngineMetrics, EngineMetricsSnapshot, PhaseTimings};
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
@@ -28,4 +30,7 @@ pub trait QueryExecutor {
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
     fn primary_connector(&self) -> &(dyn Connector + Send + Sync);
+
+    /// Counters and latency totals for the queries executed so far. See [`EngineMetrics`].
+    fn metrics(&self) -> EngineMetricsSnapshot;
 }
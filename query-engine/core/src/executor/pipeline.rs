@@ -1,4 +1,6 @@
-use crate::{Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use super::PhaseTimings;
+use crate::{query_graph::to_graphviz, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use std::time::Instant;
 
 #[derive(Debug)]
 pub struct QueryPipeline<'conn, 'tx> {
@@ -16,12 +18,34 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
         }
     }
 
-    pub async fn execute(self) -> crate::Result<ResponseData> {
+    /// Runs the graph to completion, timing the connector execution and serialization phases.
+    /// `timings.graph_building` is left at zero: the graph is already built by the time it gets
+    /// here, so the caller fills that in itself.
+    pub async fn execute(self) -> crate::Result<(ResponseData, PhaseTimings)> {
         let serializer = self.serializer;
+
+        if std::env::var("PRISMA_RENDER_QUERY_GRAPHS").as_deref() == Ok("dot") {
+            trace!("{}", to_graphviz(&self.graph));
+        }
+
+        let execution_started_at = Instant::now();
         let expr = Expressionista::translate(self.graph)?;
         let result = self.interpreter.interpret(expr, Env::default(), 0).await;
+        let connector_execution = execution_started_at.elapsed();
 
         trace!("{}", self.interpreter.log_output());
-        serializer.serialize(result?)
+
+        let serialization_started_at = Instant::now();
+        let response = serializer.serialize(result?)?;
+        let serialization = serialization_started_at.elapsed();
+
+        Ok((
+            response,
+            PhaseTimings {
+                graph_building: Default::default(),
+                connector_execution,
+                serialization,
+            },
+        ))
     }
 }
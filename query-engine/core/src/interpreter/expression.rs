@@ -1,5 +1,6 @@
 use super::{Env, ExpressionResult, InterpretationResult};
 use crate::Query;
+use prisma_models::PrismaValue;
 
 pub enum Expression {
     Sequence {
@@ -33,9 +34,23 @@ pub enum Expression {
         else_: Vec<Expression>,
     },
 
+    /// Evaluates `func` once, then runs the `Sequence` in `cases` whose `PrismaValue` matches,
+    /// falling back to `default` if none match (or doing nothing if there's no default arm).
+    Switch {
+        func: Box<dyn FnOnce() -> PrismaValue + Send + Sync + 'static>,
+        cases: Vec<(PrismaValue, Vec<Expression>)>,
+        default: Vec<Expression>,
+    },
+
     Return {
         result: Box<ExpressionResult>,
     },
+
+    /// Translated from `Flow::Transaction`. Runs `body` like a `Sequence` today - see that
+    /// node's doc comment for why this doesn't (yet) issue a real savepoint around it.
+    Transaction {
+        body: Vec<Expression>,
+    },
 }
 
 pub struct Binding {
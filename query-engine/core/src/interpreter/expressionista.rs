@@ -2,7 +2,7 @@ use super::{
     expression::*, ComputationResult, DiffResult, Env, ExpressionResult, InterpretationResult, InterpreterError,
 };
 use crate::{query_graph::*, Query};
-use prisma_models::RecordProjection;
+use prisma_models::{PrismaValue, RecordProjection};
 use std::{collections::VecDeque, convert::TryInto};
 
 pub struct Expressionista;
@@ -15,6 +15,14 @@ struct IfNodeAcc {
     other: Vec<(EdgeRef, NodeRef)>,
 }
 
+/// Helper accumulator struct.
+#[derive(Default)]
+struct SwitchNodeAcc {
+    cases: Vec<(PrismaValue, (EdgeRef, NodeRef))>,
+    default: Option<(EdgeRef, NodeRef)>,
+    other: Vec<(EdgeRef, NodeRef)>,
+}
+
 impl Expressionista {
     #[tracing::instrument(skip(graph))]
     pub fn translate(mut graph: QueryGraph) -> InterpretationResult<Expression> {
@@ -216,7 +224,9 @@ impl Expressionista {
 
         match graph.node_content(node).unwrap() {
             Node::Flow(Flow::If(_)) => Self::translate_if_node(graph, node, parent_edges),
+            Node::Flow(Flow::Switch(_)) => Self::translate_switch_node(graph, node, parent_edges),
             Node::Flow(Flow::Return(_)) => Self::translate_return_node(graph, node, parent_edges),
+            Node::Flow(Flow::Transaction) => Self::translate_transaction_node(graph, node, parent_edges),
             _ => unreachable!(),
         }
     }
@@ -288,6 +298,77 @@ impl Expressionista {
         Self::transform_node(graph, parent_edges, node, into_expr)
     }
 
+    #[tracing::instrument(skip(graph, node, parent_edges))]
+    fn translate_switch_node(
+        graph: &mut QueryGraph,
+        node: &NodeRef,
+        parent_edges: Vec<EdgeRef>,
+    ) -> InterpretationResult<Expression> {
+        let child_pairs = graph.direct_child_pairs(node);
+
+        let switch_node_info = child_pairs
+            .into_iter()
+            .fold(SwitchNodeAcc::default(), |mut acc, (edge, node)| {
+                match graph.edge_content(&edge) {
+                    Some(QueryGraphDependency::Case(value)) => acc.cases.push((value.clone(), (edge, node))),
+                    Some(QueryGraphDependency::Default) => acc.default = Some((edge, node)),
+                    _ => acc.other.push((edge, node)),
+                };
+
+                acc
+            });
+
+        // Build expressions for every case arm plus the (optional) default arm.
+        let cases = switch_node_info
+            .cases
+            .into_iter()
+            .map(|(value, (_, node))| {
+                let expr = Self::build_expression(graph, &node, graph.incoming_edges(&node))?;
+                Ok((value, vec![expr]))
+            })
+            .collect::<InterpretationResult<Vec<_>>>()?;
+
+        let default = switch_node_info
+            .default
+            .into_iter()
+            .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+            .collect::<InterpretationResult<Vec<_>>>()?;
+
+        let child_expressions = Self::process_children(graph, switch_node_info.other)?;
+
+        let node_id = node.id();
+        let node = graph.pluck_node(node);
+        let into_expr = Box::new(move |node: Node| {
+            let flow: Flow = node.try_into()?;
+
+            if let Flow::Switch(value_fn) = flow {
+                let switch_expr = Expression::Switch {
+                    func: value_fn,
+                    cases,
+                    default,
+                };
+
+                let expr = if !child_expressions.is_empty() {
+                    Expression::Let {
+                        bindings: vec![Binding {
+                            name: node_id,
+                            expr: switch_expr,
+                        }],
+                        expressions: child_expressions,
+                    }
+                } else {
+                    switch_expr
+                };
+
+                Ok(expr)
+            } else {
+                unreachable!()
+            }
+        });
+
+        Self::transform_node(graph, parent_edges, node, into_expr)
+    }
+
     #[tracing::instrument(skip(graph, node, parent_edges))]
     fn translate_return_node(
         graph: &mut QueryGraph,
@@ -313,6 +394,20 @@ impl Expressionista {
         Self::transform_node(graph, parent_edges, node, into_expr)
     }
 
+    #[tracing::instrument(skip(graph, node, parent_edges))]
+    fn translate_transaction_node(
+        graph: &mut QueryGraph,
+        node: &NodeRef,
+        parent_edges: Vec<EdgeRef>,
+    ) -> InterpretationResult<Expression> {
+        let child_pairs = graph.direct_child_pairs(node);
+        let body = Self::process_children(graph, child_pairs)?;
+
+        let into_expr = Box::new(move |_node: Node| Ok(Expression::Transaction { body }));
+
+        Self::transform_node(graph, parent_edges, Node::Flow(Flow::Transaction), into_expr)
+    }
+
     /// Runs transformer functions (e.g. `ParentIdsFn`) via `Expression::Func` if necessary, or if none present,
     /// builds an expression directly. `into_expr` does the final expression building based on the node coming in.
     #[tracing::instrument(skip(graph, parent_edges, node, into_expr))]
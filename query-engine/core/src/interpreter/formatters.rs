@@ -45,6 +45,12 @@ pub fn format_expression(expr: &Expression, indent: usize) -> String {
             then: _,
             else_: _,
         } => add_indent(indent, "if (Fn env)"),
+        Expression::Switch {
+            func: _,
+            cases,
+            default: _,
+        } => add_indent(indent, format!("switch (Fn env) [{} cases]", cases.len())),
+        Expression::Transaction { body } => add_indent(indent, format!("transaction [{} expressions]", body.len())),
         Expression::Return { result } => add_indent(indent, format!("Return {:?}", result)),
     }
 }
@@ -1,4 +1,5 @@
 use std::fmt;
+use std::time::{Duration, Instant};
 
 use super::{
     expression::*,
@@ -10,8 +11,20 @@ use connector::ConnectionLike;
 use crossbeam_queue::SegQueue;
 use futures::future::BoxFuture;
 use im::HashMap;
+use once_cell::sync::Lazy;
 use prisma_models::prelude::*;
 
+/// The `slow_query_threshold_ms` setting, read once from the environment.
+/// Queries whose execution time exceeds this threshold emit a dedicated
+/// `slow_query` tracing event so operators can alert on them without turning
+/// on full query logging.
+static SLOW_QUERY_THRESHOLD: Lazy<Option<Duration>> = Lazy::new(|| {
+    std::env::var("QE_SLOW_QUERY_THRESHOLD_MS")
+        .ok()
+        .and_then(|threshold| threshold.parse::<u64>().ok())
+        .map(Duration::from_millis)
+});
+
 #[derive(Debug, Clone)]
 pub enum ExpressionResult {
     Query(QueryResult),
@@ -213,14 +226,22 @@ where
                 match *query {
                     Query::Read(read) => {
                         self.log_line(level, || format!("READ {}", read));
-                        Ok(read::execute(&self.conn, read, None)
-                            .await
-                            .map(ExpressionResult::Query)?)
+                        let description = read.to_string();
+                        let started_at = Instant::now();
+                        let result = read::execute(&self.conn, read, None).await;
+                        self.log_slow_query(&description, started_at);
+
+                        Ok(result.map(ExpressionResult::Query)?)
                     }
 
                     Query::Write(write) => {
                         self.log_line(level, || format!("WRITE {}", write));
-                        Ok(write::execute(&self.conn, write).await.map(ExpressionResult::Query)?)
+                        let description = write.to_string();
+                        let started_at = Instant::now();
+                        let result = write::execute(&self.conn, write).await;
+                        self.log_slow_query(&description, started_at);
+
+                        Ok(result.map(ExpressionResult::Query)?)
                     }
                 }
             }),
@@ -256,10 +277,28 @@ where
                 }
             }),
 
+            Expression::Switch { func, cases, default } => Box::pin(async move {
+                self.log_line(level, || "SWITCH");
+
+                let value = func();
+                let seq = cases
+                    .into_iter()
+                    .find(|(case_value, _)| case_value == &value)
+                    .map(|(_, seq)| seq)
+                    .unwrap_or(default);
+
+                self.interpret(Expression::Sequence { seq }, env, level + 1).await
+            }),
+
             Expression::Return { result } => Box::pin(async move {
                 self.log_line(level, || "RETURN");
                 Ok(*result)
             }),
+
+            Expression::Transaction { body } => Box::pin(async move {
+                self.log_line(level, || "TRANSACTION");
+                self.interpret(Expression::Sequence { seq: body }, env, level + 1).await
+            }),
         }
     }
 
@@ -274,6 +313,24 @@ where
         output
     }
 
+    /// Emits a `slow_query` tracing event when the elapsed time since
+    /// `started_at` exceeds `QE_SLOW_QUERY_THRESHOLD_MS`.
+    fn log_slow_query(&self, description: &str, started_at: Instant) {
+        if let Some(threshold) = *SLOW_QUERY_THRESHOLD {
+            let elapsed = started_at.elapsed();
+
+            if elapsed >= threshold {
+                tracing::warn!(
+                    slow_query = true,
+                    query = description,
+                    duration_ms = elapsed.as_millis() as u64,
+                    threshold_ms = threshold.as_millis() as u64,
+                    "Query exceeded the slow query threshold"
+                );
+            }
+        }
+    }
+
     fn log_line<F, S>(&self, level: usize, f: F)
     where
         S: AsRef<str>,
@@ -82,6 +82,13 @@ impl InMemoryRecordProcessor {
         records.records.first().map(|x| x.parent_id.is_some()).unwrap_or(false)
     }
 
+    /// Distinct is applied here, identically for every connector, rather than pushed down to the
+    /// database: we always select the record's unique identifiers alongside the distinct fields
+    /// (see `collect_selected_fields`), which would make every row distinct by definition if
+    /// evaluated in the database. This also means distinct already works uniformly on any
+    /// addressable field of the model - including ones backed by a nested/embedded document in
+    /// the Mongo connector - since it operates on the already-materialized `PrismaValue`s rather
+    /// than on the underlying storage shape.
     fn apply_distinct(&self, mut records: ManyRecords) -> ManyRecords {
         let field_names = &records.field_names;
 
@@ -21,6 +21,8 @@ pub async fn execute<'a, 'b>(
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
         WriteQuery::ExecuteRaw(rq) => execute_raw(tx, rq.query, rq.parameters).await,
         WriteQuery::QueryRaw(rq) => query_raw(tx, rq.query, rq.parameters).await,
+        WriteQuery::RunCommandRaw(rq) => run_command_raw(tx, rq.command).await,
+        WriteQuery::AggregateRaw(rq) => aggregate_raw(tx, rq.pipeline, rq.options).await,
     }
 }
 
@@ -44,6 +46,20 @@ async fn execute_raw<'a, 'b>(
     Ok(QueryResult::Json(num))
 }
 
+async fn run_command_raw<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, command: String) -> InterpretationResult<QueryResult> {
+    let res = tx.run_command_raw(command).await?;
+    Ok(QueryResult::Json(res))
+}
+
+async fn aggregate_raw<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    pipeline: Vec<String>,
+    options: Option<String>,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.aggregate_raw(pipeline, options).await?;
+    Ok(QueryResult::Json(res))
+}
+
 async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) -> InterpretationResult<QueryResult> {
     let res = tx.create_record(&q.model, q.args).await?;
 
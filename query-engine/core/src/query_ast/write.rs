@@ -16,6 +16,8 @@ pub enum WriteQuery {
     DisconnectRecords(DisconnectRecords),
     ExecuteRaw(RawQuery),
     QueryRaw(RawQuery),
+    RunCommandRaw(RunCommandRaw),
+    AggregateRaw(AggregateRaw),
 }
 
 impl WriteQuery {
@@ -127,6 +129,14 @@ pub struct CreateRecord {
     pub args: WriteArgs,
 }
 
+/// Note: this only ever resolves to a `QueryResult::Count`, unlike `CreateRecord`'s
+/// `QueryResult::Id`. Client-side `@default(uuid())`/`@default(cuid())` generation for `args`
+/// already happens the same way it does for a single create (see `create_many_object_type`'s doc
+/// comment), so the generated ids are known here - but surfacing them would mean growing
+/// `connector_interface::WriteOperations::create_records` (and both its SQL and Mongo
+/// implementations, and the `createMany` GraphQL output type) from returning just the affected
+/// count into returning the created records' projections, which is a real API shape change, not
+/// something to bolt on to this struct alone.
 #[derive(Debug, Clone)]
 pub struct CreateManyRecords {
     pub model: ModelRef,
@@ -196,6 +206,20 @@ pub struct RawQuery {
     pub parameters: Vec<PrismaValue>,
 }
 
+/// A raw MongoDB command document, JSON-encoded, to be run as-is via `db.runCommand`.
+#[derive(Debug, Clone)]
+pub struct RunCommandRaw {
+    pub command: String,
+}
+
+/// A raw MongoDB aggregation pipeline, with each stage JSON-encoded, plus optional
+/// JSON-encoded extra command options (e.g. `allowDiskUse`).
+#[derive(Debug, Clone)]
+pub struct AggregateRaw {
+    pub pipeline: Vec<String>,
+    pub options: Option<String>,
+}
+
 impl FilteredQuery for UpdateRecord {
     fn get_filter(&mut self) -> Option<&mut Filter> {
         Some(&mut self.record_filter.filter)
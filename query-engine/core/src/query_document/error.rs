@@ -50,6 +50,22 @@ impl fmt::Display for QueryPath {
     }
 }
 
+impl QueryPath {
+    /// Renders the path as a JSON pointer (RFC 6901) into the request body, e.g. `/data/where/id`.
+    /// `~` and `/` in individual segments are escaped as `~0` and `~1`, per the spec, so a field or
+    /// argument name containing either of those characters still round-trips to a valid pointer.
+    pub fn json_pointer(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.replace('~', "~0").replace('/', "~1"))
+            .fold(String::new(), |mut pointer, segment| {
+                pointer.push('/');
+                pointer.push_str(&segment);
+                pointer
+            })
+    }
+}
+
 #[derive(Debug)]
 pub enum QueryParserErrorKind {
     AssertionError(String),
@@ -62,6 +78,17 @@ pub enum QueryParserErrorKind {
     InputUnionParseError { parsing_errors: Vec<QueryParserError> },
 }
 
+impl QueryParserErrorKind {
+    /// The type the query schema expected at this path, if the error kind carries one. Used to
+    /// surface `expectedType` in structured, client-generator-facing validation errors.
+    pub fn expected_type(&self) -> Option<String> {
+        match self {
+            Self::ValueTypeMismatchError { want, .. } => Some(want.type_name()),
+            _ => None,
+        }
+    }
+}
+
 impl Display for QueryParserErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -12,6 +12,65 @@ pub fn format(graph: &QueryGraph) -> String {
     )
 }
 
+/// Renders the graph in the DOT language (https://graphviz.org/doc/info/lang.html), for piping
+/// into `dot -Tsvg` or pasting into an online Graphviz viewer. Meant for eyeballing the shape of
+/// complex nested writes during development, where the indented text `format()` above produces
+/// is too linear to see the fan-out/fan-in between nodes at a glance.
+pub fn to_graphviz(graph: &QueryGraph) -> String {
+    let mut nodes = String::new();
+    let mut edges = String::new();
+    let mut seen_nodes = Vec::new();
+
+    collect_graphviz(graph, graph.root_nodes(), &mut seen_nodes, &mut nodes, &mut edges);
+
+    format!("digraph {{\n{}\n{}}}", nodes, edges)
+}
+
+fn collect_graphviz(
+    graph: &QueryGraph,
+    nodes: Vec<NodeRef>,
+    seen_nodes: &mut Vec<NodeRef>,
+    rendered_nodes: &mut String,
+    rendered_edges: &mut String,
+) {
+    for node in nodes {
+        if seen_nodes.contains(&node) {
+            continue;
+        }
+
+        seen_nodes.push(node);
+
+        rendered_nodes.push_str(&format!(
+            "  \"{}\" [label=\"{}\"];\n",
+            node.id(),
+            escape_dot_label(&graph.node_content(&node).unwrap().to_string())
+        ));
+
+        let children: Vec<NodeRef> = graph
+            .outgoing_edges(&node)
+            .iter()
+            .map(|child_edge| {
+                let child_node = graph.edge_target(child_edge);
+
+                rendered_edges.push_str(&format!(
+                    "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                    node.id(),
+                    child_node.id(),
+                    escape_dot_label(&graph.edge_content(child_edge).unwrap().to_string())
+                ));
+
+                child_node
+            })
+            .collect();
+
+        collect_graphviz(graph, children, seen_nodes, rendered_nodes, rendered_edges);
+    }
+}
+
+fn escape_dot_label(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
 fn stringify_nodes(graph: &QueryGraph, nodes: Vec<NodeRef>, seen_nodes: &mut Vec<NodeRef>) -> Vec<String> {
     let mut rendered_nodes = vec![];
 
@@ -57,6 +116,8 @@ impl Display for Flow {
         match self {
             Self::If(_) => write!(f, "(If (condition func)"),
             Self::Return(_) => write!(f, "(return projections)"),
+            Self::Switch(_) => write!(f, "(Switch (value func)"),
+            Self::Transaction => write!(f, "(Transaction)"),
         }
     }
 }
@@ -105,6 +166,8 @@ impl Display for QueryGraphDependency {
             ),
             Self::Then => write!(f, "Then"),
             Self::Else => write!(f, "Else"),
+            Self::Case(value) => write!(f, "Case ({:?})", value),
+            Self::Default => write!(f, "Default"),
         }
     }
 }
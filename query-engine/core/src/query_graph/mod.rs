@@ -13,8 +13,12 @@ use crate::{
 use connector::{IdFilter, QueryArguments};
 use guard::*;
 use petgraph::{graph::*, visit::EdgeRef as PEdgeRef, *};
-use prisma_models::{ModelProjection, ModelRef, RecordProjection};
-use std::{borrow::Borrow, collections::HashSet, fmt};
+use prisma_models::{ModelProjection, ModelRef, PrismaValue, RecordProjection};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
 pub type QueryGraphResult<T> = std::result::Result<T, QueryGraphError>;
 
@@ -53,6 +57,36 @@ pub enum Flow {
 
     /// Returns a fixed set of record projections.
     Return(Option<Vec<RecordProjection>>),
+
+    /// Expresses a multi-branch control flow in the graph, keyed on a computed `PrismaValue`.
+    /// Possible outgoing edges are `Case(value)`, at most one per distinct value, plus at most
+    /// one `Default` edge for when no case matches. Composes `If`'s then/else pair into N
+    /// labeled branches, so graphs that would otherwise need nested `If`s to express something
+    /// like "connect, or create, or update, depending on which of these values is set" can use
+    /// a single node instead.
+    ///
+    /// Note: `swap_marked` and `normalize_if_nodes` currently special-case `Flow::If`'s `Then`
+    /// / `Else` edges only, for the node-splitting exceptions they need during graph finalization.
+    /// Nothing in the existing nested write builders constructs a `Switch` node yet, so those two
+    /// haven't needed the equivalent `Case` / `Default` handling; a builder that starts emitting
+    /// `Switch` nodes in a position where nodes get split (e.g. inside upsert) will need that
+    /// exception extended first.
+    Switch(Box<dyn FnOnce() -> PrismaValue + Send + Sync + 'static>),
+
+    /// Marks a subgraph that is meant to become an independently rollback-able unit - a
+    /// savepoint - inside the surrounding write's transaction, instead of participating in its
+    /// current all-or-nothing interpretation. Children are attached the same way as any other
+    /// node (plain `ExecutionOrder` edges), executed in order.
+    ///
+    /// This is graph-level vocabulary only for now: `connector_interface::Transaction` has no
+    /// `create_savepoint` / `rollback_to_savepoint` operation, so `Expressionista` translates this
+    /// node into a plain `Expression::Transaction`, which the interpreter runs exactly like a
+    /// `Sequence` (see its doc comment). A failure under a `Transaction` node still propagates and
+    /// aborts the whole outer transaction, same as before this node type existed. Giving it real
+    /// partial-rollback semantics needs that connector-level savepoint primitive added to both the
+    /// SQL and Mongo connector implementations first (and a decision on what a Mongo "savepoint"
+    /// even means), which is out of reach here.
+    Transaction,
 }
 
 impl Flow {
@@ -136,6 +170,15 @@ pub enum QueryGraphDependency {
 
     /// Only valid in the context of a `If` control flow node.
     Else,
+
+    /// Only valid in the context of a `Switch` control flow node. Taken when the switch's
+    /// computed value equals `PrismaValue`. At most one `Case` edge per distinct value is
+    /// allowed on a given `Switch` node.
+    Case(PrismaValue),
+
+    /// Only valid in the context of a `Switch` control flow node. Taken when none of the
+    /// `Case` edges match the switch's computed value. At most one per `Switch` node.
+    Default,
 }
 
 /// A graph representing an abstract view of queries and their execution dependencies.
@@ -221,12 +264,89 @@ impl QueryGraph {
             self.swap_marked()?;
             self.insert_reloads()?;
             self.normalize_if_nodes()?;
+            self.deduplicate_read_nodes()?;
             self.finalized = true;
         }
 
         Ok(())
     }
 
+    /// Detects structurally identical, dependency-free `Read` nodes (same model, filter/query
+    /// arguments and selection) and collapses each group down to a single node, redirecting all
+    /// of the duplicates' outgoing edges onto the survivor. This targets the common pattern of
+    /// nested write expansion issuing the same lookup (e.g. "does this child exist") more than
+    /// once for different branches of the same write.
+    ///
+    /// Deliberately conservative: only considers nodes with **no incoming edges** (a node fed by
+    /// a parent's result isn't comparable until it has actually run, so it's left alone) that
+    /// aren't a result node of the graph (merging one away would change what gets returned to the
+    /// caller) and that don't carry their own `nested` sub-selections (recursively comparing and
+    /// merging those isn't implemented here). Widening this to dependent nodes would need keying
+    /// on the *shape* of the parent dependency instead of just static content, which is out of
+    /// scope for now.
+    #[tracing::instrument(skip(self))]
+    fn deduplicate_read_nodes(&mut self) -> QueryGraphResult<()> {
+        let mut groups: HashMap<String, Vec<NodeRef>> = HashMap::new();
+
+        for node_ix in self.graph.node_indices() {
+            let node = NodeRef { node_ix };
+
+            if self.is_result_node(&node) || !self.incoming_edges(&node).is_empty() {
+                continue;
+            }
+
+            if let Some(key) = self.read_node_dedup_key(&node) {
+                groups.entry(key).or_insert_with(Vec::new).push(node);
+            }
+        }
+
+        for nodes in groups.into_values() {
+            let mut nodes = nodes.into_iter();
+            let survivor = match nodes.next() {
+                Some(node) => node,
+                None => continue,
+            };
+
+            for duplicate in nodes {
+                trace!(
+                    "[Graph][Dedup] Merging duplicate read node {} into {}",
+                    duplicate.id(),
+                    survivor.id()
+                );
+
+                for edge in self.outgoing_edges(&duplicate) {
+                    let target = self.edge_target(&edge);
+                    let content = self.pluck_edge(&edge);
+
+                    self.create_edge(&survivor, &target, content)?;
+                    self.remove_edge(edge);
+                }
+
+                *self.graph.node_weight_mut(duplicate.node_ix).unwrap() = Guard::new(Node::Empty);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a key that's equal for two read nodes iff they're safe to merge - i.e. issuing
+    /// either one would produce the same result. Returns `None` for anything that isn't a
+    /// mergeable read node (writes, flow control, computations, or a read with `nested`
+    /// sub-selections).
+    fn read_node_dedup_key(&self, node: &NodeRef) -> Option<String> {
+        match self.node_content(node)? {
+            Node::Query(Query::Read(ReadQuery::RecordQuery(rq))) if rq.nested.is_empty() => Some(format!(
+                "record:{}:{:?}:{:?}:{:?}",
+                rq.model.name, rq.filter, rq.selected_fields, rq.aggregation_selections
+            )),
+            Node::Query(Query::Read(ReadQuery::ManyRecordsQuery(rq))) if rq.nested.is_empty() => Some(format!(
+                "many:{}:{:?}:{:?}:{:?}",
+                rq.model.name, rq.args, rq.selected_fields, rq.aggregation_selections
+            )),
+            _ => None,
+        }
+    }
+
     /// Returns a NodeRef to the result node that occurs in the subtree, if it exists.
     /// Returns None if no such node is found.
     pub fn find_result_node(&self, starting_node: &NodeRef) -> Option<NodeRef> {
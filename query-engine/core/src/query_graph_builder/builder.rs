@@ -6,6 +6,16 @@ use prisma_value::PrismaValue;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
 // the query_document module, possibly already as part of the parser.
+//
+// Note on automatic tenant-scoping: injecting a tenant filter here, once per operation, was
+// looked at as a way to prevent cross-tenant data access by construction. It doesn't work as a
+// single choke point though: `ExecuteRaw`/`QueryRaw` each become a single raw-query node (see
+// `dispatch_build` below) whose SQL is handed to the connector as written, so a filter injected only in this
+// builder would silently not apply to raw queries - which is worse than no automatic filter at
+// all, since callers would reasonably assume every query path is covered. Doing this properly
+// needs the tenant value threaded into (and combined with) the filter at every read/write builder
+// and into the raw query path, which is a schema (new `@@` attribute) and connector-interface
+// change, not something that fits in the query graph builder alone.
 pub struct QueryGraphBuilder {
     pub query_schema: QuerySchemaRef,
 }
@@ -55,7 +65,15 @@ impl QueryGraphBuilder {
     pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryGraph, IrSerializer)> {
         match operation {
             Operation::Read(selection) => self.build_internal(selection, &self.query_schema.query()),
-            Operation::Write(selection) => self.build_internal(selection, &self.query_schema.mutation()),
+            Operation::Write(selection) => {
+                if self.query_schema.is_read_only() {
+                    return Err(QueryGraphBuilderError::WriteOperationsNotAllowed {
+                        query: selection.name().to_string(),
+                    });
+                }
+
+                self.build_internal(selection, &self.query_schema.mutation())
+            }
         }
     }
 
@@ -104,6 +122,8 @@ impl QueryGraphBuilder {
             (QueryTag::DeleteMany, Some(m)) => QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field)),
             (QueryTag::ExecuteRaw, _) => QueryGraph::root(|g| write::execute_raw(g, parsed_field)),
             (QueryTag::QueryRaw, _) => QueryGraph::root(|g| write::query_raw(g, parsed_field)),
+            (QueryTag::RunCommandRaw, _) => QueryGraph::root(|g| write::run_command_raw(g, parsed_field)),
+            (QueryTag::AggregateRaw, _) => QueryGraph::root(|g| write::aggregate_raw(g, parsed_field)),
             _ => unreachable!("Query builder dispatching failed."),
         }?;
 
@@ -40,6 +40,11 @@ pub enum QueryGraphBuilderError {
     RecordNotFound(String),
 
     QueryGraphError(QueryGraphError),
+
+    /// The engine is running in read-only mode and the requested operation is a write.
+    WriteOperationsNotAllowed {
+        query: String,
+    },
 }
 
 #[derive(Debug)]
@@ -232,6 +232,10 @@ fn parse_internal_scalar(
         filters::STARTS_WITH => Ok(vec![field.starts_with(as_prisma_value(input)?)]),
         filters::ENDS_WITH => Ok(vec![field.ends_with(as_prisma_value(input)?)]),
 
+        // `search` has no negated counterpart in the API, so unlike the filters above it ignores
+        // `reverse`.
+        filters::SEARCH => Ok(vec![field.search(as_prisma_value(input)?)]),
+
         filters::LOWER_THAN if reverse => Ok(vec![field.greater_than_or_equals(as_prisma_value(input)?)]),
         filters::GREATER_THAN if reverse => Ok(vec![field.less_than_or_equals(as_prisma_value(input)?)]),
         filters::LOWER_THAN_OR_EQUAL if reverse => Ok(vec![field.greater_than(as_prisma_value(input)?)]),
@@ -57,6 +57,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         }
                     }
 
+                    args::INDEX_HINT => Ok(QueryArguments {
+                        index_hint: extract_index_hint(arg.value, model)?,
+                        ..res
+                    }),
+
                     _ => Ok(res),
                 }
             } else {
@@ -203,6 +208,37 @@ fn extract_skip(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<i64>>
     }
 }
 
+/// Validates a `indexHint` argument against the model it was issued for: the model must have
+/// opted in via `@@allowIndexHints`, and the hinted name must resolve to one of its indexes.
+fn extract_index_hint(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Option<String>> {
+    let hint: Option<String> = value.try_into()?;
+    let hint = match hint {
+        Some(hint) => hint,
+        None => return Ok(None),
+    };
+
+    if !model.allow_index_hints() {
+        return Err(QueryGraphBuilderError::AssertionError(format!(
+            "Model {} does not allow index hints. Annotate it with `@@allowIndexHints` to use the `indexHint` argument.",
+            model.name
+        )));
+    }
+
+    let known = model
+        .indexes()
+        .iter()
+        .any(|index| index.name.as_deref() == Some(hint.as_str()) || index.db_name.as_deref() == Some(hint.as_str()));
+
+    if !known {
+        return Err(QueryGraphBuilderError::AssertionError(format!(
+            "Unknown index `{}` on model {}. `indexHint` must reference the name of one of its indexes.",
+            hint, model.name
+        )));
+    }
+
+    Ok(Some(hint))
+}
+
 fn extract_cursor(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Option<RecordProjection>> {
     let input_map: ParsedInputMap = value.try_into()?;
     let mut pairs = vec![];
@@ -267,6 +303,29 @@ fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArgume
         });
 
         args.order_by.extend(order_bys);
+    } else if args.cursor.is_some() {
+        // A user-provided orderBy isn't necessarily unique, which means the cursor comparison row can match
+        // more than one record and paging can skip or repeat rows. Append the primary identifier fields not
+        // already part of the orderBy as ascending tiebreakers on the root model, preserving the order the
+        // user asked for while guaranteeing a stable, total order to page over.
+        let primary_identifier = model.primary_identifier();
+        let missing_pk_order_bys = primary_identifier.into_iter().filter_map(|f| match f {
+            Field::Scalar(f) => {
+                let already_ordered = args
+                    .order_by
+                    .iter()
+                    .any(|order_by| order_by.path.is_empty() && order_by.field.name == f.name);
+
+                if already_ordered {
+                    None
+                } else {
+                    Some(f.into())
+                }
+            }
+            _ => unreachable!(),
+        });
+
+        args.order_by.extend(missing_pk_order_bys);
     }
 
     args
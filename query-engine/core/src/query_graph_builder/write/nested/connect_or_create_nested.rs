@@ -3,9 +3,11 @@ use crate::{
     constants::args,
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
+    write::write_args_parser::WriteArgsParser,
     ParsedInputMap, ParsedInputValue,
 };
 use connector::{Filter, IdFilter};
+use itertools::Itertools;
 use prisma_models::{ModelRef, RelationFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
@@ -25,7 +27,7 @@ pub fn nested_connect_or_create(
     let values = utils::coerce_vec(value);
 
     if relation.is_many_to_many() {
-        handle_many_to_many(graph, parent_node, parent_relation_field, values, child_model)
+        handle_many_to_many_bulk(graph, parent_node, parent_relation_field, values, child_model)
     } else if relation.is_one_to_many() {
         handle_one_to_many(graph, parent_node, parent_relation_field, values, child_model)
     } else {
@@ -34,47 +36,49 @@ pub fn nested_connect_or_create(
 }
 
 /// Handles a nested connect-or-create many-to-many relation case.
+///
+/// Unlike the one-to-many and one-to-one cases below, a many-to-many `connectOrCreate` doesn't
+/// need to inject anything into the child's own create arguments (the relation lives in a
+/// separate join table, not a foreign key column), and the "connect" step is naturally
+/// idempotent (`create_relation_table_records` already inserts with `ON CONFLICT DO NOTHING`).
+/// That means a whole list of `connectOrCreate` items can be resolved without a read/if/create
+/// subgraph per item: create every missing child in one bulk `INSERT ... ON CONFLICT DO NOTHING`,
+/// then read back the (pre-existing and freshly created) children in one query and connect all of
+/// them in one bulk join-table insert. Two write statements for the whole batch, regardless of
+/// how many items it contains.
 /// ```text
 ///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ┐
-/// ┌──      Parent       ────────────────────────┐
-/// │  └ ─ ─ ─ ─ ─ ─ ─ ─ ┘         │              │
-/// │           │                                 │
-/// │           │                  │              │
-/// │           │                                 │
-/// │           ▼                  ▼              │
-/// │  ┌─────────────────┐  ┌ ─ ─ ─ ─ ─ ─         │
-/// ├──│   Read Child    │      Result   │        │
-/// │  └─────────────────┘  └ ─ ─ ─ ─ ─ ─         │
-/// │           │                                 │
-/// │           │                                 │
-/// │           │                                 │
-/// │           ▼                                 │
-/// │  ┌─────────────────┐                        │
-/// │  │   If (exists)   │────────────┐           │
-/// │  └─────────────────┘            │           │
-/// │           │                     │           │
-/// │           │                     │           │
-/// │           │                     │           │
-/// │           ▼                     ▼           │
-/// │  ┌─────────────────┐   ┌─────────────────┐  │
-/// └─▶│     Connect     │   │  Create Child   │  │
-///    └─────────────────┘   └─────────────────┘  │
-///                                   │           │
-///                                   │           │
-///                                   │           │
-///                                   ▼           │
-///                          ┌─────────────────┐  │
-///                          │     Connect     │◀─┘
-///                          └─────────────────┘
+/// ┌──      Parent       ──────────────┐
+/// │  └ ─ ─ ─ ─ ─ ─ ─ ─ ┘              │
+/// │           │                       │
+/// │           ▼                       │
+/// │  ┌─────────────────┐              │
+/// │  │  Create Missing │              │
+/// │  │  Children (bulk)│              │
+/// │  └─────────────────┘              │
+/// │           │                       │
+/// │           ▼                       │
+/// │  ┌─────────────────┐  ┌ ─ ─ ─ ─ ─ ┐
+/// ├──│   Read Children │      Result
+/// │  └─────────────────┘  └ ─ ─ ─ ─ ─ ┘
+/// │           │                       │
+/// │           ▼                       │
+/// │  ┌─────────────────┐              │
+/// └─▶│  Connect (bulk) │◀─────────────┘
+///    └─────────────────┘
 /// ```
 #[tracing::instrument(skip(graph, parent_node, parent_relation_field, values, child_model))]
-fn handle_many_to_many(
+fn handle_many_to_many_bulk(
     graph: &mut QueryGraph,
     parent_node: NodeRef,
     parent_relation_field: &RelationFieldRef,
     values: Vec<ParsedInputValue>,
     child_model: &ModelRef,
 ) -> QueryGraphBuilderResult<()> {
+    let expected_connects = values.len();
+    let mut filters = Vec::with_capacity(values.len());
+    let mut args = Vec::with_capacity(values.len());
+
     for value in values {
         let mut value: ParsedInputMap = value.try_into()?;
 
@@ -84,41 +88,36 @@ fn handle_many_to_many(
         let create_arg = value.remove(args::CREATE).unwrap();
         let create_map: ParsedInputMap = create_arg.try_into()?;
 
-        let filter = extract_unique_filter(where_map, &child_model)?;
-        let read_node = graph.create_node(utils::read_ids_infallible(
-            child_model.clone(),
-            child_model.primary_identifier(),
-            filter,
-        ));
+        filters.push(extract_unique_filter(where_map, &child_model)?);
 
-        let create_node = create::create_record_node(graph, Arc::clone(child_model), create_map)?;
-        let if_node = graph.create_node(Flow::default_if());
+        let mut write_args = WriteArgsParser::from(&child_model, create_map)?.args;
+        write_args.add_datetimes(&child_model);
+        args.push(write_args);
+    }
 
-        let connect_exists_node =
-            connect::connect_records_node(graph, &parent_node, &read_node, &parent_relation_field, 1)?;
+    let filter = Filter::or(filters.into_iter().unique().collect());
 
-        let _connect_create_node =
-            connect::connect_records_node(graph, &parent_node, &create_node, &parent_relation_field, 1)?;
+    let create_node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(CreateManyRecords {
+        model: Arc::clone(child_model),
+        args,
+        skip_duplicates: true,
+    })));
 
-        graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
-        graph.create_edge(
-            &read_node,
-            &if_node,
-            QueryGraphDependency::ParentProjection(
-                child_model.primary_identifier(),
-                Box::new(|if_node, child_ids| {
-                    if let Node::Flow(Flow::If(_)) = if_node {
-                        Ok(Node::Flow(Flow::If(Box::new(move || !child_ids.is_empty()))))
-                    } else {
-                        Ok(if_node)
-                    }
-                }),
-            ),
-        )?;
+    let read_node = graph.create_node(utils::read_ids_infallible(
+        child_model.clone(),
+        child_model.primary_identifier(),
+        filter,
+    ));
 
-        graph.create_edge(&if_node, &connect_exists_node, QueryGraphDependency::Then)?;
-        graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
-    }
+    graph.create_edge(&parent_node, &create_node, QueryGraphDependency::ExecutionOrder)?;
+    graph.create_edge(&create_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
+    connect::connect_records_node(
+        graph,
+        &parent_node,
+        &read_node,
+        &parent_relation_field,
+        expected_connects,
+    )?;
 
     Ok(())
 }
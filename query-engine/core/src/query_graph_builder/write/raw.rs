@@ -37,3 +37,60 @@ fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
         parameters,
     })
 }
+
+#[tracing::instrument(skip(graph, field))]
+pub fn run_command_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
+    let raw_query = Query::Write(WriteQuery::RunCommandRaw(run_command_raw_query(field)?));
+
+    graph.create_node(raw_query);
+    Ok(())
+}
+
+#[tracing::instrument(skip(graph, field))]
+pub fn aggregate_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
+    let raw_query = Query::Write(WriteQuery::AggregateRaw(aggregate_raw_query(field)?));
+
+    graph.create_node(raw_query);
+    Ok(())
+}
+
+fn run_command_raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RunCommandRaw> {
+    let command_arg = field.arguments.lookup(args::COMMAND).unwrap().value;
+    let command: PrismaValue = command_arg.try_into()?;
+
+    Ok(RunCommandRaw {
+        command: json_string(command),
+    })
+}
+
+fn aggregate_raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<AggregateRaw> {
+    let pipeline_arg = field.arguments.lookup(args::PIPELINE).unwrap().value;
+    let options_arg = field.arguments.lookup(args::OPTIONS);
+
+    let pipeline_value: PrismaValue = pipeline_arg.try_into()?;
+    let pipeline = pipeline_value
+        .into_list()
+        .unwrap()
+        .into_iter()
+        .map(json_string)
+        .collect();
+
+    let options = match options_arg {
+        Some(parsed) => {
+            let val: PrismaValue = parsed.value.try_into()?;
+            Some(json_string(val))
+        }
+        None => None,
+    };
+
+    Ok(AggregateRaw { pipeline, options })
+}
+
+/// Unwraps the JSON-encoded string carried by a `PrismaValue::Json`, the shape produced when
+/// parsing a `Json` GraphQL scalar argument.
+fn json_string(value: PrismaValue) -> String {
+    match value {
+        PrismaValue::Json(s) => s,
+        other => unreachable!("Expected a Json scalar value, got: {:?}", other),
+    }
+}
@@ -248,4 +248,15 @@ impl InputType {
             Self::Object(weak) => weak.into_arc().is_empty(),
         }
     }
+
+    /// A human-readable name for this type, suitable for surfacing in user-facing validation
+    /// errors (e.g. "String", "SortOrder", "UserWhereInput", "List<Int>").
+    pub fn type_name(&self) -> String {
+        match self {
+            Self::Scalar(st) => format!("{:?}", st),
+            Self::Enum(e) => e.name().to_owned(),
+            Self::List(inner) => format!("List<{}>", inner.type_name()),
+            Self::Object(obj) => obj.into_arc().identifier.name().to_owned(),
+        }
+    }
 }
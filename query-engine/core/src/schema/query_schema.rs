@@ -28,6 +28,9 @@ pub struct QuerySchema {
     output_object_types: Vec<ObjectTypeStrongRef>,
 
     pub internal_data_model: InternalDataModelRef,
+
+    /// If true, the engine rejects all write operations and raw queries at graph-construction time.
+    is_read_only: bool,
 }
 
 impl QuerySchema {
@@ -37,6 +40,7 @@ impl QuerySchema {
         input_object_types: Vec<InputObjectTypeStrongRef>,
         output_object_types: Vec<ObjectTypeStrongRef>,
         internal_data_model: InternalDataModelRef,
+        is_read_only: bool,
     ) -> Self {
         QuerySchema {
             query,
@@ -44,9 +48,14 @@ impl QuerySchema {
             input_object_types,
             output_object_types,
             internal_data_model,
+            is_read_only,
         }
     }
 
+    pub fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
     pub fn find_mutation_field<T>(&self, name: T) -> Option<OutputFieldRef>
     where
         T: Into<String>,
@@ -102,6 +111,8 @@ pub enum QueryTag {
     GroupBy,
     ExecuteRaw,
     QueryRaw,
+    RunCommandRaw,
+    AggregateRaw,
 }
 
 impl fmt::Display for QueryTag {
@@ -121,6 +132,8 @@ impl fmt::Display for QueryTag {
             Self::GroupBy => "groupBy",
             Self::ExecuteRaw => "executeRaw",
             Self::QueryRaw => "queryRaw",
+            Self::RunCommandRaw => "runCommandRaw",
+            Self::AggregateRaw => "aggregateRaw",
         };
 
         write!(f, "{}", s)
@@ -12,19 +12,27 @@
 //! but also prevents issues with memory leaks in the schema, as well as issues that when all strong
 //! arcs are dropped due to visitor operations, the schema can't be traversed anymore due to invalid references.
 use super::*;
-use std::{collections::HashMap, fmt::Debug, sync::Weak};
+use indexmap::IndexMap;
+use std::{fmt::Debug, sync::Weak};
 
 /// Cache wrapper over Arc<T>.
 /// Caches keys at most once, and errors on repeated insertion of the same key
 /// to uphold schema building consistency guarantees.
+///
+/// Backed by an `IndexMap` rather than a `HashMap` so that draining the cache
+/// (see the `Into<Vec<Arc<T>>>` impl below) yields types in the order they were
+/// first inserted, instead of the random order a `HashMap` would produce. The
+/// query schema itself doesn't depend on that order today, but a stable order
+/// here is cheap to guarantee and avoids surprising diffs for anything that
+/// starts relying on it later (e.g. schema snapshots).
 #[derive(Debug, Default)]
 pub struct TypeRefCache<T> {
-    cache: HashMap<Identifier, Arc<T>>,
+    cache: IndexMap<Identifier, Arc<T>>,
 }
 
 impl<T: Debug> TypeRefCache<T> {
     pub fn new() -> Self {
-        TypeRefCache { cache: HashMap::new() }
+        TypeRefCache { cache: IndexMap::new() }
     }
 
     // Retrieves a cached Arc if present, and hands out a weak reference to the contents.
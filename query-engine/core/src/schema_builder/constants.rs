@@ -22,10 +22,18 @@ pub mod args {
     pub const QUERY: &str = "query";
     pub const PARAMETERS: &str = "parameters";
 
+    // MongoDB raw specific args
+    pub const COMMAND: &str = "command";
+    pub const PIPELINE: &str = "pipeline";
+    pub const OPTIONS: &str = "options";
+
     pub const DISTINCT: &str = "distinct";
 
     // createMany-specific args
     pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+
+    // index hint arg, only present on models with `@@allowIndexHints`
+    pub const INDEX_HINT: &str = "indexHint";
 }
 
 pub mod operations {
@@ -64,6 +72,9 @@ pub mod filters {
     pub const GREATER_THAN_OR_EQUAL: &str = "gte";
     pub const IN: &str = "in";
 
+    // full-text search filter
+    pub const SEARCH: &str = "search";
+
     // legacy filter
     pub const NOT_IN: &str = "notIn";
 
@@ -135,6 +135,10 @@ pub(crate) fn many_records_arguments(
         );
     }
 
+    if model.allow_index_hints() && ctx.capabilities.contains(ConnectorCapability::IndexHints) {
+        args.push(input_field(args::INDEX_HINT, InputType::string(), None).optional());
+    }
+
     args
 }
 
@@ -151,6 +151,7 @@ fn full_scalar_filter_type(
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .chain(string_filters(mapped_scalar_type.clone()))
+            .chain(search_filters(ctx, mapped_scalar_type.clone()))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
@@ -334,6 +335,18 @@ fn string_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+fn search_filters(ctx: &BuilderContext, mapped_type: InputType) -> impl Iterator<Item = InputField> {
+    let fields = if ctx.has_feature(&PreviewFeature::FullTextSearch)
+        && ctx.capabilities.contains(ConnectorCapability::TextSearch)
+    {
+        vec![input_field(filters::SEARCH, mapped_type, None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
 fn json_filters(ctx: &mut BuilderContext) -> impl Iterator<Item = InputField> {
     // TODO: also add json-specific "keys" filters
     // TODO: add json_type filter
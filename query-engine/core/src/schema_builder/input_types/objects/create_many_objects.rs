@@ -52,6 +52,15 @@ pub(crate) fn create_many_object_type(
         })
         .collect();
 
+    // Note: passing `default` through here (via `with_defaults: true` below) is what already
+    // gives createMany the same engine-side `@default(uuid())`/`@default(cuid())` generation as
+    // a single create, for free: `QueryDocumentParser::parse_input_object` fills in a fresh value
+    // from `InputField::default_value` for every field missing from an individual list item, and
+    // it's called once per item in the `data` list, not once for the whole array. No SQL `DEFAULT`
+    // is involved for these two generators - see `sql_query_connector::write::create_records_nonempty`,
+    // which only reaches for `default_value()` (the SQL keyword) for fields the args don't carry a
+    // value for at all, which by this point in the pipeline id fields with a client-side generator
+    // never are.
     let fields = input_fields::scalar_input_fields(
         ctx,
         scalar_fields,
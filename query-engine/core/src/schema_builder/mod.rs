@@ -158,6 +158,7 @@ pub fn build(
     enable_raw_queries: bool,
     capabilities: ConnectorCapabilities,
     preview_features: Vec<PreviewFeature>,
+    read_only: bool,
 ) -> QuerySchema {
     let mut ctx = BuilderContext::new(
         mode,
@@ -185,6 +186,7 @@ pub fn build(
         input_objects,
         output_objects,
         ctx.internal_data_model,
+        read_only,
     )
 }
 
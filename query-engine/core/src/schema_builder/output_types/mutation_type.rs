@@ -37,6 +37,11 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
     if ctx.enable_raw_queries {
         fields.push(create_execute_raw_field());
         fields.push(create_query_raw_field());
+
+        if ctx.capabilities.contains(ConnectorCapability::MongoDbRawQueries) {
+            fields.push(create_run_command_raw_field());
+            fields.push(create_aggregate_raw_field());
+        }
     }
 
     let ident = Identifier::new("Mutation".to_owned(), PRISMA_NAMESPACE);
@@ -138,6 +143,33 @@ fn create_query_raw_field() -> OutputField {
     )
 }
 
+fn create_run_command_raw_field() -> OutputField {
+    field(
+        "runCommandRaw",
+        vec![input_field(COMMAND, InputType::json(), None)],
+        OutputType::json(),
+        Some(QueryInfo {
+            tag: QueryTag::RunCommandRaw,
+            model: None,
+        }),
+    )
+}
+
+fn create_aggregate_raw_field() -> OutputField {
+    field(
+        "aggregateRaw",
+        vec![
+            input_field(PIPELINE, InputType::json_list(), None),
+            input_field(OPTIONS, InputType::json(), None).optional(),
+        ],
+        OutputType::json(),
+        Some(QueryInfo {
+            tag: QueryTag::AggregateRaw,
+            model: None,
+        }),
+    )
+}
+
 /// Builds a create mutation field (e.g. createUser) for given model.
 fn create_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     let args = arguments::create_one_arguments(ctx, model).unwrap_or_else(Vec::new);
@@ -0,0 +1,21 @@
+use std::{ffi::CString, os::raw::c_char};
+
+/// Helper for handing an error message back across the FFI boundary the same way everywhere:
+/// write it to `*out_error` (unless the caller passed null, meaning they don't want it) and
+/// return the `1` failure status code every fallible function in this crate uses.
+pub(crate) struct CError;
+
+impl CError {
+    pub(crate) fn write(message: &str, out_error: *mut *mut c_char) -> i32 {
+        if !out_error.is_null() {
+            let message =
+                CString::new(message).unwrap_or_else(|_| CString::new("<error message contained a NUL byte>").unwrap());
+
+            unsafe {
+                *out_error = message.into_raw();
+            }
+        }
+
+        1
+    }
+}
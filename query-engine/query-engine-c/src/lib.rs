@@ -0,0 +1,198 @@
+//! A `cdylib`/`staticlib` C ABI around [`query_engine_embedded`], for host languages other than
+//! Node (Python, Ruby, .NET, ...) to embed the query engine without shelling out to the HTTP
+//! binary or going through napi.
+//!
+//! The shape is deliberately narrow: opaque handles, JSON in/out, and callback-based completion
+//! for the async operations, since a plain C ABI has no `async`/`await` of its own. All strings
+//! crossing the boundary are NUL-terminated UTF-8; strings returned by this library must be freed
+//! with [`qe_string_free`], never with the host language's own allocator.
+//!
+//! ```c
+//! void on_query_result(void *ctx, const char *response_json) { ... }
+//!
+//! QueryEngine *engine = NULL;
+//! char *error = NULL;
+//! if (qe_connect(schema, &engine, &error) != 0) {
+//!     fprintf(stderr, "%s\n", error);
+//!     qe_string_free(error);
+//!     return 1;
+//! }
+//!
+//! qe_query(engine, "{ findManyUser { id } }", NULL, NULL, on_query_result);
+//! qe_free(engine);
+//! ```
+
+mod error;
+
+use error::CError;
+use once_cell::sync::Lazy;
+use query_engine_embedded::{EngineBuilder, PrismaEngine};
+use std::{
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_void},
+    ptr,
+};
+use tokio::runtime::Runtime;
+
+static RUNTIME: Lazy<Runtime> =
+    Lazy::new(|| Runtime::new().expect("query-engine-c: failed to start the Tokio runtime backing the engine"));
+
+/// An opaque, connected query engine instance. Only ever accessed behind a pointer handed out by
+/// [`qe_connect`]; never constructed or read from directly by the host language.
+pub struct QueryEngine(PrismaEngine);
+
+/// Invoked exactly once, from a worker thread owned by this library, when a [`qe_query`] call
+/// completes. `response_json` is valid only for the duration of the call; copy it if you need to
+/// keep it around, then let this library free it as usual by returning normally (the string is
+/// freed right after the callback returns).
+pub type QueryCallback = extern "C" fn(ctx: *mut c_void, response_json: *const c_char);
+
+/// A minimal wrapper making the raw `*mut c_void` context pointer `Send`, so it can cross into
+/// the worker thread that runs the query. Safe because the pointer is opaque to us: we never
+/// dereference it ourselves, we just hand it back to `callback` on whichever thread completes.
+struct SendPtr(*mut c_void);
+unsafe impl Send for SendPtr {}
+
+/// Parses and connects to the datasource described by `schema` (a NUL-terminated Prisma schema
+/// string), blocking the calling thread until the connection is established.
+///
+/// On success, writes a handle to `*out_engine` and returns `0`. On failure, writes a
+/// human-readable, NUL-terminated error message (owned by the caller, to be freed with
+/// [`qe_string_free`]) to `*out_error` and returns `1`. `out_error` may be null if the caller
+/// doesn't care about the message.
+///
+/// # Safety
+/// `schema` must be a valid pointer to a NUL-terminated UTF-8 string. `out_engine` must be a
+/// valid pointer to a `*mut QueryEngine`. `out_error`, if non-null, must be a valid pointer to a
+/// `*mut c_char`.
+#[no_mangle]
+pub unsafe extern "C" fn qe_connect(
+    schema: *const c_char,
+    out_engine: *mut *mut QueryEngine,
+    out_error: *mut *mut c_char,
+) -> i32 {
+    *out_engine = ptr::null_mut();
+
+    let schema = match CStr::from_ptr(schema).to_str() {
+        Ok(schema) => schema,
+        Err(_) => return CError::write("the schema passed to qe_connect is not valid UTF-8", out_error),
+    };
+
+    let result = RUNTIME.block_on(async move { EngineBuilder::new(schema)?.build().await });
+
+    match result {
+        Ok(engine) => {
+            *out_engine = Box::into_raw(Box::new(QueryEngine(engine)));
+            0
+        }
+        Err(err) => CError::write(&err.to_string(), out_error),
+    }
+}
+
+/// Runs a single GraphQL query against `engine` asynchronously, invoking `callback` with the
+/// JSON-encoded response once it completes. `variables_json`, if non-null, is a NUL-terminated
+/// JSON object mapping GraphQL variable names to values, mirroring the napi and HTTP bindings.
+///
+/// `ctx` is passed back to `callback` unchanged; use it to recover whatever state the host
+/// language needs on the other side of the call (e.g. a boxed closure or promise handle).
+///
+/// # Safety
+/// `engine` must be a live handle returned by [`qe_connect`] and not yet passed to [`qe_free`].
+/// `query` must be a valid NUL-terminated UTF-8 string that outlives this call. `variables_json`,
+/// if non-null, must likewise be valid NUL-terminated UTF-8. `callback` must be safe to invoke
+/// from a thread other than the one calling `qe_query`.
+#[no_mangle]
+pub unsafe extern "C" fn qe_query(
+    engine: *const QueryEngine,
+    query: *const c_char,
+    variables_json: *const c_char,
+    ctx: *mut c_void,
+    callback: QueryCallback,
+) {
+    let ctx = SendPtr(ctx);
+
+    let query = match CStr::from_ptr(query).to_str() {
+        Ok(query) => query.to_owned(),
+        Err(_) => {
+            invoke_with_error("the query passed to qe_query is not valid UTF-8", ctx, callback);
+            return;
+        }
+    };
+
+    let variables = if variables_json.is_null() {
+        None
+    } else {
+        match CStr::from_ptr(variables_json).to_str() {
+            Ok(json) => match serde_json::from_str::<std::collections::HashMap<String, String>>(json) {
+                Ok(variables) => Some(variables),
+                Err(err) => {
+                    invoke_with_error(&format!("invalid variables_json: {}", err), ctx, callback);
+                    return;
+                }
+            },
+            Err(_) => {
+                invoke_with_error(
+                    "the variables_json passed to qe_query is not valid UTF-8",
+                    ctx,
+                    callback,
+                );
+                return;
+            }
+        }
+    };
+
+    // The caller is responsible for not calling `qe_free` until every in-flight `qe_query`
+    // callback for `engine` has fired; we only ever read through this address on the runtime's
+    // worker threads while that contract holds, so carrying it across as a `usize` and
+    // re-establishing the reference inside the task is sound.
+    let engine_addr = engine as usize;
+
+    RUNTIME.spawn(async move {
+        let engine = unsafe { &*(engine_addr as *const QueryEngine) };
+        let response = match engine.0.query(query, variables).await {
+            Ok(response) => serde_json::to_string(&response)
+                .unwrap_or_else(|err| format!(r#"{{"errors":[{{"error":"{}"}}]}}"#, err)),
+            Err(err) => format!(r#"{{"errors":[{{"error":"{}"}}]}}"#, err),
+        };
+
+        invoke_with_json(&response, ctx, callback);
+    });
+}
+
+fn invoke_with_json(json: &str, ctx: SendPtr, callback: QueryCallback) {
+    let json = CString::new(json).unwrap_or_else(|_| CString::new("{}").unwrap());
+    callback(ctx.0, json.as_ptr());
+}
+
+fn invoke_with_error(message: &str, ctx: SendPtr, callback: QueryCallback) {
+    invoke_with_json(
+        &format!(r#"{{"errors":[{{"error":"{}"}}]}}"#, message.replace('"', "'")),
+        ctx,
+        callback,
+    );
+}
+
+/// Frees an engine handle returned by [`qe_connect`]. The caller must not use `engine` again
+/// afterwards, and must ensure every [`qe_query`] callback for it has already fired.
+///
+/// # Safety
+/// `engine` must be a handle previously returned by [`qe_connect`] that hasn't already been
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn qe_free(engine: *mut QueryEngine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Frees a string previously returned by this library (currently: the `out_error` string written
+/// by [`qe_connect`]).
+///
+/// # Safety
+/// `s` must be a pointer previously returned by this library, or null.
+#[no_mangle]
+pub unsafe extern "C" fn qe_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
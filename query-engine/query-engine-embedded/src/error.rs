@@ -0,0 +1,41 @@
+use datamodel::diagnostics::Diagnostics;
+use query_core::CoreError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum EmbeddedError {
+    #[error("{}", _0)]
+    ConfigurationError(String),
+
+    #[error("{}", _0)]
+    CoreError(Box<CoreError>),
+
+    #[error("{}", _0)]
+    ConversionError(Diagnostics, String),
+}
+
+impl From<CoreError> for EmbeddedError {
+    fn from(err: CoreError) -> Self {
+        EmbeddedError::CoreError(Box::new(err))
+    }
+}
+
+impl From<EmbeddedError> for user_facing_errors::Error {
+    fn from(err: EmbeddedError) -> Self {
+        use std::fmt::Write as _;
+
+        match err {
+            EmbeddedError::ConversionError(errors, dml_string) => {
+                let mut full_error = errors.to_pretty_string("schema.prisma", &dml_string);
+                write!(full_error, "\nValidation Error Count: {}", errors.errors.len()).unwrap();
+
+                user_facing_errors::Error::from(user_facing_errors::KnownError::new(
+                    user_facing_errors::common::SchemaParserError { full_error },
+                ))
+            }
+            other => user_facing_errors::Error::new_non_panic_with_current_backtrace(other.to_string()),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, EmbeddedError>;
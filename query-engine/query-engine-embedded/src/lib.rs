@@ -0,0 +1,164 @@
+//! A programmatic Rust API for embedding the query engine in-process, without going through
+//! napi or the HTTP server binary.
+//!
+//! ```no_run
+//! # async fn run() -> query_engine_embedded::Result<()> {
+//! let schema = r#"
+//!     datasource db {
+//!       provider = "sqlite"
+//!       url      = "file:dev.db"
+//!     }
+//!
+//!     model User {
+//!       id    Int    @id @default(autoincrement())
+//!       email String @unique
+//!     }
+//! "#;
+//!
+//! let engine = query_engine_embedded::EngineBuilder::new(schema)?.build().await?;
+//! let response = engine.query(r#"{ findManyUser { id } }"#, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+pub mod query_builder;
+
+pub use error::{EmbeddedError, Result};
+pub use query_builder::QueryBuilder;
+pub use request_handlers::PrismaResponse;
+
+use datamodel::{Configuration, Datamodel};
+use prisma_models::DatamodelConverter;
+use query_core::{exec_loader, schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
+use request_handlers::{dmmf, GraphQlBody, GraphQlHandler, SingleQuery};
+use std::{env, fmt};
+
+/// Parses and validates a datamodel string, producing an [`EngineBuilder`] that can be used to
+/// connect to the database and construct a [`PrismaEngine`].
+pub struct EngineBuilder {
+    config: Configuration,
+    datamodel: Datamodel,
+    enable_raw_queries: bool,
+}
+
+impl EngineBuilder {
+    /// Parses the given Prisma schema. Fails if the schema doesn't parse or validate, or if it
+    /// doesn't declare exactly one datasource.
+    pub fn new(schema: &str) -> Result<Self> {
+        let config = datamodel::parse_configuration(schema)
+            .map_err(|errors| EmbeddedError::ConversionError(errors, schema.to_owned()))?;
+
+        config
+            .subject
+            .validate_that_one_datasource_is_provided()
+            .map_err(|errors| EmbeddedError::ConversionError(errors, schema.to_owned()))?;
+
+        let datamodel = datamodel::parse_datamodel(schema)
+            .map_err(|errors| EmbeddedError::ConversionError(errors, schema.to_owned()))?
+            .subject;
+
+        Ok(Self {
+            config: config.subject,
+            datamodel,
+            enable_raw_queries: true,
+        })
+    }
+
+    /// Whether to allow the `$queryRaw`/`$executeRaw` escape hatches. Enabled by default.
+    pub fn enable_raw_queries(mut self, val: bool) -> Self {
+        self.enable_raw_queries = val;
+        self
+    }
+
+    /// Resolves the datasource URL from the environment, connects to the database and builds the
+    /// query schema.
+    pub async fn build(self) -> Result<PrismaEngine> {
+        let template = DatamodelConverter::convert(&self.datamodel);
+
+        // We only support one data source at the moment, so take the first one (default not exposed yet).
+        let data_source = self
+            .config
+            .datasources
+            .first()
+            .ok_or_else(|| EmbeddedError::ConfigurationError("No valid data source found".into()))?;
+
+        let url = data_source
+            .load_url(|key| env::var(key).ok())
+            .map_err(|err| EmbeddedError::ConfigurationError(err.to_string()))?;
+
+        let preview_features: Vec<_> = self.config.preview_features().cloned().collect();
+        let (db_name, executor) = exec_loader::load(&data_source, &preview_features, &url).await?;
+
+        let internal_data_model = template.build(db_name);
+        let query_schema: QuerySchemaRef = std::sync::Arc::new(schema_builder::build(
+            internal_data_model,
+            BuildMode::Modern,
+            self.enable_raw_queries,
+            data_source.capabilities(),
+            preview_features,
+            false,
+        ));
+
+        let engine = PrismaEngine {
+            datamodel: self.datamodel,
+            query_schema,
+            executor,
+        };
+
+        engine
+            .executor
+            .primary_connector()
+            .get_connection()
+            .await
+            .map_err(query_core::CoreError::from)?;
+
+        Ok(engine)
+    }
+}
+
+/// A connected, in-process query engine. Cheap to keep around for the lifetime of the embedding
+/// application; there's no supported way to reconnect or swap the schema on an existing instance,
+/// build a new one instead.
+pub struct PrismaEngine {
+    datamodel: Datamodel,
+    query_schema: QuerySchemaRef,
+    executor: Box<dyn QueryExecutor + Send + Sync>,
+}
+
+impl fmt::Debug for PrismaEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PrismaEngine { .. }")
+    }
+}
+
+impl PrismaEngine {
+    /// Runs a single GraphQL query against the engine. `variables` is a map of GraphQL variable
+    /// name to JSON-encoded value, mirroring what the HTTP and napi bindings accept.
+    pub async fn query(
+        &self,
+        query: impl Into<String>,
+        variables: Option<std::collections::HashMap<String, String>>,
+    ) -> Result<PrismaResponse> {
+        let mut single_query: SingleQuery = query.into().into();
+
+        if let Some(variables) = variables {
+            single_query = single_query.with_variables(variables);
+        }
+
+        let handler = GraphQlHandler::new(self.executor.as_ref(), &self.query_schema);
+        Ok(handler.handle(GraphQlBody::Single(single_query)).await)
+    }
+
+    /// Runs a query built with [`QueryBuilder`].
+    pub async fn run(&self, query: QueryBuilder) -> Result<PrismaResponse> {
+        self.query(query.render(), None).await
+    }
+
+    /// Renders the DMMF (Data Model Meta Format) describing the models and the operations
+    /// available on this engine, in the same shape the generators and napi bindings receive.
+    pub fn dmmf(&self) -> Result<serde_json::Value> {
+        let dmmf = dmmf::render_dmmf(&self.datamodel, self.query_schema.clone());
+        serde_json::to_value(dmmf).map_err(|err| EmbeddedError::ConfigurationError(err.to_string()))
+    }
+}
@@ -0,0 +1,118 @@
+//! A small builder for constructing single GraphQL operations to run against a [`PrismaEngine`].
+//!
+//! This is *not* a compile-time typed query builder generated from the DMMF - doing that
+//! properly needs a codegen step (a build script or proc macro reading the schema), which is out
+//! of scope for this facade. What's here validates and renders the GraphQL text for you so
+//! callers don't have to hand-write query strings, while [`PrismaEngine::dmmf`] gives you the
+//! schema to build such a codegen step against, or to validate model/field names at runtime.
+//!
+//! [`PrismaEngine`]: crate::PrismaEngine
+
+use serde_json::Value;
+
+/// Builds a single GraphQL operation for a model action, e.g. `findManyUser(where: { ... })`.
+#[derive(Debug, Clone)]
+pub struct QueryBuilder {
+    operation: String,
+    selection: Vec<String>,
+    args: Vec<(String, Value)>,
+}
+
+impl QueryBuilder {
+    /// Starts building a query for the given operation name, e.g. `findManyUser` or
+    /// `createOneUser`. Operation names follow the `mappings` section of the DMMF returned by
+    /// [`PrismaEngine::dmmf`](crate::PrismaEngine::dmmf).
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            selection: Vec::new(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Adds a field to the selection set. Defaults to `["id"]` if none are added.
+    pub fn select(mut self, field: impl Into<String>) -> Self {
+        self.selection.push(field.into());
+        self
+    }
+
+    /// Adds an argument, e.g. `.arg("where", json!({ "id": 1 }))`.
+    pub fn arg(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.args.push((name.into(), value.into()));
+        self
+    }
+
+    /// Renders the operation as GraphQL query text.
+    pub fn render(&self) -> String {
+        let selection = if self.selection.is_empty() {
+            "id".to_owned()
+        } else {
+            self.selection.join(" ")
+        };
+
+        if self.args.is_empty() {
+            format!("{{ {} {{ {} }} }}", self.operation, selection)
+        } else {
+            let args = self
+                .args
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, render_value(value)))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{{ {}({}) {{ {} }} }}", self.operation, args, selection)
+        }
+    }
+}
+
+/// Renders a JSON value as a GraphQL input literal.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_owned(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => format!("{:?}", s),
+        Value::Array(items) => {
+            let items = items.iter().map(render_value).collect::<Vec<_>>().join(", ");
+            format!("[{}]", items)
+        }
+        Value::Object(map) => {
+            let fields = map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, render_value(value)))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{{ {} }}", fields)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_a_query_without_args() {
+        let query = QueryBuilder::new("findManyUser").select("id").select("email");
+
+        assert_eq!(query.render(), "{ findManyUser { id email } }");
+    }
+
+    #[test]
+    fn renders_a_query_with_args() {
+        let query = QueryBuilder::new("findUniqueUser")
+            .arg("where", json!({ "id": 1 }))
+            .select("id");
+
+        assert_eq!(query.render(), "{ findUniqueUser(where: { id: 1 }) { id } }");
+    }
+
+    #[test]
+    fn defaults_to_selecting_id() {
+        let query = QueryBuilder::new("findManyUser");
+
+        assert_eq!(query.render(), "{ findManyUser { id } }");
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{error::ApiError, logger::ChannelLogger};
+use crate::{
+    error::ApiError,
+    logger::{ChannelLogger, ChannelMetrics, OverflowConfig, OverflowPolicy},
+};
 use datamodel::{diagnostics::ValidatedConfiguration, Datamodel};
 use napi::threadsafe_function::ThreadsafeFunction;
 use opentelemetry::global;
 use prisma_models::DatamodelConverter;
-use query_core::{exec_loader, schema_builder, BuildMode, QueryExecutor, QuerySchema, QuerySchemaRenderer};
+use query_core::{
+    exec_loader, schema_builder, BuildMode, EngineMetricsSnapshot, QueryExecutor, QuerySchema, QuerySchemaRenderer,
+};
 use request_handlers::{GraphQLSchemaRenderer, GraphQlBody, GraphQlHandler, PrismaResponse};
 use serde::{Deserialize, Serialize};
 use std::{
@@ -46,6 +51,7 @@ pub struct EngineBuilder {
     logger: ChannelLogger,
     config_dir: PathBuf,
     env: HashMap<String, String>,
+    read_only: bool,
 }
 
 /// Internal structure for querying and reconnecting with the engine.
@@ -56,6 +62,7 @@ pub struct ConnectedEngine {
     logger: ChannelLogger,
     config_dir: PathBuf,
     env: HashMap<String, String>,
+    read_only: bool,
 }
 
 /// Returned from the `serverInfo` method in javascript.
@@ -96,6 +103,10 @@ pub struct ConstructorOptions {
     config_dir: PathBuf,
     #[serde(default)]
     ignore_env_var_errors: bool,
+    #[serde(default)]
+    read_only: bool,
+    #[serde(default)]
+    log_overflow: LogOverflowOptions,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -105,6 +116,31 @@ pub struct TelemetryOptions {
     endpoint: Option<String>,
 }
 
+/// Configures the overflow policy for the channel that ships log events to
+/// the JS callback. Ignored when `telemetry.enabled` is set, since the
+/// telemetry logger always uses the default policy.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogOverflowOptions {
+    #[serde(default)]
+    policy: OverflowPolicy,
+    /// Timeout in milliseconds, only used when `policy` is `blockWithTimeout`.
+    #[serde(default)]
+    block_timeout_ms: u64,
+    #[serde(default)]
+    high_water_mark: u64,
+}
+
+impl From<LogOverflowOptions> for OverflowConfig {
+    fn from(opts: LogOverflowOptions) -> Self {
+        Self {
+            policy: opts.policy,
+            block_timeout: std::time::Duration::from_millis(opts.block_timeout_ms),
+            high_water_mark: opts.high_water_mark,
+        }
+    }
+}
+
 impl QueryEngine {
     /// Parse a validated datamodel and configuration to allow connecting later on.
     pub fn new(opts: ConstructorOptions, log_callback: ThreadsafeFunction<String>) -> crate::Result<Self> {
@@ -119,6 +155,8 @@ impl QueryEngine {
             telemetry,
             config_dir,
             ignore_env_var_errors,
+            read_only,
+            log_overflow,
         } = opts;
 
         let overrides: Vec<(_, _)> = datasource_overrides.into_iter().collect();
@@ -155,7 +193,7 @@ impl QueryEngine {
         let logger = if telemetry.enabled {
             ChannelLogger::new_with_telemetry(log_callback, telemetry.endpoint)
         } else {
-            ChannelLogger::new(&log_level, log_queries, log_callback)
+            ChannelLogger::new_with_overflow_config(&log_level, log_queries, log_callback, log_overflow.into())
         };
 
         let builder = EngineBuilder {
@@ -164,6 +202,7 @@ impl QueryEngine {
             logger,
             config_dir,
             env,
+            read_only,
         };
 
         Ok(Self {
@@ -211,6 +250,7 @@ impl QueryEngine {
                             true, // enable raw queries
                             data_source.capabilities(),
                             preview_features,
+                            builder.read_only,
                         );
 
                         Ok(ConnectedEngine {
@@ -220,6 +260,7 @@ impl QueryEngine {
                             executor,
                             config_dir: builder.config_dir.clone(),
                             env: builder.env.clone(),
+                            read_only: builder.read_only,
                         })
                     })
                     .await?;
@@ -247,6 +288,7 @@ impl QueryEngine {
                     config,
                     config_dir: engine.config_dir.clone(),
                     env: engine.env.clone(),
+                    read_only: engine.read_only,
                 };
 
                 *inner = Inner::Builder(builder);
@@ -285,6 +327,23 @@ impl QueryEngine {
             Inner::Builder(_) => Err(ApiError::NotConnected),
         }
     }
+
+    /// Counters for log events dropped because the JS side couldn't keep up.
+    pub async fn get_metrics(&self) -> ChannelMetrics {
+        match *self.inner.read().await {
+            Inner::Builder(ref builder) => builder.logger.get_metrics(),
+            Inner::Connected(ref engine) => engine.logger.get_metrics(),
+        }
+    }
+
+    /// Query counters and latency totals collected by the executor. Only available when
+    /// connected, since there's no executor to poll before that.
+    pub async fn metrics(&self) -> crate::Result<EngineMetricsSnapshot> {
+        match *self.inner.read().await {
+            Inner::Connected(ref engine) => Ok(engine.executor().metrics()),
+            Inner::Builder(_) => Err(ApiError::NotConnected),
+        }
+    }
 }
 
 pub fn set_panic_hook() {
@@ -3,6 +3,8 @@ mod registry;
 mod telemetry;
 mod visitor;
 
+pub use channel::{ChannelMetrics, OverflowPolicy};
+
 use channel::EventChannel;
 use napi::threadsafe_function::ThreadsafeFunction;
 use opentelemetry::{
@@ -12,7 +14,7 @@ use opentelemetry::{
 };
 use opentelemetry_otlp::Uninstall;
 use registry::EventRegistry;
-use std::{future::Future, sync::Arc};
+use std::{future::Future, sync::Arc, time::Duration};
 use telemetry::WithTelemetry;
 use tracing_futures::WithSubscriber;
 use tracing_subscriber::{
@@ -26,25 +28,69 @@ enum Subscriber {
     WithTelemetry(WithTelemetry),
 }
 
+/// Configures how the channel to the JS log callback behaves once its
+/// internal queue fills up, i.e. the JS side isn't draining events fast
+/// enough.
+#[derive(Debug, Clone, Copy)]
+pub struct OverflowConfig {
+    pub policy: OverflowPolicy,
+    /// Only used when `policy` is `OverflowPolicy::BlockWithTimeout`.
+    pub block_timeout: Duration,
+    /// Emit a warning event every time this many events have been dropped.
+    /// `0` disables the warning.
+    pub high_water_mark: u64,
+}
+
+impl Default for OverflowConfig {
+    fn default() -> Self {
+        Self {
+            policy: OverflowPolicy::default(),
+            block_timeout: Duration::default(),
+            high_water_mark: 0,
+        }
+    }
+}
+
 /// A logger logging to a bounded channel. When in scope, all log messages from
 /// the scope are stored to the channel, which must be consumed or after some
-/// point, further log lines will just be dropped.
+/// point, further log lines will be dropped according to the configured
+/// `OverflowPolicy`.
 #[derive(Clone)]
 pub struct ChannelLogger {
     subscriber: Subscriber,
     guard: Option<Arc<Uninstall>>,
+    channel: EventChannel,
 }
 
 impl ChannelLogger {
     /// Creates a new instance of a logger with the minimum log level.
     pub fn new(level: &str, log_queries: bool, callback: ThreadsafeFunction<String>) -> Self {
+        Self::new_with_overflow_config(level, log_queries, callback, OverflowConfig::default())
+    }
+
+    /// Creates a new instance of a logger with the minimum log level and a
+    /// non-default overflow policy for the JS callback channel.
+    pub fn new_with_overflow_config(
+        level: &str,
+        log_queries: bool,
+        callback: ThreadsafeFunction<String>,
+        overflow: OverflowConfig,
+    ) -> Self {
         let mut filter = EnvFilter::new(level);
 
         if log_queries {
             filter = filter.add_directive("quaint[{is_query}]".parse().unwrap());
         }
 
-        let javascript_cb = EventChannel::new(callback, filter, false);
+        let javascript_cb = EventChannel::new_with_overflow_policy(
+            callback,
+            filter,
+            false,
+            overflow.policy,
+            overflow.block_timeout,
+            overflow.high_water_mark,
+        );
+        let channel = javascript_cb.clone();
         let subscriber = EventRegistry::new().with(javascript_cb);
 
         let subscriber = Subscriber::Normal(subscriber);
@@ -52,6 +98,7 @@ impl ChannelLogger {
         Self {
             subscriber,
             guard: None,
+            channel,
         }
     }
 
@@ -59,6 +106,7 @@ impl ChannelLogger {
     /// Enables tracing events to OTLP endpoint.
     pub fn new_with_telemetry(callback: ThreadsafeFunction<String>, endpoint: Option<String>) -> Self {
         let javascript_cb = EventChannel::new(callback, EnvFilter::new("trace"), true);
+        let channel = javascript_cb.clone();
 
         global::set_text_map_propagator(TraceContextPropagator::new());
 
@@ -83,9 +131,16 @@ impl ChannelLogger {
         Self {
             subscriber,
             guard: Some(Arc::new(guard)),
+            channel,
         }
     }
 
+    /// Counters for events dropped by the JS callback channel because the JS
+    /// side couldn't keep up.
+    pub fn get_metrics(&self) -> ChannelMetrics {
+        self.channel.metrics()
+    }
+
     /// Wraps a future to a logger, storing all events in the pipeline to
     /// the channel.
     pub async fn with_logging<F, U, T>(&self, f: F) -> crate::Result<T>
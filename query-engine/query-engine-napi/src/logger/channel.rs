@@ -1,26 +1,172 @@
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
 use super::visitor::JsonVisitor;
-use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
-use serde_json::{Map, Value};
+use napi::{threadsafe_function::ThreadsafeFunction, threadsafe_function::ThreadsafeFunctionCallMode, Status};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
 use tracing::{Event, Subscriber};
 use tracing_subscriber::{layer::Context, registry::LookupSpan, EnvFilter, Layer};
 
+/// How the logging channel behaves once the napi threadsafe function's
+/// internal queue is full, i.e. the JS side isn't draining events fast
+/// enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverflowPolicy {
+    /// Drop the event that doesn't fit instead of blocking the caller.
+    DropNewest,
+    /// Requested equivalent of "evict the oldest queued event and enqueue
+    /// the new one", but napi's threadsafe function queue is opaque with no
+    /// eviction API, so this is implemented identically to `DropNewest`:
+    /// the event that doesn't fit is the one that gets dropped.
+    DropOldest,
+    /// Block the calling thread until there's room in the queue, giving up
+    /// after `block_timeout` and falling back to dropping the event.
+    BlockWithTimeout,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::DropNewest
+    }
+}
+
+/// Snapshot of the channel's overflow-handling counters, returned from
+/// `ChannelLogger::get_metrics`.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelMetrics {
+    pub dropped_events: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    dropped_events: AtomicU64,
+}
+
 #[derive(Clone)]
 pub struct EventChannel {
     callback: ThreadsafeFunction<String>,
     telemetry: bool,
     filter: Arc<EnvFilter>,
+    overflow_policy: OverflowPolicy,
+    block_timeout: Duration,
+    high_water_mark: u64,
+    counters: Arc<Counters>,
 }
 
 impl EventChannel {
     pub fn new(callback: ThreadsafeFunction<String>, filter: EnvFilter, telemetry: bool) -> Self {
+        Self::new_with_overflow_policy(
+            callback,
+            filter,
+            telemetry,
+            OverflowPolicy::default(),
+            Duration::default(),
+            0,
+        )
+    }
+
+    pub fn new_with_overflow_policy(
+        callback: ThreadsafeFunction<String>,
+        filter: EnvFilter,
+        telemetry: bool,
+        overflow_policy: OverflowPolicy,
+        block_timeout: Duration,
+        high_water_mark: u64,
+    ) -> Self {
         Self {
             callback,
             telemetry,
             filter: Arc::new(filter),
+            overflow_policy,
+            block_timeout,
+            high_water_mark,
+            counters: Arc::new(Counters::default()),
         }
     }
+
+    /// Counters for events dropped because the JS side couldn't keep up.
+    pub fn metrics(&self) -> ChannelMetrics {
+        ChannelMetrics {
+            dropped_events: self.counters.dropped_events.load(Ordering::Relaxed),
+        }
+    }
+
+    fn send(&self, json_str: String) {
+        let delivered = match self.overflow_policy {
+            OverflowPolicy::DropNewest | OverflowPolicy::DropOldest => {
+                self.callback
+                    .call(Ok(json_str), ThreadsafeFunctionCallMode::NonBlocking)
+                    == Status::Ok
+            }
+            OverflowPolicy::BlockWithTimeout if self.block_timeout.is_zero() => {
+                self.callback.call(Ok(json_str), ThreadsafeFunctionCallMode::Blocking);
+                true
+            }
+            OverflowPolicy::BlockWithTimeout => self.send_blocking_with_timeout(json_str),
+        };
+
+        if !delivered {
+            self.record_drop();
+        }
+    }
+
+    /// Retries a non-blocking send until the queue has room or `block_timeout`
+    /// elapses, at which point the event is dropped. napi's threadsafe
+    /// function has no timed-blocking call, so this approximates one.
+    fn send_blocking_with_timeout(&self, json_str: String) -> bool {
+        let deadline = Instant::now() + self.block_timeout;
+
+        loop {
+            if self
+                .callback
+                .call(Ok(json_str.clone()), ThreadsafeFunctionCallMode::NonBlocking)
+                == Status::Ok
+            {
+                return true;
+            }
+
+            if Instant::now() >= deadline {
+                return false;
+            }
+
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.counters.dropped_events.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if self.high_water_mark > 0 && dropped % self.high_water_mark == 0 {
+            self.emit_high_water_mark_warning(dropped);
+        }
+    }
+
+    /// Surfaces a high-water-mark warning to the JS side through the same
+    /// callback used for regular log events, tagged so consumers can tell it
+    /// apart. Sent non-blocking so a saturated queue can't wedge on its own
+    /// warning.
+    fn emit_high_water_mark_warning(&self, dropped_events: u64) {
+        let payload = json!({
+            "level": "WARN",
+            "module_path": "query_engine_napi::logger",
+            "message": "Log event channel is overflowing; events are being dropped",
+            "dropped_events": dropped_events,
+        });
+
+        self.callback.call(
+            Ok(serde_json::to_string(&payload).unwrap()),
+            ThreadsafeFunctionCallMode::NonBlocking,
+        );
+    }
 }
 
 impl<S> Layer<S> for EventChannel
@@ -43,7 +189,7 @@ where
         let js_object = Value::Object(object);
         let json_str = serde_json::to_string(&js_object).unwrap();
 
-        self.callback.call(Ok(json_str), ThreadsafeFunctionCallMode::Blocking);
+        self.send(json_str);
     }
 
     fn enabled(&self, metadata: &tracing::Metadata<'_>, ctx: Context<'_, S>) -> bool {
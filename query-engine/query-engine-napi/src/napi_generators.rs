@@ -14,6 +14,8 @@ pub fn init(mut exports: JsObject, env: Env) -> napi::Result<()> {
             Property::new(&env, "disconnect")?.with_method(engine::disconnect),
             Property::new(&env, "query")?.with_method(engine::query),
             Property::new(&env, "sdlSchema")?.with_method(engine::sdl_schema),
+            Property::new(&env, "getMetrics")?.with_method(engine::get_metrics),
+            Property::new(&env, "metrics")?.with_method(engine::metrics),
         ],
     )?;
 
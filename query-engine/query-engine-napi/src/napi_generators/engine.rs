@@ -89,3 +89,31 @@ pub fn sdl_schema(ctx: CallContext) -> napi::Result<JsObject> {
             env.create_string_from_std(res)
         })
 }
+
+#[js_function(0)]
+pub fn get_metrics(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    ctx.env
+        .execute_tokio_future(async move { Ok(engine.get_metrics().await) }, |env, metrics| {
+            let res = serde_json::to_string(&metrics).unwrap();
+            env.adjust_external_memory(res.len() as i64)?;
+            env.create_string_from_std(res)
+        })
+}
+
+#[js_function(0)]
+pub fn metrics(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    ctx.env
+        .execute_tokio_future(async move { Ok(engine.metrics().await?) }, |env, metrics| {
+            let res = serde_json::to_string(&metrics).unwrap();
+            env.adjust_external_memory(res.len() as i64)?;
+            env.create_string_from_std(res)
+        })
+}
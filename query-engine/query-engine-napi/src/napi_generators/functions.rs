@@ -53,6 +53,7 @@ pub fn dmmf(ctx: CallContext) -> napi::Result<JsString> {
         true,
         capabilities,
         config.subject.preview_features().cloned().collect(),
+        false,
     ));
 
     let dmmf = dmmf::render_dmmf(&datamodel.subject, query_schema);
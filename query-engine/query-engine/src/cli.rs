@@ -1,6 +1,8 @@
 use crate::{
     context::PrismaContext,
     opt::{CliOpt, PrismaOpt, Subcommand},
+    replay::ReplayRequest,
+    seed::SeedRequest,
     PrismaResult,
 };
 
@@ -18,12 +20,14 @@ pub struct ExecuteRequest {
     datamodel: Datamodel,
     config: Configuration,
     enable_raw_queries: bool,
+    read_only: bool,
 }
 
 pub struct DmmfRequest {
     datamodel: Datamodel,
     build_mode: BuildMode,
     enable_raw_queries: bool,
+    read_only: bool,
     config: Configuration,
 }
 
@@ -36,6 +40,8 @@ pub enum CliCommand {
     Dmmf(DmmfRequest),
     GetConfig(GetConfigRequest),
     ExecuteRequest(ExecuteRequest),
+    Seed(SeedRequest),
+    Replay(ReplayRequest),
 }
 
 impl CliCommand {
@@ -60,6 +66,7 @@ impl CliCommand {
                         datamodel: opts.datamodel()?,
                         build_mode,
                         enable_raw_queries: opts.enable_raw_queries,
+                        read_only: opts.read_only,
                         config: opts.configuration(true)?.subject,
                     })))
                 }
@@ -70,10 +77,27 @@ impl CliCommand {
                 CliOpt::ExecuteRequest(input) => Ok(Some(CliCommand::ExecuteRequest(ExecuteRequest {
                     query: input.query.clone(),
                     enable_raw_queries: opts.enable_raw_queries,
+                    read_only: opts.read_only,
                     legacy: input.legacy,
                     datamodel: opts.datamodel()?,
                     config: opts.configuration(false)?.subject,
                 }))),
+                CliOpt::Seed(input) => Ok(Some(CliCommand::Seed(SeedRequest {
+                    file: input.file.clone(),
+                    legacy: opts.legacy,
+                    enable_raw_queries: opts.enable_raw_queries,
+                    read_only: opts.read_only,
+                    datamodel: opts.datamodel()?,
+                    config: opts.configuration(false)?.subject,
+                }))),
+                CliOpt::Replay(input) => Ok(Some(CliCommand::Replay(ReplayRequest {
+                    file: input.file.clone(),
+                    legacy: input.legacy,
+                    enable_raw_queries: opts.enable_raw_queries,
+                    read_only: opts.read_only,
+                    datamodel: opts.datamodel()?,
+                    config: opts.configuration(false)?.subject,
+                }))),
             },
         }
     }
@@ -83,6 +107,8 @@ impl CliCommand {
             CliCommand::Dmmf(request) => Self::dmmf(request).await,
             CliCommand::GetConfig(input) => Self::get_config(input),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
+            CliCommand::Seed(request) => request.run().await,
+            CliCommand::Replay(request) => request.run().await,
         }
     }
 
@@ -102,6 +128,7 @@ impl CliCommand {
             request.enable_raw_queries,
             capabilities,
             request.config.preview_features().cloned().collect(),
+            request.read_only,
         ));
 
         let dmmf = dmmf::render_dmmf(&request.datamodel, query_schema);
@@ -138,6 +165,7 @@ impl CliCommand {
         let cx = PrismaContext::builder(request.config, request.datamodel)
             .legacy(request.legacy)
             .enable_raw_queries(request.enable_raw_queries)
+            .read_only(request.read_only)
             .build()
             .await?;
 
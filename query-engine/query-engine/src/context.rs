@@ -24,6 +24,7 @@ impl fmt::Debug for PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    read_only: bool,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -39,14 +40,32 @@ impl ContextBuilder {
         self
     }
 
+    pub fn read_only(mut self, val: bool) -> Self {
+        self.read_only = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.read_only,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        read_only: bool,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -73,6 +92,7 @@ impl PrismaContext {
             enable_raw_queries,
             data_source.capabilities(),
             preview_features,
+            read_only,
         ));
 
         let context = Self {
@@ -95,6 +115,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            read_only: false,
             datamodel,
             config,
         }
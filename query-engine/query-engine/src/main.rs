@@ -15,6 +15,9 @@ mod context;
 mod error;
 mod logger;
 mod opt;
+mod recording;
+mod replay;
+mod seed;
 mod server;
 
 #[cfg(test)]
@@ -38,7 +41,8 @@ async fn main() -> Result<(), AnyError> {
     });
 
     async fn main() -> Result<(), PrismaError> {
-        let opts = PrismaOpt::from_args();
+        let mut opts = PrismaOpt::from_args();
+        opts.apply_config_file()?;
 
         let mut logger = Logger::new("query-engine-http");
         logger.log_format(opts.log_format());
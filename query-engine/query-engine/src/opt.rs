@@ -1,7 +1,7 @@
 use crate::{error::PrismaError, PrismaResult};
 use datamodel::diagnostics::ValidatedConfiguration;
 use datamodel::Datamodel;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::{ffi::OsStr, fs::File, io::Read};
 use structopt::StructOpt;
@@ -28,6 +28,25 @@ pub struct GetConfigInput {
     pub ignore_env_var_errors: bool,
 }
 
+#[derive(Debug, Clone, StructOpt)]
+pub struct SeedInput {
+    /// Path to the seed file: a JSON array of `{ "model": "...", "records": [...] }` entries,
+    /// applied in file order.
+    #[structopt(long)]
+    pub file: String,
+}
+
+#[derive(Debug, Clone, StructOpt)]
+pub struct ReplayInput {
+    /// Path to the recorded query log, as produced by `--record-queries`: one JSON object per
+    /// line, each `{ "request": <GraphQlBody>, "response": <PrismaResponse> }`.
+    #[structopt(long)]
+    pub file: String,
+    /// Run in the legacy GraphQL mode.
+    #[structopt(long)]
+    pub legacy: bool,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 pub enum CliOpt {
     /// Output the DMMF from the loaded data model.
@@ -36,6 +55,11 @@ pub enum CliOpt {
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
     ExecuteRequest(ExecuteRequestInput),
+    /// Loads a declarative seed file through the normal write path and then terminates.
+    Seed(SeedInput),
+    /// Replays a query log recorded with `--record-queries` against this engine/database and
+    /// diffs the responses, for checking an engine upgrade against a real workload.
+    Replay(ReplayInput),
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -75,6 +99,10 @@ pub struct PrismaOpt {
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// Runs the engine in read-only mode, rejecting all write operations and raw queries with a specific error.
+    #[structopt(long)]
+    pub read_only: bool,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
@@ -87,6 +115,13 @@ pub struct PrismaOpt {
     #[structopt(long, short = "o")]
     pub log_queries: bool,
 
+    /// Record every incoming query document and its response as a line of JSON in the given
+    /// file, for later replay with `cli replay --file`. Meant for capturing a real production
+    /// workload ahead of an engine upgrade, not for permanent use: it appends to the file on
+    /// every request and never rotates or truncates it.
+    #[structopt(long, env = "RECORD_QUERIES")]
+    pub record_queries: Option<String>,
+
     /// Set the log format.
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
@@ -96,19 +131,59 @@ pub struct PrismaOpt {
     pub open_telemetry: bool,
 
     /// The url to the OpenTelemetry collector.
+    // Keep in sync with `DEFAULT_OPEN_TELEMETRY_ENDPOINT` below.
     #[structopt(long, default_value = "http://localhost:4317")]
     pub open_telemetry_endpoint: String,
 
+    /// Path to a TOML config file providing defaults for the options above, for setups (e.g.
+    /// containers) where flags and env vars are inconvenient to manage. Flags and env vars
+    /// always take precedence over the config file.
+    #[structopt(long, short = "c", env = "PRISMA_CONFIG_PATH")]
+    pub config_path: Option<String>,
+
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 }
 
-#[derive(Debug, Deserialize)]
+const DEFAULT_OPEN_TELEMETRY_ENDPOINT: &str = "http://localhost:4317";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct SourceOverride {
     name: String,
     url: String,
 }
 
+/// The shape of the `--config` TOML file. Every field is optional and only fills in a value
+/// that wasn't already set via a flag or environment variable.
+///
+/// Connection pool sizing isn't covered here: it's not something this binary manages today,
+/// `connection_limit`/`pool_timeout` are read straight off the datasource URL by the
+/// underlying database driver, so they belong in the datasource URL or, for `pgbouncer`-style
+/// setups, alongside the other datasource overrides.
+///
+/// Preview features aren't covered either, since those are declared in the Prisma schema's
+/// `generator` block, not something this binary chooses independently of the schema it loaded.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EngineConfig {
+    #[serde(default)]
+    datasource_overrides: Vec<SourceOverride>,
+    log_format: Option<String>,
+    #[serde(default)]
+    log_queries: bool,
+    #[serde(default)]
+    open_telemetry: bool,
+    open_telemetry_endpoint: Option<String>,
+    #[serde(default)]
+    enable_raw_queries: bool,
+    #[serde(default)]
+    read_only: bool,
+    #[serde(default)]
+    enable_playground: bool,
+    #[serde(default)]
+    enable_debug_mode: bool,
+}
+
 impl PrismaOpt {
     fn datamodel_str(&self) -> PrismaResult<&str> {
         let res = self
@@ -176,6 +251,43 @@ impl PrismaOpt {
     pub(crate) fn log_queries(&self) -> bool {
         std::env::var("LOG_QUERIES").map(|_| true).unwrap_or(self.log_queries)
     }
+
+    /// If `--config`/`PRISMA_CONFIG_PATH` was given, read it and use it to fill in any of the
+    /// options above that weren't already set via a flag or environment variable. Must be
+    /// called before any of the other methods on this type that read those fields.
+    pub fn apply_config_file(&mut self) -> PrismaResult<()> {
+        let path = match self.config_path.as_deref() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let contents = std::fs::read_to_string(path).map_err(|err| {
+            PrismaError::ConfigurationError(format!("Could not read config file `{}`: {}", path, err))
+        })?;
+
+        let config: EngineConfig = toml::from_str(&contents)
+            .map_err(|err| PrismaError::ConfigurationError(format!("Invalid config file `{}`: {}", path, err)))?;
+
+        if self.overwrite_datasources.is_none() && !config.datasource_overrides.is_empty() {
+            self.overwrite_datasources = Some(serde_json::to_string(&config.datasource_overrides)?);
+        }
+
+        self.log_format = self.log_format.take().or(config.log_format);
+        self.log_queries = self.log_queries || config.log_queries;
+        self.open_telemetry = self.open_telemetry || config.open_telemetry;
+        self.enable_raw_queries = self.enable_raw_queries || config.enable_raw_queries;
+        self.read_only = self.read_only || config.read_only;
+        self.enable_playground = self.enable_playground || config.enable_playground;
+        self.enable_debug_mode = self.enable_debug_mode || config.enable_debug_mode;
+
+        if self.open_telemetry_endpoint == DEFAULT_OPEN_TELEMETRY_ENDPOINT {
+            if let Some(endpoint) = config.open_telemetry_endpoint {
+                self.open_telemetry_endpoint = endpoint;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 fn parse_base64_string(s: &str) -> PrismaResult<String> {
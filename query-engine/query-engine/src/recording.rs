@@ -0,0 +1,15 @@
+use request_handlers::GraphQlBody;
+use serde::{Deserialize, Serialize};
+
+/// One line of the file written by `--record-queries`, and read back by `cli replay`. Newline
+/// delimited (rather than one JSON array) so a long-running server can append to it without
+/// re-reading or re-writing what's already there.
+///
+/// `response` is kept as a plain JSON value rather than a `PrismaResponse`: the response types
+/// only implement `Serialize`, and comparing the JSON a replayed query produces against the JSON
+/// that was recorded is all a diff needs anyway.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct RecordedQuery {
+    pub(crate) request: GraphQlBody,
+    pub(crate) response: serde_json::Value,
+}
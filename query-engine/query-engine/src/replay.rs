@@ -0,0 +1,80 @@
+use crate::{context::PrismaContext, recording::RecordedQuery, PrismaError, PrismaResult};
+use datamodel::{Configuration, Datamodel};
+use request_handlers::GraphQlHandler;
+use std::{fs, sync::Arc};
+
+pub struct ReplayRequest {
+    pub(crate) file: String,
+    pub(crate) legacy: bool,
+    pub(crate) enable_raw_queries: bool,
+    pub(crate) read_only: bool,
+    pub(crate) datamodel: Datamodel,
+    pub(crate) config: Configuration,
+}
+
+impl ReplayRequest {
+    /// Re-runs every query recorded in `self.file` against a freshly built engine (typically
+    /// pointed at a restored copy of the database the recording was taken from) and compares the
+    /// response it gets back to the one that was recorded, line by line. Mismatches are reported
+    /// but don't stop the replay, so a single regression doesn't hide the rest of the workload's
+    /// results.
+    pub(crate) async fn run(self) -> PrismaResult<()> {
+        self.config.validate_that_one_datasource_is_provided()?;
+
+        let contents = fs::read_to_string(&self.file)?;
+
+        let cx = PrismaContext::builder(self.config, self.datamodel)
+            .legacy(self.legacy)
+            .enable_raw_queries(self.enable_raw_queries)
+            .read_only(self.read_only)
+            .build()
+            .await?;
+
+        let cx = Arc::new(cx);
+        let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
+
+        let mut replayed = 0usize;
+        let mut mismatched = Vec::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let recorded: RecordedQuery = serde_json::from_str(line)?;
+            let response = handler.handle(recorded.request).await;
+            let actual = serde_json::to_value(&response)?;
+
+            replayed += 1;
+
+            if actual != recorded.response {
+                mismatched.push(index + 1);
+                println!(
+                    "Mismatch on line {}:\n  recorded: {}\n  actual:   {}",
+                    index + 1,
+                    recorded.response,
+                    actual
+                );
+            }
+        }
+
+        println!(
+            "Replayed {} quer{} against the database, {} mismatch{}.",
+            replayed,
+            if replayed == 1 { "y" } else { "ies" },
+            mismatched.len(),
+            if mismatched.len() == 1 { "" } else { "es" },
+        );
+
+        if mismatched.is_empty() {
+            Ok(())
+        } else {
+            Err(PrismaError::InvocationError(format!(
+                "{} of {} replayed queries produced a different response than recorded (lines: {:?})",
+                mismatched.len(),
+                replayed,
+                mismatched
+            )))
+        }
+    }
+}
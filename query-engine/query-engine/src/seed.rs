@@ -0,0 +1,141 @@
+use crate::{context::PrismaContext, PrismaError, PrismaResult};
+use datamodel::{Configuration, Datamodel};
+use request_handlers::{GraphQlBody, GraphQlHandler};
+use serde::Deserialize;
+use std::{fs, sync::Arc};
+
+/// One entry in a seed file: every object in `records` becomes a single `create` (or
+/// `createOne`, in modern mode) mutation for `model`, run in file order. Because mutations
+/// run strictly in that order, a later model's records can reference an earlier model's
+/// freshly created rows the same way any other `<Model>CreateInput` already does - through
+/// a nested `connect: { <uniqueField>: <value> }` - so there is no separate "relation
+/// reference" syntax to invent or parse here.
+#[derive(Debug, Deserialize)]
+struct SeedModel {
+    model: String,
+    records: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+pub struct SeedRequest {
+    pub(crate) file: String,
+    pub(crate) legacy: bool,
+    pub(crate) enable_raw_queries: bool,
+    pub(crate) read_only: bool,
+    pub(crate) datamodel: Datamodel,
+    pub(crate) config: Configuration,
+}
+
+impl SeedRequest {
+    /// Loads the seed file at `self.file` and inserts every record through the normal
+    /// GraphQL write path, so defaults and validations run exactly as they would for a
+    /// client-issued mutation. Aborts on the first record that fails, since there's no
+    /// savepoint mechanism (see `query_core::query_graph::Flow::Transaction`) to unwind the
+    /// records already written.
+    pub(crate) async fn run(self) -> PrismaResult<()> {
+        self.config.validate_that_one_datasource_is_provided()?;
+
+        let contents = fs::read_to_string(&self.file)?;
+        let seed_models: Vec<SeedModel> = serde_json::from_str(&contents)?;
+
+        let cx = PrismaContext::builder(self.config, self.datamodel)
+            .legacy(self.legacy)
+            .enable_raw_queries(self.enable_raw_queries)
+            .read_only(self.read_only)
+            .build()
+            .await?;
+
+        let cx = Arc::new(cx);
+        let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
+
+        // The `create{Model}`/`createOne{Model}` split mirrors `pluralize_internal` in
+        // `schema_builder`, which is the thing that actually names the field - `legacy` is
+        // the sole input to that choice, so we can compute the field name here without
+        // inspecting the built query schema.
+        let create_prefix = if self.legacy { "create" } else { "createOne" };
+        let mut seeded = 0usize;
+
+        for seed_model in seed_models {
+            let field_name = format!("{}{}", create_prefix, seed_model.model);
+
+            for record in seed_model.records {
+                let data = json_object_to_graphql_literal(&record);
+                let query = format!("mutation {{ {}(data: {}) {{ __typename }} }}", field_name, data);
+
+                let response = handler.handle(GraphQlBody::Single(query.into())).await;
+                let serialized = serde_json::to_string(&response).unwrap();
+
+                if response_contains_errors(&serialized) {
+                    return Err(PrismaError::InvocationError(format!(
+                        "Seeding `{}` record #{} failed: {}",
+                        seed_model.model,
+                        seeded + 1,
+                        serialized
+                    )));
+                }
+
+                seeded += 1;
+            }
+        }
+
+        println!("Seeded {} record(s).", seeded);
+
+        Ok(())
+    }
+}
+
+/// A response serializes to `{"data":...}` on success and `{"errors":[...],"data":...}` (or
+/// no `data` key at all) when something failed - checking for a non-empty `errors` array is
+/// the only public signal available, since `GQLResponse`'s fields aren't otherwise exposed.
+fn response_contains_errors(serialized_response: &str) -> bool {
+    match serde_json::from_str::<serde_json::Value>(serialized_response) {
+        Ok(serde_json::Value::Object(obj)) => match obj.get("errors") {
+            Some(serde_json::Value::Array(errors)) => !errors.is_empty(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Renders a JSON object as a GraphQL input object literal (e.g. `{ name: "Alice", age: 30 }`).
+/// Seed records are inlined this way rather than passed through `SingleQuery`'s `variables`
+/// map, since nothing in `GraphQlBody::into_doc` actually reads `variables` - it's accepted
+/// but never wired up to the parsed query.
+fn json_object_to_graphql_literal(object: &serde_json::Map<String, serde_json::Value>) -> String {
+    let fields: Vec<String> = object
+        .iter()
+        .map(|(key, value)| format!("{}: {}", key, json_value_to_graphql_literal(value)))
+        .collect();
+
+    format!("{{ {} }}", fields.join(", "))
+}
+
+fn json_value_to_graphql_literal(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => format!("\"{}\"", escape_graphql_string(s)),
+        serde_json::Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(json_value_to_graphql_literal).collect();
+            format!("[{}]", items.join(", "))
+        }
+        serde_json::Value::Object(obj) => json_object_to_graphql_literal(obj),
+    }
+}
+
+fn escape_graphql_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
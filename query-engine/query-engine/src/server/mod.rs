@@ -16,7 +16,12 @@ use tracing::Level;
 use tracing_futures::Instrument;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::{Arc, Mutex},
+};
 
 mod elapsed_middleware;
 
@@ -25,15 +30,22 @@ pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    record_queries: Option<Arc<Mutex<File>>>,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        enable_playground: bool,
+        enable_debug_mode: bool,
+        record_queries: Option<Arc<Mutex<File>>>,
+    ) -> Self {
         Self {
             cx: Arc::new(cx),
             enable_playground,
             enable_debug_mode,
+            record_queries,
         }
     }
 }
@@ -44,6 +56,7 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            record_queries: self.record_queries.clone(),
         }
     }
 }
@@ -58,10 +71,25 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .read_only(opts.read_only)
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let record_queries = opts
+        .record_queries
+        .as_ref()
+        .map(|path| -> PrismaResult<_> {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            Ok(Arc::new(Mutex::new(file)))
+        })
+        .transpose()?;
+
+    let mut app = tide::with_state(State::new(
+        cx,
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        record_queries,
+    ));
     app.with(ElapsedMiddleware::new());
 
     if opts.enable_playground {
@@ -104,9 +132,14 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
     let work = async move {
         let body: GraphQlBody = req.body_json().await?;
         let cx = req.state().cx.clone();
+        let record_queries = req.state().record_queries.clone();
 
         let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
-        let result = handler.handle(body).await;
+        let result = handler.handle(body.clone()).await;
+
+        if let Some(record_queries) = record_queries {
+            record_query(&record_queries, body, &result);
+        }
 
         let mut res = Response::new(StatusCode::Ok);
         res.set_body(Body::from_json(&result)?);
@@ -117,6 +150,36 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
     work.instrument(span).await
 }
 
+/// Appends a `RecordedQuery` line to the `--record-queries` file. Errors (a full disk, a
+/// permission change after startup, ...) are logged rather than propagated: losing one line of
+/// the recording shouldn't turn into a request failure for a feature that's opt-in tooling to
+/// begin with.
+fn record_query(file: &Mutex<File>, request: GraphQlBody, response: &request_handlers::PrismaResponse) {
+    let entry = crate::recording::RecordedQuery {
+        request,
+        response: match serde_json::to_value(response) {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::warn!(error = %err, "Failed to serialize response for query recording");
+                return;
+            }
+        },
+    };
+
+    let mut line = match serde_json::to_vec(&entry) {
+        Ok(line) => line,
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to serialize recorded query");
+            return;
+        }
+    };
+    line.push(b'\n');
+
+    if let Err(err) = file.lock().unwrap().write_all(&line) {
+        tracing::warn!(error = %err, "Failed to write recorded query");
+    }
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -27,6 +27,7 @@ pub fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml:
         false,
         capabilities,
         config.subject.preview_features().cloned().collect(),
+        false,
     );
 
     (schema, dm)
@@ -103,6 +104,7 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         datamodel_path: None,
         enable_debug_mode: false,
         enable_raw_queries: false,
+        read_only: false,
         enable_playground: false,
         legacy: false,
         log_format: None,
@@ -113,6 +115,7 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         subcommand: Some(Subcommand::Cli(CliOpt::Dmmf)),
         open_telemetry: false,
         open_telemetry_endpoint: String::new(),
+        config_path: None,
     };
 
     let cli_cmd = CliCommand::from_opt(&prisma_opt)?.unwrap();
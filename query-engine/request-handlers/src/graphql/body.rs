@@ -33,6 +33,14 @@ impl MultiQuery {
     }
 }
 
+impl SingleQuery {
+    /// Attaches GraphQL variables (JSON-encoded) to the query.
+    pub fn with_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.variables = variables;
+        self
+    }
+}
+
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
@@ -22,6 +22,7 @@ pub fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml:
         false,
         capabilities,
         config.subject.preview_features().cloned().collect(),
+        false,
     );
 
     (schema, dm)